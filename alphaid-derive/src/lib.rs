@@ -0,0 +1,343 @@
+//! Derive macros for [alphaid](https://docs.rs/alphaid).
+//!
+//! ## AlphaEncoded
+//!
+//! Derives `encode`/`decode` methods, `Display` and `FromStr` for a newtype
+//! wrapping an unsigned integer, so that it is always shown as an encoded ID.
+//!
+//! ```rust
+//! use alphaid::AlphaId;
+//! use alphaid_derive::AlphaEncoded;
+//!
+//! fn user_ids() -> AlphaId<u64> {
+//!     AlphaId::builder().pad(5).build()
+//! }
+//!
+//! #[derive(AlphaEncoded, Debug, PartialEq)]
+//! #[alphaid(config = user_ids)]
+//! struct UserId(u64);
+//!
+//! assert_eq!(UserId(0).to_string(), "aaaab");
+//! assert_eq!("aaaab".parse(), Ok(UserId(0)));
+//! ```
+//!
+//! The `config` is a path to a function returning the `AlphaId` to use; it
+//! is called once. Without it the default configuration is used. Adding
+//! `#[alphaid(serde)]` also derives `serde::Serialize` and
+//! `serde::Deserialize` as the encoded string, which requires `serde` to be
+//! a dependency of the crate using the derive.
+//!
+//! ## alphaid!
+//!
+//! Returns a `&'static AlphaId` whose configuration is checked at compile
+//! time, re-exported as `alphaid::alphaid!` with the `derive` feature.
+//!
+//! The integer type defaults to `u128`. `chars` must be more than 16 unique
+//! printable ASCII characters and `pad` must be larger than 0, otherwise the
+//! build fails. Both take constant expressions. The instance is kept in a
+//! `std::sync::OnceLock`, so the macro needs `std`.
+//!
+//! ```rust
+//! use alphaid_derive::alphaid;
+//!
+//! let ids = alphaid!(u32, chars = "ABCDEFGHIJKLMNOPQRSTUVWXYZ", pad = 2);
+//! assert_eq!(ids.encode(0), Ok(b"AB".to_vec()));
+//!
+//! let ids = alphaid!(pad = 5);
+//! assert_eq!(ids.encode(0), Ok(b"aaaab".to_vec()));
+//! ```
+//!
+//! ```compile_fail
+//! use alphaid_derive::alphaid;
+//!
+//! let ids = alphaid!(u32, chars = "ABCDEFGHIJKLMNOPQRSTUVWXYA");
+//! ```
+//!
+//! ```compile_fail
+//! use alphaid_derive::alphaid;
+//!
+//! let ids = alphaid!(u32, chars = "ABCDEFGHIJKLMNOPQ RSTUVWXYZ");
+//! ```
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+/// See the [crate documentation](index.html).
+#[proc_macro_derive(AlphaEncoded, attributes(alphaid))]
+pub fn derive_alpha_encoded(input: TokenStream) -> TokenStream {
+    match parse(input) {
+        Ok(newtype) => newtype.expand().parse().unwrap(),
+        Err(msg) => format!("compile_error!({:?});", msg).parse().unwrap(),
+    }
+}
+
+/// See the [crate documentation](index.html#alphaid).
+#[proc_macro]
+pub fn alphaid(input: TokenStream) -> TokenStream {
+    match parse_instance(input) {
+        Ok(instance) => instance.expand().parse().unwrap(),
+        Err(msg) => format!("compile_error!({:?})", msg).parse().unwrap(),
+    }
+}
+
+struct Instance {
+    ty: String,
+    chars: Option<String>,
+    pad: Option<String>,
+}
+
+fn parse_instance(input: TokenStream) -> Result<Instance, String> {
+    // Splits the input at the top level commas.
+    let mut args = vec![Vec::new()];
+    for token in input {
+        match token {
+            TokenTree::Punct(ref p) if p.as_char() == ',' => args.push(Vec::new()),
+            token => args.last_mut().unwrap().push(token),
+        }
+    }
+    if args.last().is_some_and(Vec::is_empty) {
+        args.pop();
+    }
+
+    let mut instance = Instance {
+        ty: "u128".to_string(),
+        chars: None,
+        pad: None,
+    };
+    for (i, arg) in args.into_iter().enumerate() {
+        let key = match (arg.first(), arg.get(1)) {
+            (Some(TokenTree::Ident(key)), Some(TokenTree::Punct(p))) if p.as_char() == '=' => {
+                key.to_string()
+            }
+            _ if i == 0 && !arg.is_empty() => {
+                instance.ty = arg.iter().map(ToString::to_string).collect();
+                continue;
+            }
+            _ => return Err("expected `chars = ...` or `pad = ...`".into()),
+        };
+        if arg.len() < 3 {
+            return Err(format!("expected a value for `{}`", key));
+        }
+        let value: String = arg[2..].iter().map(ToString::to_string).collect();
+        let slot = match key.as_str() {
+            "chars" => &mut instance.chars,
+            "pad" => &mut instance.pad,
+            _ => return Err(format!("unknown alphaid! setting `{}`", key)),
+        };
+        if slot.replace(value).is_some() {
+            return Err(format!("duplicate alphaid! setting `{}`", key));
+        }
+    }
+    Ok(instance)
+}
+
+impl Instance {
+    fn expand(&self) -> String {
+        let mut checks = String::new();
+        let mut setters = String::new();
+        if let Some(chars) = &self.chars {
+            checks.push_str(&format!("::alphaid::__private::check_chars({});", chars));
+            setters.push_str(&format!(".chars(({}).as_bytes().to_vec())", chars));
+        }
+        if let Some(pad) = &self.pad {
+            checks.push_str(&format!("::alphaid::__private::check_pad({});", pad));
+            setters.push_str(&format!(".pad({})", pad));
+        }
+        format!(
+            r#"{{
+    const _: () = {{ {checks} }};
+    static ALPHAID: ::std::sync::OnceLock<::alphaid::AlphaId<{ty}>> =
+        ::std::sync::OnceLock::new();
+    ALPHAID.get_or_init(|| ::alphaid::AlphaId::<{ty}>::builder(){setters}.build())
+}}"#,
+            checks = checks,
+            ty = self.ty,
+            setters = setters,
+        )
+    }
+}
+
+struct Newtype {
+    name: String,
+    inner: String,
+    config: Option<String>,
+    serde: bool,
+}
+
+fn parse(input: TokenStream) -> Result<Newtype, String> {
+    let mut config = None;
+    let mut serde = false;
+    let mut name = None;
+    let mut inner = None;
+
+    let mut tokens = input.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(ref p) if p.as_char() == '#' => {
+                if let Some(TokenTree::Group(attr)) = tokens.next() {
+                    let mut attr = attr.stream().into_iter();
+                    match (attr.next(), attr.next()) {
+                        (Some(TokenTree::Ident(ident)), Some(TokenTree::Group(args)))
+                            if ident.to_string() == "alphaid" =>
+                        {
+                            parse_args(args.stream(), &mut config, &mut serde)?;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            TokenTree::Ident(ref ident) if ident.to_string() == "struct" => {
+                name = match tokens.next() {
+                    Some(TokenTree::Ident(ident)) => Some(ident.to_string()),
+                    _ => return Err("expected a struct name".into()),
+                };
+                match tokens.next() {
+                    Some(TokenTree::Group(fields))
+                        if fields.delimiter() == Delimiter::Parenthesis =>
+                    {
+                        inner = Some(parse_field(fields.stream())?);
+                    }
+                    _ => {
+                        return Err(
+                            "AlphaEncoded can only be derived for tuple structs with one field"
+                                .into(),
+                        )
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match (name, inner) {
+        (Some(name), Some(inner)) => Ok(Newtype {
+            name,
+            inner,
+            config,
+            serde,
+        }),
+        _ => Err("AlphaEncoded can only be derived for structs".into()),
+    }
+}
+
+fn parse_args(
+    args: TokenStream,
+    config: &mut Option<String>,
+    serde: &mut bool,
+) -> Result<(), String> {
+    let mut args = args.into_iter().peekable();
+    while let Some(token) = args.next() {
+        match token {
+            TokenTree::Ident(ident) if ident.to_string() == "serde" => *serde = true,
+            TokenTree::Ident(ident) if ident.to_string() == "config" => {
+                match args.next() {
+                    Some(TokenTree::Punct(p)) if p.as_char() == '=' => {}
+                    _ => return Err("expected `config = path`".into()),
+                }
+                let mut path = String::new();
+                while let Some(token) = args.peek() {
+                    if let TokenTree::Punct(p) = token {
+                        if p.as_char() == ',' {
+                            break;
+                        }
+                    }
+                    path.push_str(&args.next().unwrap().to_string());
+                }
+                *config = Some(path);
+            }
+            TokenTree::Punct(ref p) if p.as_char() == ',' => {}
+            other => return Err(format!("unknown alphaid attribute `{}`", other)),
+        }
+    }
+    Ok(())
+}
+
+fn parse_field(field: TokenStream) -> Result<String, String> {
+    let mut tokens: Vec<TokenTree> = field.into_iter().collect();
+    if let Some(TokenTree::Ident(ident)) = tokens.first() {
+        if ident.to_string() == "pub" {
+            tokens.remove(0);
+            if let Some(TokenTree::Group(_)) = tokens.first() {
+                tokens.remove(0);
+            }
+        }
+    }
+    if tokens
+        .iter()
+        .any(|t| matches!(t, TokenTree::Punct(p) if p.as_char() == ','))
+    {
+        return Err("AlphaEncoded can only be derived for tuple structs with one field".into());
+    }
+    Ok(tokens.iter().map(ToString::to_string).collect())
+}
+
+impl Newtype {
+    fn expand(&self) -> String {
+        let Newtype { name, inner, .. } = self;
+        let init = match &self.config {
+            Some(config) => config.clone(),
+            None => "::alphaid::AlphaId::new".to_string(),
+        };
+
+        let mut out = format!(
+            r#"
+impl {name} {{
+    fn alphaid() -> &'static ::alphaid::AlphaId<{inner}> {{
+        static ALPHAID: ::std::sync::OnceLock<::alphaid::AlphaId<{inner}>> =
+            ::std::sync::OnceLock::new();
+        ALPHAID.get_or_init({init})
+    }}
+
+    /// Encodes the ID.
+    pub fn encode(&self) -> ::std::result::Result<::std::vec::Vec<u8>, ::alphaid::AlphaIdError> {{
+        Self::alphaid().encode(self.0)
+    }}
+
+    /// Decodes an encoded ID.
+    pub fn decode<V: ::std::convert::AsRef<[u8]>>(v: V) -> ::std::result::Result<Self, ::alphaid::AlphaIdError> {{
+        Self::alphaid().decode(v).map({name})
+    }}
+}}
+
+impl ::std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {{
+        Self::alphaid().encode_fmt(self.0, f).map_err(|_| ::std::fmt::Error)
+    }}
+}}
+
+impl ::std::str::FromStr for {name} {{
+    type Err = ::alphaid::AlphaIdError;
+
+    fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {{
+        Self::alphaid().decode_str(s).map({name})
+    }}
+}}
+"#,
+            name = name,
+            inner = inner,
+            init = init,
+        );
+
+        if self.serde {
+            out.push_str(&format!(
+                r#"
+impl ::serde::Serialize for {name} {{
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {{
+        serializer.collect_str(self)
+    }}
+}}
+
+impl<'de> ::serde::Deserialize<'de> for {name} {{
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {{
+        let s = <::std::borrow::Cow<'de, str> as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+        s.parse().map_err(|e| <D::Error as ::serde::de::Error>::custom(::std::format!("invalid ID: {{:?}}", e)))
+    }}
+}}
+"#,
+                name = name,
+            ));
+        }
+
+        out
+    }
+}
@@ -0,0 +1,49 @@
+use alphaid::{AlphaId, AlphaIdError};
+use alphaid_derive::{alphaid, AlphaEncoded};
+
+fn order_ids() -> AlphaId<u32> {
+    AlphaId::builder().prefix(b"ord_").build()
+}
+
+#[derive(AlphaEncoded, Debug, Clone, Copy, PartialEq)]
+struct UserId(u64);
+
+#[derive(AlphaEncoded, Debug, Clone, Copy, PartialEq)]
+#[alphaid(config = order_ids)]
+pub struct OrderId(pub u32);
+
+#[test]
+fn test_default_config() {
+    let id = UserId(1350997667);
+    assert_eq!(id.to_string(), "90F7qb");
+    assert_eq!(id.encode(), Ok(b"90F7qb".to_vec()));
+    assert_eq!("90F7qb".parse::<UserId>(), Ok(id));
+    assert_eq!(UserId::decode(b"90F7qb"), Ok(id));
+}
+
+#[test]
+fn test_custom_config() {
+    let id = OrderId(12345);
+    assert_eq!(id.to_string(), "ord_Vad");
+    assert_eq!("ord_Vad".parse::<OrderId>(), Ok(id));
+    assert_eq!(
+        "usr_Vad".parse::<OrderId>(),
+        Err(AlphaIdError::PrefixMissed)
+    );
+}
+
+#[test]
+fn test_alphaid_macro() {
+    let ids = alphaid!();
+    assert_eq!(ids.encode(1350997667), Ok(b"90F7qb".to_vec()));
+
+    const CHARS: &str = "0123456789abcdefghij";
+    let ids = alphaid!(u64, pad = 3, chars = CHARS,);
+    let expected = AlphaId::<u64>::builder()
+        .chars(CHARS.as_bytes().to_vec())
+        .pad(3)
+        .build();
+    for n in 0..1000 {
+        assert_eq!(ids.encode(n), expected.encode(n));
+    }
+}
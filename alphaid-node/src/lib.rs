@@ -0,0 +1,64 @@
+//! Node.js bindings for [alphaid](https://docs.rs/alphaid) through N-API,
+//! producing the same IDs as the Rust services without a WASM round trip.
+//!
+//! Build the addon with `napi build --release` in this directory.
+//!
+//! ```js
+//! const { AlphaId } = require("./alphaid.node");
+//!
+//! const ids = new AlphaId("pad=5;prefix=usr_");
+//! ids.encode(0n);                        // 'usr_aaaab'
+//! ids.decode("usr_aaaab");               // 0n
+//! ids.decode(Buffer.from("usr_aaaab"));  // 0n
+//! ```
+//!
+//! Numbers are `BigInt`s, so that all `u64` survive the round trip.
+//! Invalid settings and IDs throw an `Error` with the message of the
+//! `AlphaIdError`.
+
+use napi::bindgen_prelude::{BigInt, Buffer, Either};
+use napi::{Error, Result, Status};
+use napi_derive::napi;
+
+fn js_error(e: alphaid::AlphaIdError) -> Error {
+    Error::new(Status::InvalidArg, e.to_string())
+}
+
+/// An `AlphaId<u64>`, constructed from the settings of `Builder::parse`.
+#[napi(js_name = "AlphaId")]
+pub struct NodeAlphaId(alphaid::AlphaId<u64>);
+
+#[napi]
+impl NodeAlphaId {
+    /// Constructs an `AlphaId` from `key=value` settings separated by `;`,
+    /// or the default settings.
+    #[napi(constructor)]
+    pub fn new(settings: Option<String>) -> Result<Self> {
+        // `parse` rejects the settings `build` would panic on.
+        let builder = match settings {
+            Some(s) => alphaid::Builder::parse(&s).map_err(js_error)?,
+            None => alphaid::Builder::new(),
+        };
+        Ok(Self(builder.build()))
+    }
+
+    /// Encodes a non-negative `BigInt` into a string.
+    #[napi]
+    pub fn encode(&self, n: BigInt) -> Result<String> {
+        let (signed, n, lossless) = n.get_u64();
+        if signed || !lossless {
+            return Err(js_error(alphaid::AlphaIdError::Overflow));
+        }
+        self.0.encode_string(n).map_err(js_error)
+    }
+
+    /// Decodes a string or the bytes of a `Buffer` into a `BigInt`.
+    #[napi]
+    pub fn decode(&self, id: Either<String, Buffer>) -> Result<BigInt> {
+        let n = match id {
+            Either::A(s) => self.0.decode_str(&s),
+            Either::B(buf) => self.0.decode(&buf[..]),
+        };
+        n.map(BigInt::from).map_err(js_error)
+    }
+}
@@ -0,0 +1,42 @@
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+const SCRIPT: &str = r#"
+const assert = require("assert");
+const { AlphaId } = require(process.argv[1]);
+
+const ids = new AlphaId("pad=5;prefix=usr_");
+assert.strictEqual(ids.encode(0n), "usr_aaaab");
+assert.strictEqual(ids.decode("usr_aaaab"), 0n);
+assert.strictEqual(ids.decode(Buffer.from("usr_aaaab")), 0n);
+const max = 2n ** 64n - 1n;
+assert.strictEqual(ids.decode(ids.encode(max)), max);
+assert.strictEqual(new AlphaId().encode(1350997667n), "90F7qb");
+
+assert.throws(() => ids.decode("usr_aa!ab"), /unexpected character/);
+assert.throws(() => ids.encode(-1n), /overflow/);
+assert.throws(() => ids.encode(2n ** 64n), /overflow/);
+assert.throws(() => new AlphaId("pad=0"), /invalid configuration/);
+"#;
+
+/// Loads the addon built next to the test into Node.js.
+#[test]
+#[ignore = "requires Node.js, run with `cargo test -p alphaid-node -- --ignored`"]
+fn test_node_addon() {
+    // target/<profile>/deps/node-<hash> -> target/<profile>
+    let exe = std::env::current_exe().unwrap();
+    let dir = exe.parent().and_then(|deps| deps.parent()).unwrap();
+    let lib = dir.join(format!("{}alphaid_node{}", DLL_PREFIX, DLL_SUFFIX));
+    // Node.js only loads addons with the `.node` extension.
+    let addon: PathBuf = dir.join("alphaid_test.node");
+    fs::copy(&lib, &addon).unwrap();
+
+    let status = Command::new("node")
+        .args(["-e", SCRIPT])
+        .arg(&addon)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
@@ -0,0 +1,73 @@
+//! PostgreSQL functions for [alphaid](https://docs.rs/alphaid) through
+//! pgrx, so that views and migrations produce the same IDs as the Rust
+//! services.
+//!
+//! Install the extension with `cargo pgrx install --features pg17` in this
+//! directory, for the installed PostgreSQL version.
+//!
+//! ```sql
+//! CREATE EXTENSION alphaid_pg;
+//!
+//! SELECT alpha_encode(0, 'pad=5;prefix=usr_');          -- 'usr_aaaab'
+//! SELECT alpha_decode('usr_aaaab', 'pad=5;prefix=usr_'); -- 0
+//! SELECT alpha_encode(1350997667, '');                  -- '90F7qb'
+//! ```
+//!
+//! The settings are those of `Builder::parse`, an empty string for the
+//! default ones. Numbers are encoded as `AlphaId<u64>`, so negative
+//! numbers can not be encoded and IDs of numbers beyond `bigint` can not be
+//! decoded. Errors are raised with the message of the `AlphaIdError`.
+
+use alphaid::{AlphaId, AlphaIdError, Builder};
+use std::cell::RefCell;
+
+#[cfg(any(
+    feature = "pg13",
+    feature = "pg14",
+    feature = "pg15",
+    feature = "pg16",
+    feature = "pg17",
+    feature = "pg18"
+))]
+mod pg;
+
+thread_local! {
+    /// The `AlphaId` of the last settings, which a query mostly repeats
+    /// for every row.
+    static LAST: RefCell<Option<(String, AlphaId<u64>)>> = const { RefCell::new(None) };
+}
+
+fn with_alphaid<R>(
+    settings: &str,
+    f: impl FnOnce(&AlphaId<u64>) -> Result<R, AlphaIdError>,
+) -> Result<R, AlphaIdError> {
+    LAST.with(|last| {
+        let mut last = last.borrow_mut();
+        match &*last {
+            Some((s, _)) if s == settings => {}
+            _ => {
+                // `parse` rejects the settings `build` would panic on.
+                let alphaid = Builder::parse(settings)?.build();
+                *last = Some((settings.to_owned(), alphaid));
+            }
+        }
+        let (_, alphaid) = last.as_ref().unwrap();
+        f(alphaid)
+    })
+}
+
+/// Encodes a non-negative number with the settings of `Builder::parse`.
+///
+/// Returns `AlphaIdError::Overflow` for negative numbers.
+pub fn encode(n: i64, settings: &str) -> Result<String, AlphaIdError> {
+    let n = u64::try_from(n).map_err(|_| AlphaIdError::Overflow)?;
+    with_alphaid(settings, |alphaid| alphaid.encode_string(n))
+}
+
+/// Decodes with the settings of `Builder::parse`.
+///
+/// Returns `AlphaIdError::Overflow` for numbers beyond `i64::MAX`.
+pub fn decode(s: &str, settings: &str) -> Result<i64, AlphaIdError> {
+    let n = with_alphaid(settings, |alphaid| alphaid.decode_str(s))?;
+    i64::try_from(n).map_err(|_| AlphaIdError::Overflow)
+}
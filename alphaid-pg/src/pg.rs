@@ -0,0 +1,15 @@
+use pgrx::prelude::*;
+
+::pgrx::pg_module_magic!(name, version);
+
+/// Encodes a non-negative `bigint` into `text`.
+#[pg_extern(immutable, parallel_safe)]
+fn alpha_encode(n: i64, config: &str) -> String {
+    crate::encode(n, config).unwrap_or_else(|e| error!("alpha_encode: {}", e))
+}
+
+/// Decodes `text` into a `bigint`.
+#[pg_extern(immutable, parallel_safe)]
+fn alpha_decode(s: &str, config: &str) -> i64 {
+    crate::decode(s, config).unwrap_or_else(|e| error!("alpha_decode: {}", e))
+}
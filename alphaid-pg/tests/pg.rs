@@ -0,0 +1,29 @@
+use alphaid::{AlphaId, AlphaIdError, Builder};
+
+#[test]
+fn test_same_as_alphaid() {
+    let settings = "pad=5;prefix=usr_";
+    let alphaid = Builder::<u64>::parse(settings).unwrap().build();
+    for &n in &[0, 1, 1350997667, i64::MAX] {
+        let v = alphaid_pg::encode(n, settings).unwrap();
+        assert_eq!(v, alphaid.encode_string(n as u64).unwrap());
+        assert_eq!(alphaid_pg::decode(&v, settings), Ok(n));
+    }
+    assert_eq!(alphaid_pg::encode(1350997667, ""), Ok("90F7qb".to_string()));
+    assert_eq!(alphaid_pg::decode("90F7qb", ""), Ok(1350997667));
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(alphaid_pg::encode(-1, ""), Err(AlphaIdError::Overflow));
+    let max = AlphaId::<u64>::new().encode_string(u64::MAX).unwrap();
+    assert_eq!(alphaid_pg::decode(&max, ""), Err(AlphaIdError::Overflow));
+    assert_eq!(
+        alphaid_pg::decode("usr_aa!ab", "pad=5;prefix=usr_"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(
+        alphaid_pg::encode(0, "pad=0"),
+        Err(AlphaIdError::InvalidConfig)
+    );
+}
@@ -0,0 +1,76 @@
+//! Python bindings for [alphaid](https://docs.rs/alphaid), producing the
+//! same IDs as the Rust services.
+//!
+//! Build and install the `alphaid` Python module with `maturin develop`
+//! in this directory.
+//!
+//! ```python
+//! import alphaid
+//!
+//! ids = alphaid.AlphaId("pad=5;prefix=usr_")
+//! ids.encode(0)          # 'usr_aaaab'
+//! ids.decode("usr_aaaab")  # 0
+//!
+//! try:
+//!     ids.decode("usr_aa!ab")
+//! except alphaid.AlphaIdError as e:
+//!     print(e)  # unexpected character
+//! ```
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+create_exception!(
+    alphaid,
+    AlphaIdError,
+    PyValueError,
+    "Raised for invalid settings and IDs which can not be encoded or decoded."
+);
+
+fn py_error(e: alphaid::AlphaIdError) -> PyErr {
+    AlphaIdError::new_err(e.to_string())
+}
+
+/// An `AlphaId<u64>`, constructed from the settings of `Builder::parse`.
+#[pyclass(name = "AlphaId", frozen)]
+pub struct PyAlphaId(alphaid::AlphaId<u64>);
+
+#[pymethods]
+impl PyAlphaId {
+    /// Constructs an `AlphaId` from `key=value` settings separated by `;`,
+    /// or the default settings.
+    #[new]
+    #[pyo3(signature = (settings = None))]
+    fn new(settings: Option<&str>) -> PyResult<Self> {
+        // `parse` rejects the settings `build` would panic on.
+        let builder = match settings {
+            Some(s) => alphaid::Builder::parse(s).map_err(py_error)?,
+            None => alphaid::Builder::new(),
+        };
+        Ok(Self(builder.build()))
+    }
+
+    /// Encodes a non-negative integer into a string.
+    fn encode(&self, n: u64) -> PyResult<String> {
+        self.0.encode_string(n).map_err(py_error)
+    }
+
+    /// Decodes a string into the integer.
+    fn decode(&self, s: &str) -> PyResult<u64> {
+        self.0.decode_str(s).map_err(py_error)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+/// The `alphaid` Python module.
+#[pymodule]
+#[pyo3(name = "alphaid")]
+pub fn alphaid_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAlphaId>()?;
+    m.add("AlphaIdError", m.py().get_type::<AlphaIdError>())?;
+    Ok(())
+}
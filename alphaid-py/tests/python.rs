@@ -0,0 +1,53 @@
+use pyo3::ffi::c_str;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+#[test]
+fn test_python_module() {
+    Python::initialize();
+    Python::attach(|py| {
+        let module = pyo3::wrap_pymodule!(alphaid_py::alphaid_module)(py);
+        let globals = PyDict::new(py);
+        globals.set_item("alphaid", module).unwrap();
+        py.run(
+            c_str!(
+                r#"
+ids = alphaid.AlphaId()
+assert ids.encode(1350997667) == "90F7qb"
+assert ids.decode("90F7qb") == 1350997667
+
+ids = alphaid.AlphaId("pad=5;prefix=usr_")
+assert ids.encode(0) == "usr_aaaab"
+assert ids.decode("usr_aaaab") == 0
+assert ids.decode(ids.encode(2**64 - 1)) == 2**64 - 1
+
+for bad in ["usr_aa!ab", "aaaab"]:
+    try:
+        ids.decode(bad)
+        raise AssertionError(bad)
+    except alphaid.AlphaIdError as e:
+        assert isinstance(e, ValueError)
+try:
+    ids.decode("usr_aa!ab")
+except alphaid.AlphaIdError as e:
+    assert str(e) == "unexpected character", str(e)
+
+for settings in ["pad=0", "chars=aab", "zigzag=1"]:
+    try:
+        alphaid.AlphaId(settings)
+        raise AssertionError(settings)
+    except alphaid.AlphaIdError:
+        pass
+try:
+    ids.encode(-1)
+    raise AssertionError("negative")
+except OverflowError:
+    pass
+"#
+            ),
+            Some(&globals),
+            None,
+        )
+        .unwrap();
+    });
+}
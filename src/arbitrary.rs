@@ -0,0 +1,186 @@
+//! Generators for fuzzing with `arbitrary`, covering configurations and
+//! inputs made of the symbols of an alphabet.
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use ::arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+use crate::{AlphaId, Builder, CharClass, Config, DigitOrder, PadMode, UnsignedInteger};
+
+/// The printable ASCII characters the generated settings pick from.
+const PRINTABLE: core::ops::RangeInclusive<u8> = b'!'..=b'~';
+
+/// Takes a character out of `pool`.
+fn take(u: &mut Unstructured<'_>, pool: &mut Vec<u8>) -> Result<u8> {
+    if pool.is_empty() {
+        return Err(Error::NotEnoughData);
+    }
+    let i = u.choose_index(pool.len())?;
+    Ok(pool.swap_remove(i))
+}
+
+/// Picks `min` to `max` characters of `pool`, which may repeat.
+fn pick(u: &mut Unstructured<'_>, pool: &[u8], min: usize, max: usize) -> Result<Vec<u8>> {
+    let len = u.int_in_range(min..=max)?;
+    (0..len).map(|_| u.choose(pool).copied()).collect()
+}
+
+/// Mostly valid settings: the alphabet and the symbols of separators,
+/// signs and `None` are distinct printable characters, and every other
+/// setting is small enough for most number types. Limits which reject the
+/// results of encoding, like `max_input_len`, are left unset.
+///
+/// The settings are still checked when building, e.g. a tag may not fit
+/// into `T`.
+impl<'a> Arbitrary<'a> for Config {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut pool: Vec<u8> = PRINTABLE.collect();
+        let allow_small_alphabet = u.ratio(1, 8)?;
+        let size = if allow_small_alphabet {
+            u.int_in_range(2..=16)?
+        } else {
+            u.int_in_range(17..=64)?
+        };
+        let chars = (0..size)
+            .map(|_| take(u, &mut pool))
+            .collect::<Result<Vec<u8>>>()?;
+        let mut symbol = |u: &mut Unstructured<'a>| -> Result<Option<u8>> {
+            if u.ratio(1, 4)? {
+                take(u, &mut pool).map(Some)
+            } else {
+                Ok(None)
+            }
+        };
+        let group = symbol(u)?.map(|sep| (1 + sep as usize % 8, sep));
+        let sign = symbol(u)?;
+        let none_symbol = symbol(u)?;
+
+        let tag = if u.ratio(1, 4)? {
+            Some((u.arbitrary()?, u.int_in_range(1..=3)?))
+        } else {
+            None
+        };
+        let blocked_words = if u.ratio(1, 8)? {
+            let word = pick(u, &chars, 1, 4)?;
+            alloc::vec![String::from_utf8_lossy(&word).into_owned()]
+        } else {
+            Vec::new()
+        };
+        let first_char = if u.ratio(1, 8)? {
+            Some(match u.int_in_range(0..=2)? {
+                0 => CharClass::Letter,
+                1 => CharClass::Alphanumeric,
+                _ => CharClass::NoneOf(pick(u, &chars, 0, 3)?),
+            })
+        } else {
+            None
+        };
+
+        let mut pad_modes = alloc::vec![PadMode::Shift, PadMode::Fill];
+        if cfg!(feature = "rand") && u.ratio(1, 8)? {
+            pad_modes.clear();
+            pad_modes.push(PadMode::Random);
+        }
+
+        Ok(Config {
+            chars,
+            pad: u.int_in_range(1..=12)?,
+            pad_mode: *u.choose(&pad_modes)?,
+            order: *u.choose(&[DigitOrder::BigEndian, DigitOrder::LittleEndian])?,
+            width: if u.ratio(1, 8)? {
+                Some(u.int_in_range(1..=24)?)
+            } else {
+                None
+            },
+            group,
+            prefix: pick(u, &PRINTABLE.collect::<Vec<u8>>(), 0, 4)?,
+            suffix: pick(u, &PRINTABLE.collect::<Vec<u8>>(), 0, 4)?,
+            blocked_words,
+            never_numeric: u.ratio(1, 8)?,
+            first_char,
+            reject_empty: u.arbitrary()?,
+            tag,
+            sign,
+            none_symbol,
+            digit_seed: if u.ratio(1, 4)? {
+                Some(u.arbitrary()?)
+            } else {
+                None
+            },
+            max_value: if u.ratio(1, 8)? {
+                Some(u.arbitrary()?)
+            } else {
+                None
+            },
+            offset: if u.ratio(1, 8)? {
+                Some(u.arbitrary()?)
+            } else {
+                None
+            },
+            tag_key_id: tag.is_some() && u.arbitrary()?,
+            old_tag_keys: if tag.is_some() {
+                u.arbitrary()?
+            } else {
+                Vec::new()
+            },
+            allow_small_alphabet,
+            ..Config::default()
+        })
+    }
+}
+
+/// Builds an arbitrary [`Config`], returning `Error::IncorrectFormat` for
+/// settings which do not build.
+///
+/// # Example
+///
+/// A fuzz target checking that decoding never panics:
+///
+/// ```rust
+/// use alphaid::AlphaId;
+/// use arbitrary::{Arbitrary, Unstructured};
+///
+/// fn fuzz(data: &[u8]) -> arbitrary::Result<()> {
+///     let mut u = Unstructured::new(data);
+///     let alphaid = AlphaId::<u64>::arbitrary(&mut u)?;
+///     let input = alphaid.arbitrary_input(&mut u)?;
+///     if let Ok(n) = alphaid.decode(&input) {
+///         if let Ok(v) = alphaid.encode(n) {
+///             assert_eq!(alphaid.decode(&v), Ok(n));
+///         }
+///     }
+///     Ok(())
+/// }
+/// # fuzz(&[7; 256]).ok();
+/// ```
+impl<'a, T: UnsignedInteger> Arbitrary<'a> for AlphaId<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Builder::from_config(Config::arbitrary(u)?)
+            .try_build_marked()
+            .map_err(|_| Error::IncorrectFormat)
+    }
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Generates an input from the affixes, the alphabet, the separator and
+    /// the symbols of signs and `None`, which is mostly but not always
+    /// valid, for fuzzing the decoders.
+    pub fn arbitrary_input(&self, u: &mut Unstructured<'_>) -> Result<Vec<u8>> {
+        let mut tokens: Vec<&[u8]> = if self.symbols.is_empty() {
+            self.chars.chunks(1).collect()
+        } else {
+            self.symbols.iter().map(|s| s.as_bytes()).collect()
+        };
+        let extra = [self.group.map(|(_, sep)| sep), self.sign, self.none_symbol];
+        let extra: Vec<[u8; 1]> = extra.iter().flatten().map(|&b| [b]).collect();
+        tokens.extend(extra.iter().map(|b| &b[..]));
+
+        let mut input = self.prefix.to_vec();
+        let len = u.int_in_range(0..=self.max_encoded_len() + 2)?;
+        for _ in 0..len {
+            input.extend_from_slice(u.choose(&tokens)?);
+        }
+        input.extend_from_slice(&self.suffix);
+        Ok(input)
+    }
+}
@@ -0,0 +1,90 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// An encoded result which is known to be ASCII, returned by
+/// [`AlphaId::encode_ascii`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AsciiId(Vec<u8>);
+
+impl AsciiId {
+    /// Returns the result as a string slice, without any check.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: ASCII is valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        // SAFETY: ASCII is valid UTF-8.
+        unsafe { String::from_utf8_unchecked(self.0) }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl Deref for AsciiId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for AsciiId {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for AsciiId {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Display for AsciiId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<AsciiId> for String {
+    fn from(id: AsciiId) -> Self {
+        id.into_string()
+    }
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode the numbers into an [`AsciiId`], which is a `str` without
+    /// UTF-8 validation.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` unless the alphabet, the
+    /// affixes and the separator are all ASCII, which is checked once when
+    /// building.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let id = alphaid.encode_ascii(1350997667).unwrap();
+    /// assert_eq!(id.as_str(), "90F7qb");
+    /// assert_eq!(alphaid.decode(&id), Ok(1350997667));
+    /// ```
+    pub fn encode_ascii(&self, n: T) -> Result<AsciiId, AlphaIdError> {
+        if !self.ascii {
+            return Err(AlphaIdError::InvalidConfig);
+        }
+        self.encode(n).map(AsciiId)
+    }
+}
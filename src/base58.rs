@@ -0,0 +1,106 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+
+use crate::AlphaIdError;
+
+/// The Bitcoin alphabet.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Returns the first 4 bytes of the double SHA-256 of `data`.
+fn checksum(data: &[u8]) -> [u8; 4] {
+    let hash = Sha256::digest(Sha256::digest(data));
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Encodes a version byte and a payload as Base58Check, e.g. Bitcoin
+/// addresses and WIF keys.
+///
+/// The version byte is prepended and the first 4 bytes of the double
+/// SHA-256 are appended, every leading zero byte is encoded as `1`.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{decode_base58check, encode_base58check};
+///
+/// let hash160 = [
+///     0x01, 0x09, 0x66, 0x77, 0x60, 0x06, 0x95, 0x3d, 0x55, 0x67, 0x43, 0x9e, 0x5e, 0x39,
+///     0xf8, 0x6a, 0x0d, 0x27, 0x3b, 0xee,
+/// ];
+/// let address = encode_base58check(0, &hash160);
+/// assert_eq!(address, "16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM");
+/// assert_eq!(decode_base58check(&address), Ok((0, hash160.to_vec())));
+/// ```
+pub fn encode_base58check(version: u8, payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(payload.len() + 5);
+    data.push(version);
+    data.extend_from_slice(payload);
+    let sum = checksum(&data);
+    data.extend_from_slice(&sum);
+
+    // The base 58 digits, least significant first.
+    let mut digits: Vec<u8> = Vec::with_capacity(data.len() * 138 / 100 + 1);
+    for &b in &data {
+        let mut carry = u32::from(b);
+        for d in digits.iter_mut() {
+            carry += u32::from(*d) << 8;
+            *d = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let zeros = data.iter().take_while(|&&b| b == 0).count();
+    let mut out = String::with_capacity(zeros + digits.len());
+    out.extend(core::iter::repeat_n('1', zeros));
+    out.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&d| char::from(ALPHABET[d as usize])),
+    );
+    out
+}
+
+/// Decodes Base58Check into the version byte and the payload, see
+/// [`encode_base58check`].
+///
+/// Returns `AlphaIdError::UnexpectedChar` for characters outside of the
+/// alphabet, `AlphaIdError::InvalidLength` if there is no version byte and
+/// checksum, and `AlphaIdError::ChecksumMismatch` if the checksum is wrong.
+pub fn decode_base58check(s: &str) -> Result<(u8, Vec<u8>), AlphaIdError> {
+    // The bytes, least significant first.
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len() * 733 / 1000 + 1);
+    for c in s.bytes() {
+        let mut carry = ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or(AlphaIdError::UnexpectedChar)? as u32;
+        for b in bytes.iter_mut() {
+            carry += u32::from(*b) * 58;
+            *b = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let zeros = s.bytes().take_while(|&c| c == b'1').count();
+    let mut data = alloc::vec![0; zeros];
+    data.extend(bytes.iter().rev());
+    if data.len() < 5 {
+        return Err(AlphaIdError::InvalidLength);
+    }
+
+    let (data, sum) = data.split_at(data.len() - 4);
+    if checksum(data) != sum {
+        return Err(AlphaIdError::ChecksumMismatch);
+    }
+    Ok((data[0], data[1..].to_vec()))
+}
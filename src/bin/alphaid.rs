@@ -0,0 +1,110 @@
+//! Encode and decode numbers from the command line.
+//!
+//! ```text
+//! alphaid [OPTIONS] encode [NUMBER]...
+//! alphaid [OPTIONS] decode [ID]...
+//! ```
+//!
+//! Without arguments the numbers or IDs are read from stdin, one per line.
+//! The options are the keys of `Builder::parse`, e.g. `--chars`, `--pad`
+//! and `--prefix`, and `--config` for a whole `key=value;...` string.
+
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use alphaid::{AlphaId, Builder};
+
+const USAGE: &str =
+    "usage: alphaid [--config SETTINGS] [--KEY VALUE]... (encode|decode) [VALUE]...";
+
+enum Command {
+    Encode,
+    Decode,
+}
+
+fn main() {
+    let mut settings = Vec::new();
+    let mut command = None;
+    let mut values = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if command.is_some() {
+            values.push(arg);
+        } else if arg == "-h" || arg == "--help" {
+            println!("{}", USAGE);
+            return;
+        } else if let Some(key) = arg.strip_prefix("--") {
+            let value = args
+                .next()
+                .unwrap_or_else(|| fail(&format!("missing value for {}", arg)));
+            if key == "config" {
+                settings.push(value);
+            } else if value.contains(';') {
+                fail(&format!("invalid value for {}", arg));
+            } else {
+                settings.push(format!("{}={}", key.replace('-', "_"), value));
+            }
+        } else {
+            command = Some(match arg.as_str() {
+                "encode" => Command::Encode,
+                "decode" => Command::Decode,
+                _ => fail(USAGE),
+            });
+        }
+    }
+    let command = command.unwrap_or_else(|| fail(USAGE));
+
+    let alphaid = Builder::<u128>::parse(&settings.join(";"))
+        .unwrap_or_else(|e| fail(&e.to_string()))
+        .build();
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut ok = true;
+    let mut run = |value: &str| {
+        match run(&alphaid, &command, value) {
+            Ok(result) => writeln!(out, "{}", result),
+            Err(e) => {
+                eprintln!("{}: {}", value, e);
+                ok = false;
+                Ok(())
+            }
+        }
+        .unwrap_or_else(|e| fail(&e.to_string()));
+    };
+
+    if values.is_empty() {
+        for line in io::stdin().lock().lines() {
+            let line = line.unwrap_or_else(|e| fail(&e.to_string()));
+            let value = line.trim();
+            if !value.is_empty() {
+                run(value);
+            }
+        }
+    } else {
+        values.iter().for_each(|value| run(value));
+    }
+
+    if !ok {
+        process::exit(1);
+    }
+}
+
+fn run(alphaid: &AlphaId<u128>, command: &Command, value: &str) -> Result<String, String> {
+    match command {
+        Command::Encode => {
+            let n = value.parse().map_err(|_| "invalid number".to_string())?;
+            alphaid.encode_string(n).map_err(|e| e.to_string())
+        }
+        Command::Decode => alphaid
+            .decode(value)
+            .map(|n| n.to_string())
+            .map_err(|e| e.to_string()),
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("alphaid: {}", message);
+    process::exit(2);
+}
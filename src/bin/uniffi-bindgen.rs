@@ -0,0 +1,6 @@
+//! Generates the Swift and Kotlin bindings of the `uniffi` feature from the
+//! built library, see the `alphaid::uniffi` module.
+
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
@@ -0,0 +1,116 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::CharClass;
+
+/// Words which must not appear in, or be equal to, the encoded result.
+#[derive(Clone, Default)]
+pub(crate) struct Blocklist {
+    words: Vec<Vec<u8>>,
+    reserved: Vec<Vec<u8>>,
+    /// Whether results of only ASCII digits are blocked.
+    numeric: bool,
+    /// The class results must start with.
+    first: Option<CharClass>,
+    /// Whether an empty word was added, which fails the build.
+    empty_word: bool,
+}
+
+impl Blocklist {
+    pub(crate) fn extend<S: AsRef<str>>(&mut self, words: &[S]) {
+        for word in words {
+            let word = word.as_ref();
+            if word.is_empty() {
+                self.empty_word = true;
+                continue;
+            }
+            self.words.push(fold(word.as_bytes()));
+        }
+    }
+
+    pub(crate) fn reserve<S: AsRef<str>>(&mut self, words: &[S]) {
+        self.reserved
+            .extend(words.iter().map(|w| fold(w.as_ref().as_bytes())));
+    }
+
+    pub(crate) fn has_empty_word(&self) -> bool {
+        self.empty_word
+    }
+
+    pub(crate) fn block_numeric(&mut self) {
+        self.numeric = true;
+    }
+
+    pub(crate) fn blocks_numeric(&self) -> bool {
+        self.numeric
+    }
+
+    pub(crate) fn require_first(&mut self, class: CharClass) {
+        self.first = Some(class);
+    }
+
+    pub(crate) fn first(&self) -> Option<&CharClass> {
+        self.first.as_ref()
+    }
+
+    pub(crate) fn words(&self) -> Vec<String> {
+        self.words
+            .iter()
+            .map(|w| String::from_utf8_lossy(w).into_owned())
+            .collect()
+    }
+
+    pub(crate) fn reserved(&self) -> Vec<String> {
+        self.reserved
+            .iter()
+            .map(|w| String::from_utf8_lossy(w).into_owned())
+            .collect()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.words.is_empty() && self.reserved.is_empty() && !self.numeric && self.first.is_none()
+    }
+
+    /// Returns true if `v` contains any of the words or equals any of the
+    /// reserved words, ignoring case, only consists of ASCII digits
+    /// if those are blocked, or does not start with the required class.
+    pub(crate) fn matches(&self, v: &[u8]) -> bool {
+        if self.numeric && v.iter().all(u8::is_ascii_digit) {
+            return true;
+        }
+        if let (Some(class), Some(&b)) = (&self.first, v.first()) {
+            if !class.contains(b) {
+                return true;
+            }
+        }
+        let v = fold(v);
+        self.reserved.contains(&v)
+            || self
+                .words
+                .iter()
+                .any(|word| v.windows(word.len()).any(|w| w == &word[..]))
+    }
+
+    /// Returns true if `v` equals any of the reserved words, ignoring case.
+    pub(crate) fn is_reserved(&self, v: &[u8]) -> bool {
+        self.reserved.contains(&fold(v))
+    }
+}
+
+/// Folds the case of the words and of the encoded results alike: Unicode
+/// lower case for UTF-8, ASCII lower case for raw bytes.
+fn fold(v: &[u8]) -> Vec<u8> {
+    match core::str::from_utf8(v) {
+        Ok(s) => s.to_lowercase().into_bytes(),
+        Err(_) => v.to_ascii_lowercase(),
+    }
+}
+
+/// A list of common English profanity.
+#[cfg(feature = "blocklist")]
+pub const ENGLISH_BLOCKLIST: &[&str] = &[
+    "anal", "anus", "arse", "ass", "bastard", "bitch", "boob", "butt", "cock", "crap", "cum",
+    "cunt", "damn", "dick", "dildo", "dyke", "fag", "fuck", "hell", "homo", "jizz", "kike", "nazi",
+    "nigg", "penis", "piss", "poop", "porn", "pussy", "rape", "scum", "sex", "shit", "slut",
+    "spic", "tit", "twat", "vagina", "wank", "whore",
+];
@@ -0,0 +1,133 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::hash::Hash;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// A map keeping the `capacity` most recently used entries.
+struct Lru<K, V> {
+    capacity: usize,
+    /// Incremented on every use, the tick of an entry is its last use.
+    tick: u64,
+    entries: HashMap<K, (V, u64)>,
+    /// The keys by the tick of their last use, least recent first.
+    order: BTreeMap<u64, K>,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Lru<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+        }
+    }
+
+    fn get<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (value, tick) = self.entries.get_mut(key)?;
+        let k = self.order.remove(tick).expect("every entry is ordered");
+        self.tick += 1;
+        *tick = self.tick;
+        self.order.insert(self.tick, k);
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        if let Some((_, tick)) = self.entries.remove(&key) {
+            self.order.remove(&tick);
+        } else if self.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = self.order.pop_first() {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, (value, self.tick));
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+/// Memoizes the results of an `AlphaId`, keeping the `capacity` most
+/// recently encoded numbers and decoded inputs each.
+///
+/// Errors are not cached. The cache can be shared between threads.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, CachedAlphaId};
+///
+/// let cached = CachedAlphaId::new(AlphaId::<u64>::new(), 4096);
+/// assert_eq!(cached.decode(b"90F7qb"), Ok(1350997667));
+/// assert_eq!(cached.decode(b"90F7qb"), Ok(1350997667));
+/// assert_eq!(cached.encode(1350997667), Ok(b"90F7qb".to_vec()));
+/// ```
+pub struct CachedAlphaId<T: UnsignedInteger = u128, M = ()> {
+    alphaid: AlphaId<T, M>,
+    encoded: Mutex<Lru<T, Vec<u8>>>,
+    decoded: Mutex<Lru<Vec<u8>, T>>,
+}
+
+impl<T: UnsignedInteger + Hash, M> CachedAlphaId<T, M> {
+    /// Creates a cache in front of `alphaid`. A capacity of zero caches
+    /// nothing.
+    pub fn new(alphaid: AlphaId<T, M>, capacity: usize) -> Self {
+        Self {
+            alphaid,
+            encoded: Mutex::new(Lru::new(capacity)),
+            decoded: Mutex::new(Lru::new(capacity)),
+        }
+    }
+
+    /// Returns the cached `AlphaId`.
+    pub fn alphaid(&self) -> &AlphaId<T, M> {
+        &self.alphaid
+    }
+
+    /// Encode the number like [`AlphaId::encode`], reusing a cached result.
+    pub fn encode(&self, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        if let Some(v) = lock(&self.encoded).get(&n) {
+            return Ok(v);
+        }
+        let v = self.alphaid.encode(n)?;
+        lock(&self.encoded).insert(n, v.clone());
+        Ok(v)
+    }
+
+    /// Decode the input like [`AlphaId::decode`], reusing a cached result.
+    pub fn decode<V: AsRef<[u8]>>(&self, v: V) -> Result<T, AlphaIdError> {
+        let v = v.as_ref();
+        if let Some(n) = lock(&self.decoded).get(v) {
+            return Ok(n);
+        }
+        let n = self.alphaid.decode(v)?;
+        lock(&self.decoded).insert(v.to_vec(), n);
+        Ok(n)
+    }
+
+    /// Removes every cached result.
+    pub fn clear(&self) {
+        lock(&self.encoded).clear();
+        lock(&self.decoded).clear();
+    }
+}
+
+/// Locks the mutex, ignoring poisoning since the cache stays consistent.
+fn lock<L>(mutex: &Mutex<L>) -> std::sync::MutexGuard<'_, L> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -0,0 +1,39 @@
+use alloc::vec::Vec;
+
+/// A set of characters, see [`Builder::first_char`](crate::Builder::first_char).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum CharClass {
+    /// ASCII letters.
+    Letter,
+    /// ASCII letters and digits.
+    Alphanumeric,
+    /// Every byte except the listed ones.
+    NoneOf(Vec<u8>),
+}
+
+impl CharClass {
+    /// Returns true if the byte is in the class.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::CharClass;
+    ///
+    /// assert!(CharClass::Letter.contains(b'a'));
+    /// assert!(!CharClass::Alphanumeric.contains(b'-'));
+    /// assert!(!CharClass::NoneOf(b"-_".to_vec()).contains(b'_'));
+    /// ```
+    pub fn contains(&self, b: u8) -> bool {
+        match self {
+            CharClass::Letter => b.is_ascii_alphabetic(),
+            CharClass::Alphanumeric => b.is_ascii_alphanumeric(),
+            CharClass::NoneOf(bytes) => !bytes.contains(&b),
+        }
+    }
+}
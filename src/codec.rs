@@ -0,0 +1,111 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "php")]
+use crate::PhpAlphaId;
+use crate::{AlphaId, AlphaId128, AlphaId64, AlphaIdConst, AlphaIdError, UnsignedInteger};
+
+/// The encoders and decoders of numbers of type `T`, so that they can be
+/// swapped behind a `dyn Codec<T>`.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, AlphaId64, Codec};
+///
+/// let codecs: Vec<Box<dyn Codec<u64>>> = vec![
+///     Box::new(AlphaId::<u64>::new()),
+///     Box::new(AlphaId64::default()),
+/// ];
+/// for codec in &codecs {
+///     let v = codec.encode(1350997667).unwrap();
+///     assert_eq!(v, b"90F7qb");
+///     assert!(v.len() <= codec.max_len());
+///     assert_eq!(codec.decode(&v), Ok(1350997667));
+/// }
+/// ```
+pub trait Codec<T> {
+    /// Encode the numbers.
+    fn encode(&self, n: T) -> Result<Vec<u8>, AlphaIdError>;
+
+    /// Decode into numbers.
+    fn decode(&self, v: &[u8]) -> Result<T, AlphaIdError>;
+
+    /// Returns an upper bound of the length in bytes of an encoded result.
+    fn max_len(&self) -> usize;
+}
+
+impl<T: UnsignedInteger, M> Codec<T> for AlphaId<T, M> {
+    fn encode(&self, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        AlphaId::encode(self, n)
+    }
+
+    fn decode(&self, v: &[u8]) -> Result<T, AlphaIdError> {
+        AlphaId::decode(self, v)
+    }
+
+    fn max_len(&self) -> usize {
+        self.max_encoded_len()
+    }
+}
+
+impl<T: UnsignedInteger, const BASE: usize> Codec<T> for AlphaIdConst<T, BASE> {
+    fn encode(&self, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        AlphaIdConst::encode(self, n)
+    }
+
+    fn decode(&self, v: &[u8]) -> Result<T, AlphaIdError> {
+        AlphaIdConst::decode(self, v)
+    }
+
+    fn max_len(&self) -> usize {
+        let base = match T::from_usize(BASE) {
+            Some(base) => base,
+            None => return 1,
+        };
+        let mut len = 1;
+        let mut n = T::max_value();
+        while n >= base {
+            n = n / base;
+            len += 1;
+        }
+        len
+    }
+}
+
+macro_rules! impl_fast_codec {
+    ($name:ident, $int:ty) => {
+        impl Codec<$int> for $name {
+            fn encode(&self, n: $int) -> Result<Vec<u8>, AlphaIdError> {
+                Ok($name::encode(self, n))
+            }
+
+            fn decode(&self, v: &[u8]) -> Result<$int, AlphaIdError> {
+                $name::decode(self, v)
+            }
+
+            fn max_len(&self) -> usize {
+                $name::encode(self, <$int>::MAX).len()
+            }
+        }
+    };
+}
+
+impl_fast_codec!(AlphaId64, u64);
+impl_fast_codec!(AlphaId128, u128);
+
+#[cfg(feature = "php")]
+impl Codec<u64> for PhpAlphaId {
+    fn encode(&self, n: u64) -> Result<Vec<u8>, AlphaIdError> {
+        PhpAlphaId::encode(self, n).map(String::into_bytes)
+    }
+
+    fn decode(&self, v: &[u8]) -> Result<u64, AlphaIdError> {
+        let s = core::str::from_utf8(v).map_err(|_| AlphaIdError::UnexpectedChar)?;
+        PhpAlphaId::decode(self, s)
+    }
+
+    fn max_len(&self) -> usize {
+        // `u64::MAX` has 11 digits in base 62.
+        11
+    }
+}
@@ -0,0 +1,498 @@
+use crate::blocklist::Blocklist;
+use crate::{
+    from_u128, AlphaId, AlphaIdError, Builder, CharClass, DigitOrder, PadMode, UnsignedInteger,
+    DEFAULT_SEED,
+};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// The keys understood by [`Builder::parse`] and [`Builder::from_env`].
+#[cfg(feature = "std")]
+const KEYS: &[&str] = &[
+    "chars",
+    "pad",
+    "pad_char",
+    "sign",
+    "none_symbol",
+    "shuffle_seed",
+    "permute_digits",
+    "pad_mode",
+    "order",
+    "sortable",
+    "group",
+    "prefix",
+    "suffix",
+    "block_words",
+    "reserved",
+    "never_numeric",
+    "first_char",
+    "reject_empty",
+    "max_input_len",
+    "max_value",
+    "offset",
+    "small_alphabet",
+];
+
+/// The complete settings of an `AlphaId`, e.g. to share them between
+/// services through a configuration file.
+///
+/// The alphabet is stored as it is used for encoding, after sorting for
+/// the sortable mode and swapping in the padding character.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, Config};
+///
+/// let alphaid = AlphaId::<u32>::builder().pad(5).prefix(b"usr_").build();
+/// let config = alphaid.to_config();
+/// assert_eq!(config.pad, 5);
+///
+/// let copy = AlphaId::<u32>::from_config(config);
+/// assert_eq!(copy.encode(0), alphaid.encode(0));
+/// ```
+///
+/// With the `serde` feature the settings can be (de)serialized, missing
+/// fields take their default values.
+///
+/// The keys of [`Builder::tag`] and [`Builder::old_tag_keys`] are secrets
+/// which are part of the settings, so serialized settings must be stored
+/// like any other secret. `Debug` prints them redacted.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(default)
+)]
+#[non_exhaustive]
+pub struct Config {
+    /// The characters set, empty if `symbols` is used.
+    pub chars: Vec<u8>,
+    /// The symbols set by [`Builder::chars_str`], empty if `chars` is used.
+    pub symbols: Vec<String>,
+    pub pad: u32,
+    pub pad_mode: PadMode,
+    pub order: DigitOrder,
+    /// The width of the sortable mode.
+    pub width: Option<u32>,
+    /// The group size and the separator.
+    pub group: Option<(usize, u8)>,
+    pub prefix: Vec<u8>,
+    pub suffix: Vec<u8>,
+    pub confusables: Vec<(Vec<u8>, u8)>,
+    pub blocked_words: Vec<String>,
+    pub reserved_words: Vec<String>,
+    /// See [`Builder::never_numeric`].
+    pub never_numeric: bool,
+    /// See [`Builder::first_char`].
+    pub first_char: Option<CharClass>,
+    pub reject_empty: bool,
+    pub max_input_len: Option<usize>,
+    /// The SipHash key and the length of the tag.
+    pub tag: Option<([u8; 16], u32)>,
+    /// The symbol of negative numbers.
+    pub sign: Option<u8>,
+    /// The symbol of `None`.
+    pub none_symbol: Option<u8>,
+    /// See [`Builder::max_len`].
+    pub max_len: Option<usize>,
+    /// See [`Builder::permute_digits`].
+    pub digit_seed: Option<u64>,
+    /// See [`Builder::max_value`].
+    pub max_value: Option<u128>,
+    /// See [`Builder::offset`].
+    pub offset: Option<u128>,
+    /// See [`Builder::tag_key_id`].
+    pub tag_key_id: bool,
+    pub old_tag_keys: Vec<[u8; 16]>,
+    /// See [`Builder::allow_raw_bytes`].
+    pub allow_raw_bytes: bool,
+    /// See [`Builder::allow_small_alphabet`].
+    pub allow_small_alphabet: bool,
+}
+
+/// Prints the settings with the tag keys redacted.
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Redacted;
+
+        impl fmt::Debug for Redacted {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("<redacted>")
+            }
+        }
+
+        f.debug_struct("Config")
+            .field("chars", &self.chars)
+            .field("symbols", &self.symbols)
+            .field("pad", &self.pad)
+            .field("pad_mode", &self.pad_mode)
+            .field("order", &self.order)
+            .field("width", &self.width)
+            .field("group", &self.group)
+            .field("prefix", &self.prefix)
+            .field("suffix", &self.suffix)
+            .field("confusables", &self.confusables)
+            .field("blocked_words", &self.blocked_words)
+            .field("reserved_words", &self.reserved_words)
+            .field("never_numeric", &self.never_numeric)
+            .field("first_char", &self.first_char)
+            .field("reject_empty", &self.reject_empty)
+            .field("max_input_len", &self.max_input_len)
+            .field("tag", &self.tag.map(|(_, len)| (Redacted, len)))
+            .field("sign", &self.sign)
+            .field("none_symbol", &self.none_symbol)
+            .field("max_len", &self.max_len)
+            .field("digit_seed", &self.digit_seed)
+            .field("max_value", &self.max_value)
+            .field("offset", &self.offset)
+            .field("tag_key_id", &self.tag_key_id)
+            .field(
+                "old_tag_keys",
+                &self
+                    .old_tag_keys
+                    .iter()
+                    .map(|_| Redacted)
+                    .collect::<Vec<_>>(),
+            )
+            .field("allow_raw_bytes", &self.allow_raw_bytes)
+            .field("allow_small_alphabet", &self.allow_small_alphabet)
+            .finish()
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            chars: DEFAULT_SEED.as_bytes().to_vec(),
+            symbols: Vec::new(),
+            pad: 1,
+            pad_mode: PadMode::default(),
+            order: DigitOrder::default(),
+            width: None,
+            group: None,
+            prefix: Vec::new(),
+            suffix: Vec::new(),
+            confusables: Vec::new(),
+            blocked_words: Vec::new(),
+            reserved_words: Vec::new(),
+            never_numeric: false,
+            first_char: None,
+            reject_empty: false,
+            max_input_len: None,
+            tag: None,
+            sign: None,
+            none_symbol: None,
+            max_len: None,
+            digit_seed: None,
+            max_value: None,
+            offset: None,
+            tag_key_id: false,
+            old_tag_keys: Vec::new(),
+            allow_raw_bytes: false,
+            allow_small_alphabet: false,
+        }
+    }
+}
+
+impl<T: UnsignedInteger> Builder<T> {
+    /// Constructs a new `Builder` from the settings.
+    ///
+    /// The settings are checked when building, see
+    /// [`AlphaId::try_from_config`].
+    pub fn from_config(config: Config) -> Self {
+        let mut blocklist = Blocklist::default();
+        blocklist.extend(&config.blocked_words);
+        blocklist.reserve(&config.reserved_words);
+        if config.never_numeric {
+            blocklist.block_numeric();
+        }
+        if let Some(class) = config.first_char {
+            blocklist.require_first(class);
+        }
+
+        let (chars, symbols) = if config.symbols.is_empty() {
+            let chars = if config.chars == DEFAULT_SEED.as_bytes() {
+                Cow::Borrowed(DEFAULT_SEED.as_bytes())
+            } else {
+                Cow::Owned(config.chars)
+            };
+            (Some(chars), None)
+        } else {
+            (None, Some(config.symbols))
+        };
+
+        Self {
+            chars,
+            symbols,
+            pad: Some(config.pad),
+            pad_char: None,
+            pad_mode: config.pad_mode,
+            width: config.width,
+            group: config.group,
+            prefix: config.prefix,
+            suffix: config.suffix,
+            confusables: config.confusables,
+            blocklist,
+            order: config.order,
+            reject_empty: config.reject_empty,
+            max_input_len: config.max_input_len,
+            tag: config.tag,
+            sign: config.sign,
+            none_symbol: config.none_symbol,
+            max_len: config.max_len,
+            digit_seed: config.digit_seed,
+            max_value: config.max_value,
+            offset: config.offset,
+            tag_key_id: config.tag_key_id,
+            old_tag_keys: config.old_tag_keys,
+            obfuscators: Vec::new(),
+            url_select_safe: false,
+            shuffle_seed: None,
+            allow_raw_bytes: config.allow_raw_bytes,
+            allow_small_alphabet: config.allow_small_alphabet,
+            _data: PhantomData,
+        }
+    }
+
+    /// Constructs a new `Builder` from `key=value` settings separated by
+    /// `;`, e.g. `chars=0123456789abcdefghij;pad=3;prefix=usr_`.
+    ///
+    /// The keys are `chars`, `pad`, `pad_char`, `sign`, `none_symbol`,
+    /// `pad_mode` (`shift`, `random` with the `rand` feature or `fill`), `order` (`little_endian`
+    /// or `big_endian`), `sortable`, `group` (`size:separator`), `prefix`,
+    /// `suffix`, `block_words` and `reserved` (comma separated),
+    /// `never_numeric`, `first_char` (`letter`, `alphanumeric` or
+    /// `none_of:` followed by the bytes), `reject_empty`, `max_input_len`,
+    /// `max_value`, `offset`, `small_alphabet`, `shuffle_seed` and
+    /// `permute_digits`.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` for unknown keys, invalid
+    /// values, and settings which [`build`](Builder::build) would panic on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaIdError, Builder};
+    ///
+    /// let alphaid = Builder::<u32>::parse("pad=3;prefix=usr_").unwrap().build();
+    /// assert_eq!(alphaid.encode(0), Ok(b"usr_aab".to_vec()));
+    ///
+    /// assert!(matches!(Builder::<u32>::parse("pad=0"), Err(AlphaIdError::InvalidConfig)));
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, AlphaIdError> {
+        let mut builder = Self::new();
+        for setting in s.split(';').filter(|s| !s.trim().is_empty()) {
+            let (key, value) = setting.split_once('=').ok_or(AlphaIdError::InvalidConfig)?;
+            builder = builder.set(key.trim(), value)?;
+        }
+        builder.validate()?;
+        Ok(builder)
+    }
+
+    /// Constructs a new `Builder` from the environment variables named by
+    /// `prefix` and the upper case keys of [`parse`](Builder::parse), e.g.
+    /// `ALPHAID_CHARS` and `ALPHAID_PAD` for the prefix `ALPHAID_`.
+    ///
+    /// Unset variables keep their default values.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` like [`parse`](Builder::parse)
+    /// does, and for values which are not valid Unicode.
+    #[cfg(feature = "std")]
+    pub fn from_env(prefix: &str) -> Result<Self, AlphaIdError> {
+        let mut builder = Self::new();
+        for key in KEYS {
+            let name = alloc::format!("{}{}", prefix, key.to_ascii_uppercase());
+            match std::env::var(&name) {
+                Ok(value) => builder = builder.set(key, &value)?,
+                Err(std::env::VarError::NotPresent) => {}
+                Err(std::env::VarError::NotUnicode(_)) => return Err(AlphaIdError::InvalidConfig),
+            }
+        }
+        builder.validate()?;
+        Ok(builder)
+    }
+
+    /// Applies a single setting of [`parse`](Builder::parse).
+    fn set(self, key: &str, value: &str) -> Result<Self, AlphaIdError> {
+        fn number<N: FromStr>(value: &str) -> Result<N, AlphaIdError> {
+            value
+                .trim()
+                .parse()
+                .map_err(|_| AlphaIdError::InvalidConfig)
+        }
+        fn flag(value: &str) -> Result<bool, AlphaIdError> {
+            match value.trim() {
+                "true" | "1" => Ok(true),
+                "false" | "0" => Ok(false),
+                _ => Err(AlphaIdError::InvalidConfig),
+            }
+        }
+        fn byte(value: &str) -> Result<u8, AlphaIdError> {
+            match value.as_bytes() {
+                &[b] => Ok(b),
+                _ => Err(AlphaIdError::InvalidConfig),
+            }
+        }
+        // Empty words are rejected when validating, like with `from_config`.
+        fn words(value: &str) -> Result<Vec<&str>, AlphaIdError> {
+            Ok(value.split(',').map(str::trim).collect())
+        }
+
+        Ok(match key {
+            "chars" => self.chars_str(value),
+            "pad" => match number(value)? {
+                0 => return Err(AlphaIdError::InvalidConfig),
+                pad => self.pad(pad),
+            },
+            "pad_char" => self.pad_char(byte(value)?),
+            "sign" => self.sign(byte(value)?),
+            "none_symbol" => self.none_symbol(byte(value)?),
+            "shuffle_seed" => self.shuffle_seed(number(value)?),
+            "permute_digits" => self.permute_digits(number(value)?),
+            "pad_mode" => self.pad_mode(match value.trim() {
+                "shift" => PadMode::Shift,
+                "random" => PadMode::Random,
+                "fill" => PadMode::Fill,
+                _ => return Err(AlphaIdError::InvalidConfig),
+            }),
+            "order" => self.order(match value.trim() {
+                "little_endian" => DigitOrder::LittleEndian,
+                "big_endian" => DigitOrder::BigEndian,
+                _ => return Err(AlphaIdError::InvalidConfig),
+            }),
+            "sortable" => match number(value)? {
+                0 => return Err(AlphaIdError::InvalidConfig),
+                width => self.sortable(width),
+            },
+            "group" => {
+                let (size, sep) = value.split_once(':').ok_or(AlphaIdError::InvalidConfig)?;
+                match number(size)? {
+                    0 => return Err(AlphaIdError::InvalidConfig),
+                    size => self.group(size, byte(sep)?),
+                }
+            }
+            "prefix" => self.prefix(value.as_bytes()),
+            "suffix" => self.suffix(value.as_bytes()),
+            "block_words" => self.block_words(&words(value)?),
+            "reserved" => self.reserved(&words(value)?),
+            "reject_empty" => self.reject_empty(flag(value)?),
+            "never_numeric" if flag(value)? => self.never_numeric(),
+            "never_numeric" => self,
+            "first_char" => self.first_char(match value.trim() {
+                "letter" => CharClass::Letter,
+                "alphanumeric" => CharClass::Alphanumeric,
+                value => match value.strip_prefix("none_of:") {
+                    Some(bytes) if !bytes.is_empty() => {
+                        CharClass::NoneOf(bytes.as_bytes().to_vec())
+                    }
+                    _ => return Err(AlphaIdError::InvalidConfig),
+                },
+            }),
+            "small_alphabet" if flag(value)? => self.allow_small_alphabet(),
+            "small_alphabet" => self,
+            "max_input_len" => self.max_input_len(number(value)?),
+            "max_value" => {
+                let max: u128 = number(value)?;
+                self.max_value(from_u128(max).ok_or(AlphaIdError::InvalidConfig)?)
+            }
+            "offset" => {
+                let offset: u128 = number(value)?;
+                self.offset(from_u128(offset).ok_or(AlphaIdError::InvalidConfig)?)
+            }
+            _ => return Err(AlphaIdError::InvalidConfig),
+        })
+    }
+
+    /// Returns `AlphaIdError::InvalidConfig` if [`build`](Builder::build)
+    /// would panic.
+    pub(crate) fn validate(&self) -> Result<(), AlphaIdError> {
+        self.clone()
+            .try_build_marked::<()>()
+            .map(drop)
+            .map_err(|_| AlphaIdError::InvalidConfig)
+    }
+}
+
+impl<T: UnsignedInteger> AlphaId<T> {
+    /// Creates a new `AlphaId` from the settings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the settings are invalid, see [`Builder::build`] and
+    /// [`try_from_config`](AlphaId::try_from_config).
+    pub fn from_config(config: Config) -> Self {
+        Builder::from_config(config).build()
+    }
+
+    /// Creates a new `AlphaId` from the settings, e.g. loaded from a file.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` for the settings which
+    /// [`from_config`](AlphaId::from_config) would panic on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError, Config};
+    ///
+    /// let mut config = Config::default();
+    /// config.pad = 3;
+    /// let alphaid = AlphaId::<u32>::try_from_config(config.clone()).unwrap();
+    /// assert_eq!(alphaid.encode(0), Ok(b"aab".to_vec()));
+    ///
+    /// config.blocked_words = vec![String::new()];
+    /// assert_eq!(
+    ///     AlphaId::<u32>::try_from_config(config).err(),
+    ///     Some(AlphaIdError::InvalidConfig)
+    /// );
+    /// ```
+    pub fn try_from_config(config: Config) -> Result<Self, AlphaIdError> {
+        Builder::from_config(config)
+            .try_build_marked()
+            .map_err(|_| AlphaIdError::InvalidConfig)
+    }
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Returns the settings of this `AlphaId`, including the tag keys, see
+    /// [`Config`].
+    pub fn to_config(&self) -> Config {
+        Config {
+            chars: self.chars.to_vec(),
+            symbols: self.symbols.to_vec(),
+            pad: self.pad,
+            pad_mode: self.pad_mode,
+            order: self.order,
+            width: self.width,
+            group: self.group,
+            prefix: self.prefix.to_vec(),
+            suffix: self.suffix.to_vec(),
+            confusables: self.confusables.to_vec(),
+            blocked_words: self.blocklist.words(),
+            reserved_words: self.blocklist.reserved(),
+            never_numeric: self.blocklist.blocks_numeric(),
+            first_char: self.blocklist.first().cloned(),
+            reject_empty: self.reject_empty,
+            max_input_len: self.max_input_len,
+            tag: self.tag,
+            sign: self.sign,
+            none_symbol: self.none_symbol,
+            max_len: self.max_len,
+            digit_seed: self.digit_seed,
+            max_value: self.max_value,
+            offset: self.offset,
+            tag_key_id: self.tag_key_id,
+            old_tag_keys: self.old_tag_keys.to_vec(),
+            allow_raw_bytes: !self.chars.iter().all(u8::is_ascii_graphic)
+                || self
+                    .symbols
+                    .iter()
+                    .any(|s| s.chars().any(|c| c.is_control() || c.is_whitespace())),
+            allow_small_alphabet: self.chars.len().max(self.symbols.len()) <= 16,
+        }
+    }
+}
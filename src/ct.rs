@@ -0,0 +1,89 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// Returns 1 if `a == b` and 0 otherwise, without branching.
+fn ct_eq(a: u8, b: u8) -> u8 {
+    ((u16::from(a ^ b)).wrapping_sub(1) >> 8) as u8 & 1
+}
+
+/// Returns 1 if the slices of the same length are equal, looking at every
+/// byte.
+fn ct_eq_slice(a: &[u8], b: &[u8]) -> u8 {
+    a.iter().zip(b).fold(1, |eq, (&a, &b)| eq & ct_eq(a, b))
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Decode like [`decode`](AlphaId::decode) without table lookups or
+    /// early returns which depend on the characters of the input.
+    ///
+    /// Every byte is compared with the whole alphabet and the affixes are
+    /// compared byte by byte, the errors are only reported once the whole
+    /// input was processed. The length of the input and the positions of
+    /// the [`group`](crate::Builder::group) separators are not hidden.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` for alphabets set by
+    /// [`Builder::chars_str`](crate::Builder::chars_str) and with
+    /// [`Builder::map_confusables`](crate::Builder::map_confusables).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError};
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.decode_ct(b"90F7qb"), Ok(1350997667));
+    /// assert_eq!(alphaid.decode_ct(b"90F!qb"), Err(AlphaIdError::UnexpectedChar));
+    /// ```
+    pub fn decode_ct<V: AsRef<[u8]>>(&self, v: V) -> Result<T, AlphaIdError> {
+        let v = v.as_ref();
+        self.check_input_len(v.len())?;
+        if !self.symbols.is_empty() || !self.confusables.is_empty() {
+            return Err(AlphaIdError::InvalidConfig);
+        }
+        if v.len() < self.prefix.len() + self.suffix.len() {
+            return match v.get(..self.prefix.len()) {
+                Some(prefix) if ct_eq_slice(prefix, &self.prefix) == 1 => {
+                    Err(AlphaIdError::SuffixMissed)
+                }
+                _ => Err(AlphaIdError::PrefixMissed),
+            };
+        }
+
+        let (prefix, rest) = v.split_at(self.prefix.len());
+        let (body, suffix) = rest.split_at(rest.len() - self.suffix.len());
+        let prefix_ok = ct_eq_slice(prefix, &self.prefix);
+        let suffix_ok = ct_eq_slice(suffix, &self.suffix);
+
+        let sep = self.group.map(|(_, sep)| sep);
+        let mut found_all = 1;
+        let mut digits = Vec::with_capacity(body.len());
+        for &b in body {
+            if sep == Some(b) {
+                continue;
+            }
+            let mut digit = 0;
+            let mut found = 0;
+            for (i, &c) in self.chars.iter().enumerate() {
+                let eq = ct_eq(b, c);
+                digit |= i as u8 & eq.wrapping_neg();
+                found |= eq;
+            }
+            found_all &= found;
+            digits.push(digit);
+        }
+        let result = self.decode_ordered(digits.len(), digits.iter().map(|&d| T::from_u8(d)));
+
+        if prefix_ok == 0 {
+            Err(AlphaIdError::PrefixMissed)
+        } else if suffix_ok == 0 {
+            Err(AlphaIdError::SuffixMissed)
+        } else if self.reject_empty && digits.is_empty() {
+            Err(AlphaIdError::EmptyInput)
+        } else if found_all == 0 {
+            Err(AlphaIdError::UnexpectedChar)
+        } else {
+            result
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::tag::siphash24;
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// The length in bytes of the tag of [`Cursor::tag`].
+const TAG_LEN: usize = 8;
+
+/// Encodes small serde values, e.g. the offset, the sort key and a hash
+/// of the filters of a page, into opaque pagination cursors.
+///
+/// The value is serialized with `postcard`, optionally followed by a
+/// keyed tag, and the bytes are encoded as one large number. Only the
+/// alphabet, the digit order, the group separator and the affixes of the
+/// `AlphaId` are applied.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, Cursor};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Page {
+///     offset: u64,
+///     sort: String,
+/// }
+///
+/// let cursor = Cursor::new(AlphaId::<u64>::builder().prefix(b"cur_").build())
+///     .tag(*b"0123456789abcdef");
+/// let page = Page { offset: 40, sort: "name".into() };
+/// let v = cursor.encode(&page).unwrap();
+/// assert!(v.starts_with("cur_"));
+/// assert_eq!(cursor.decode::<Page>(&v), Ok(page));
+/// assert!(cursor.decode::<Page>("cur_Forged").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cursor<T: UnsignedInteger = u128, M = ()> {
+    alphaid: AlphaId<T, M>,
+    key: Option<[u8; 16]>,
+}
+
+impl<T: UnsignedInteger, M> Cursor<T, M> {
+    /// Creates cursors with the alphabet and the affixes of `alphaid`.
+    pub fn new(alphaid: AlphaId<T, M>) -> Self {
+        Self { alphaid, key: None }
+    }
+
+    /// Appends the SipHash-2-4 of the serialized value under `key`, which
+    /// is verified when decoding, so that clients can not forge cursors.
+    ///
+    /// Decoding a cursor with a wrong tag returns
+    /// `CursorError::Id(AlphaIdError::TagMismatch)`.
+    pub fn tag(mut self, key: [u8; 16]) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Returns the `AlphaId` of the cursors.
+    pub fn alphaid(&self) -> &AlphaId<T, M> {
+        &self.alphaid
+    }
+
+    /// Encode a value into a cursor.
+    pub fn encode<S: Serialize + ?Sized>(&self, value: &S) -> Result<String, CursorError> {
+        // The leading 1 keeps the leading zero bytes of the value.
+        let mut bytes = postcard::to_extend(value, alloc::vec![1])?;
+        if let Some(key) = &self.key {
+            let tag = siphash24(key, &bytes[1..]);
+            bytes.extend_from_slice(&tag.to_le_bytes());
+        }
+
+        let base = self.alphaid.base() as u64;
+        // The digits in the base of the alphabet, least significant first.
+        let mut digits: Vec<usize> = Vec::new();
+        for &b in &bytes {
+            let mut carry = u64::from(b);
+            for d in digits.iter_mut() {
+                carry += *d as u64 * 256;
+                *d = (carry % base) as usize;
+                carry /= base;
+            }
+            while carry > 0 {
+                digits.push((carry % base) as usize);
+                carry /= base;
+            }
+        }
+        Ok(self.alphaid.wide_to_string(digits)?)
+    }
+
+    /// Decode a cursor into a value.
+    ///
+    /// Returns `CursorError::Id` if `s` is not a cursor of this `Cursor`,
+    /// and `CursorError::Format` if it does not deserialize into `S`
+    /// exactly, without bytes left over.
+    pub fn decode<S: DeserializeOwned>(&self, s: &str) -> Result<S, CursorError> {
+        let base = self.alphaid.base() as u64;
+        // The bytes, least significant first.
+        let mut bytes: Vec<u8> = Vec::new();
+        for d in self.alphaid.wide_digits(s)? {
+            let mut carry = d as u64;
+            for b in bytes.iter_mut() {
+                carry += u64::from(*b) * base;
+                *b = carry as u8;
+                carry >>= 8;
+            }
+            while carry > 0 {
+                bytes.push(carry as u8);
+                carry >>= 8;
+            }
+        }
+        bytes.reverse();
+
+        let mut payload = match bytes.split_first() {
+            Some((1, payload)) => payload,
+            _ => return Err(AlphaIdError::InvalidNumber.into()),
+        };
+        if let Some(key) = &self.key {
+            let at = payload
+                .len()
+                .checked_sub(TAG_LEN)
+                .ok_or(AlphaIdError::TagMismatch)?;
+            let (value, tag) = payload.split_at(at);
+            if tag != siphash24(key, value).to_le_bytes() {
+                return Err(AlphaIdError::TagMismatch.into());
+            }
+            payload = value;
+        }
+        match postcard::take_from_bytes(payload)? {
+            (value, []) => Ok(value),
+            _ => Err(postcard::Error::DeserializeBadEncoding.into()),
+        }
+    }
+}
+
+/// The error of [`Cursor`].
+#[derive(Debug, PartialEq)]
+pub enum CursorError {
+    /// The input is not a cursor, e.g. because of an unexpected character
+    /// or a wrong tag.
+    Id(AlphaIdError),
+    /// The value can not be serialized, or the cursor does not
+    /// deserialize into the requested type.
+    Format(postcard::Error),
+}
+
+impl From<AlphaIdError> for CursorError {
+    fn from(e: AlphaIdError) -> Self {
+        CursorError::Id(e)
+    }
+}
+
+impl From<postcard::Error> for CursorError {
+    fn from(e: postcard::Error) -> Self {
+        CursorError::Format(e)
+    }
+}
+
+impl fmt::Display for CursorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CursorError::Id(e) => fmt::Display::fmt(e, f),
+            CursorError::Format(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl core::error::Error for CursorError {}
@@ -0,0 +1,142 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use crate::{AlphaId, AlphaIdError, DigitOrder, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode a number of any size, given as a decimal string.
+    ///
+    /// The number does not have to fit into `T`. Only the alphabet, the
+    /// digit order, the group separator and the affixes of the
+    /// configuration are applied, so the results of numbers which do fit
+    /// may differ from [`encode`](AlphaId::encode) with padding, tags or
+    /// blocked words.
+    ///
+    /// Returns `AlphaIdError::EmptyInput` for an empty string and
+    /// `AlphaIdError::UnexpectedChar` for anything but ASCII digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// // 2^128, one more than `u128::MAX`.
+    /// let n = "340282366920938463463374607431768211456";
+    /// let v = alphaid.encode_dec_str(n).unwrap();
+    /// assert_eq!(v, "aaaaaaaaaaaaaaaaaaaaae");
+    /// assert_eq!(alphaid.decode_dec_str(&v), Ok(n.to_string()));
+    /// assert_eq!(alphaid.encode_dec_str("1350997667"), Ok("90F7qb".to_string()));
+    /// ```
+    pub fn encode_dec_str(&self, s: &str) -> Result<String, AlphaIdError> {
+        if s.is_empty() {
+            return Err(AlphaIdError::EmptyInput);
+        }
+        let base = self.base() as u64;
+        // The digits in the base of the alphabet, least significant first.
+        let mut digits: Vec<usize> = Vec::new();
+        for c in s.bytes() {
+            if !c.is_ascii_digit() {
+                return Err(AlphaIdError::UnexpectedChar);
+            }
+            let mut carry = u64::from(c - b'0');
+            for d in digits.iter_mut() {
+                carry += *d as u64 * 10;
+                *d = (carry % base) as usize;
+                carry /= base;
+            }
+            while carry > 0 {
+                digits.push((carry % base) as usize);
+                carry /= base;
+            }
+        }
+        if digits.is_empty() {
+            digits.push(0);
+        }
+        self.wide_to_string(digits)
+    }
+
+    /// Writes the digits of a number which may not fit into `T`, least
+    /// significant first, with the alphabet, the digit order, the group
+    /// separator and the affixes.
+    pub(crate) fn wide_to_string(&self, mut digits: Vec<usize>) -> Result<String, AlphaIdError> {
+        if self.order == DigitOrder::BigEndian {
+            digits.reverse();
+        }
+
+        let mut out = String::new();
+        self.write_affix(&mut out, &self.prefix)
+            .map_err(|_| AlphaIdError::WriteFailed)?;
+        for (i, &d) in digits.iter().enumerate() {
+            if let Some(sep) = self.separator_before(i) {
+                out.push(sep as char);
+            }
+            match self.symbols.get(d) {
+                Some(symbol) => out.push_str(symbol),
+                None => out.push(self.chars[d] as char),
+            }
+        }
+        self.write_affix(&mut out, &self.suffix)
+            .map_err(|_| AlphaIdError::WriteFailed)?;
+        Ok(out)
+    }
+
+    /// Decode the result of [`encode_dec_str`](AlphaId::encode_dec_str)
+    /// into a decimal string without leading zeros.
+    pub fn decode_dec_str(&self, s: &str) -> Result<String, AlphaIdError> {
+        let base = self.base();
+        // The decimal digits, least significant first.
+        let mut decimal: Vec<u8> = Vec::new();
+        for d in self.wide_digits(s)? {
+            let mut carry = d as u64;
+            for x in decimal.iter_mut() {
+                carry += u64::from(*x) * base as u64;
+                *x = (carry % 10) as u8;
+                carry /= 10;
+            }
+            while carry > 0 {
+                decimal.push((carry % 10) as u8);
+                carry /= 10;
+            }
+        }
+        if decimal.is_empty() {
+            decimal.push(0);
+        }
+        Ok(decimal
+            .iter()
+            .rev()
+            .map(|&x| char::from(b'0' + x))
+            .collect())
+    }
+
+    /// Reads the digits of the result of
+    /// [`wide_to_string`](AlphaId::wide_to_string), most significant first.
+    pub(crate) fn wide_digits(&self, s: &str) -> Result<Vec<usize>, AlphaIdError> {
+        self.check_input_len(s.len())?;
+        let v = if self.symbols.is_empty() {
+            s.chars()
+                .map(|c| u8::try_from(c).map_err(|_| AlphaIdError::UnexpectedChar))
+                .collect::<Result<Vec<u8>, _>>()?
+        } else {
+            s.as_bytes().to_vec()
+        };
+        let v = v
+            .strip_prefix(&self.prefix[..])
+            .ok_or(AlphaIdError::PrefixMissed)?;
+        let v = v
+            .strip_suffix(&self.suffix[..])
+            .ok_or(AlphaIdError::SuffixMissed)?;
+        let mut digits = self.tokenize(v).ok_or(AlphaIdError::UnexpectedChar)?;
+        if digits.is_empty() {
+            return Err(AlphaIdError::EmptyInput);
+        }
+        if self.order == DigitOrder::LittleEndian {
+            digits.reverse();
+        }
+        if digits.iter().any(|&d| d >= self.base()) {
+            return Err(AlphaIdError::UnexpectedChar);
+        }
+        Ok(digits)
+    }
+}
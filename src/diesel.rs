@@ -0,0 +1,91 @@
+//! Stores an [`EncodedId`] in a Diesel column, as its number in a `BigInt`
+//! column or as its string in a `Text` column.
+//!
+//! Binding the number depends on the backend, enable `diesel-postgres`,
+//! `diesel-mysql` or `diesel-sqlite` for it. The other conversions work
+//! with any backend.
+use alloc::string::String;
+use core::convert::TryFrom;
+
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{self, FromSql};
+#[cfg(feature = "diesel-sqlite")]
+use ::diesel::serialize::IsNull;
+use ::diesel::serialize::{self, Output, ToSql};
+use ::diesel::sql_types::{BigInt, Text};
+#[cfg(feature = "diesel-sqlite")]
+use ::diesel::sqlite::Sqlite;
+
+use crate::{from_u128, AlphaIdError, EncodedId, Encoding};
+
+#[cfg(any(
+    feature = "diesel-postgres",
+    feature = "diesel-mysql",
+    feature = "diesel-sqlite"
+))]
+fn to_i64<E: Encoding>(id: &EncodedId<E>) -> Result<i64, AlphaIdError> {
+    i64::try_from(crate::to_u128(id.value())).map_err(|_| AlphaIdError::Overflow)
+}
+
+macro_rules! bytes_to_sql {
+    ($feature:literal, $db:ty) => {
+        /// Binds the number, failing with `AlphaIdError::Overflow` beyond
+        /// `i64::MAX`.
+        #[cfg(feature = $feature)]
+        impl<E: Encoding> ToSql<BigInt, $db> for EncodedId<E> {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, $db>) -> serialize::Result {
+                ToSql::<BigInt, $db>::to_sql(&to_i64(self)?, &mut out.reborrow())
+            }
+        }
+    };
+}
+
+bytes_to_sql!("diesel-postgres", ::diesel::pg::Pg);
+bytes_to_sql!("diesel-mysql", ::diesel::mysql::Mysql);
+
+/// Binds the number, failing with `AlphaIdError::Overflow` beyond
+/// `i64::MAX`.
+#[cfg(feature = "diesel-sqlite")]
+impl<E: Encoding> ToSql<BigInt, Sqlite> for EncodedId<E> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(to_i64(self)?);
+        Ok(IsNull::No)
+    }
+}
+
+/// Encodes the number, failing with `AlphaIdError::Overflow` for negative
+/// numbers and numbers which do not fit into the `Encoding`.
+impl<DB: Backend, E: Encoding> FromSql<BigInt, DB> for EncodedId<E>
+where
+    i64: FromSql<BigInt, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let n = <i64 as FromSql<BigInt, DB>>::from_sql(bytes)?;
+        let n = u128::try_from(n)
+            .ok()
+            .and_then(from_u128)
+            .ok_or(AlphaIdError::Overflow)?;
+        Ok(EncodedId::new(n)?)
+    }
+}
+
+/// Binds the encoded string.
+impl<DB: Backend, E: Encoding> ToSql<Text, DB> for EncodedId<E>
+where
+    str: ToSql<Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        ToSql::<Text, DB>::to_sql(self.as_str(), out)
+    }
+}
+
+/// Parses the encoded string, accepting only the exact result of
+/// [`EncodedId::new`] like its `FromStr` implementation.
+impl<DB: Backend, E: Encoding> FromSql<Text, DB> for EncodedId<E>
+where
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Ok(<String as FromSql<Text, DB>>::from_sql(bytes)?.parse()?)
+    }
+}
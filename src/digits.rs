@@ -0,0 +1,184 @@
+#[cfg(feature = "rand")]
+use rand_core::{OsRng, RngCore};
+
+use crate::UnsignedInteger;
+
+/// Lazily yields the index of every output symbol of a number, least
+/// significant first.
+pub(crate) struct Digits<T> {
+    n: T,
+    base: T,
+    /// The bits per digit if the base is a power of two.
+    bits: Option<usize>,
+    i: u32,
+    width: Option<u32>,
+    /// The length the number is padded to with the zero symbol.
+    pad: u32,
+    /// Whether the number is shifted at the pad boundary.
+    shift: bool,
+    /// The length and alphabet size of `PadMode::Random` padding.
+    random: Option<(u32, usize)>,
+    state: State,
+}
+
+enum State {
+    Number,
+    /// `left` symbols of random padding follow, the first one being the
+    /// terminator.
+    Filler {
+        left: u32,
+        terminated: bool,
+    },
+    Done,
+}
+
+impl<T: UnsignedInteger> Digits<T> {
+    /// Yields exactly `width` digits. The caller makes sure `n` fits.
+    pub(crate) fn fixed(n: T, base: T, width: u32) -> Self {
+        Digits {
+            n,
+            base,
+            bits: None,
+            i: 0,
+            width: Some(width),
+            pad: 1,
+            shift: false,
+            random: None,
+            state: State::Number,
+        }
+    }
+
+    pub(crate) fn shifted(n: T, base: T, pad: u32) -> Self {
+        Digits {
+            shift: true,
+            ..Digits::filled(n, base, pad)
+        }
+    }
+
+    /// Yields the digits followed by zeros up to `pad` symbols.
+    pub(crate) fn filled(n: T, base: T, pad: u32) -> Self {
+        Digits {
+            n,
+            base,
+            bits: None,
+            i: 0,
+            width: None,
+            pad,
+            shift: false,
+            random: None,
+            state: State::Number,
+        }
+    }
+
+    /// Yields the digits, a terminator of `size - 1` and random filler up to
+    /// `pad` symbols.
+    pub(crate) fn random(n: T, base: T, pad: u32, size: usize) -> Self {
+        Digits {
+            n,
+            base,
+            bits: None,
+            i: 0,
+            width: None,
+            pad: 1,
+            shift: false,
+            random: Some((pad, size)),
+            state: State::Number,
+        }
+    }
+
+    /// Extracts digits with shifts and masks, `bits` must be the bits per
+    /// digit of a power of two base.
+    pub(crate) fn with_bits(mut self, bits: Option<usize>) -> Self {
+        self.bits = bits;
+        self
+    }
+
+    fn next_digit(&mut self) -> usize {
+        let a = match self.bits {
+            Some(bits) => {
+                let a = self.n & (self.base - T::one());
+                self.n = self.n >> bits;
+                a
+            }
+            None => {
+                let a = self.n % self.base;
+                self.n = self.n / self.base;
+                a
+            }
+        };
+        a.to_usize().expect("digits are less than the base")
+    }
+}
+
+impl<T: UnsignedInteger> Iterator for Digits<T> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match &mut self.state {
+            State::Number => {}
+            State::Done => return None,
+            State::Filler { left: 0, .. } => return None,
+            State::Filler { left, terminated } => {
+                *left -= 1;
+                let size = self.random.map_or(0, |(_, size)| size);
+                if !*terminated {
+                    *terminated = true;
+                    return Some(size - 1);
+                }
+                return Some(below(size));
+            }
+        }
+
+        if let Some(width) = self.width {
+            if self.i == width {
+                self.state = State::Done;
+                return None;
+            }
+            self.i += 1;
+            return Some(self.next_digit());
+        }
+
+        self.i += 1;
+        if self.shift && self.pad > 1 && self.pad == self.i {
+            // `n` has been divided by the base at least once, so even
+            // `T::max_value()` leaves room for the extra one.
+            self.n = self.n + T::one();
+        }
+
+        if !self.n.is_zero() {
+            return Some(self.next_digit());
+        }
+        if self.i <= self.pad {
+            return Some(0);
+        }
+
+        self.state = State::Done;
+        if let Some((pad, _)) = self.random.filter(|&(pad, _)| self.i <= pad) {
+            self.state = State::Filler {
+                left: pad - self.i + 1,
+                terminated: false,
+            };
+        }
+        self.next()
+    }
+}
+
+/// Returns a uniformly distributed number in `0..n` from the operating
+/// system's random number generator.
+#[cfg(feature = "rand")]
+fn below(n: usize) -> usize {
+    let n = n as u64;
+    let zone = u64::MAX - u64::MAX % n;
+    loop {
+        let v = OsRng.next_u64();
+        if v < zone {
+            return (v % n) as usize;
+        }
+    }
+}
+
+/// `PadMode::Random` does not build without the `rand` feature.
+#[cfg(not(feature = "rand"))]
+fn below(_: usize) -> usize {
+    unreachable!("PadMode::Random requires the rand feature")
+}
@@ -0,0 +1,78 @@
+use alloc::string::String;
+
+use crate::AlphaIdError;
+
+const LETTERS: &[u8; 26] = b"abcdefghijklmnopqrstuvwxyz";
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// The maximum length of a DNS label.
+const MAX_LABEL_LEN: usize = 63;
+
+/// Encode the numbers as a valid DNS label, e.g. a subdomain.
+///
+/// The result consists of lowercase letters and digits and always starts
+/// with a letter: the first character is the number modulo 26, followed by
+/// the rest in base 36, most significant first. Even `u128::MAX` takes only
+/// 25 characters.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(alphaid::encode_dns_label(0u8), "a");
+/// assert_eq!(alphaid::encode_dns_label(1350997667u32), "tuxppk");
+/// assert_eq!(alphaid::decode_dns_label("tuxppk"), Ok(1350997667));
+/// ```
+pub fn encode_dns_label<N: Into<u128>>(n: N) -> String {
+    let n = n.into();
+    let mut out = String::new();
+    out.push(char::from(LETTERS[(n % 26) as usize]));
+
+    let mut rest = n / 26;
+    let mut digits = [0; 25];
+    let mut len = 0;
+    while rest > 0 {
+        digits[len] = DIGITS[(rest % 36) as usize];
+        rest /= 36;
+        len += 1;
+    }
+    out.extend(digits[..len].iter().rev().map(|&b| char::from(b)));
+    out
+}
+
+/// Decode the result of [`encode_dns_label`], ignoring case like DNS does.
+///
+/// Returns `AlphaIdError::InvalidLength` for labels longer than 63
+/// characters, `AlphaIdError::UnexpectedChar` if the label does not start
+/// with a letter, and `AlphaIdError::NonCanonical` if a `0` follows the
+/// first letter.
+pub fn decode_dns_label<V: AsRef<[u8]>>(v: V) -> Result<u128, AlphaIdError> {
+    let v = v.as_ref();
+    if v.len() > MAX_LABEL_LEN {
+        return Err(AlphaIdError::InvalidLength);
+    }
+    let (&first, rest) = v.split_first().ok_or(AlphaIdError::EmptyInput)?;
+    let first = first.to_ascii_lowercase();
+    let low = LETTERS
+        .iter()
+        .position(|&c| c == first)
+        .ok_or(AlphaIdError::UnexpectedChar)?;
+    if rest.first() == Some(&b'0') {
+        return Err(AlphaIdError::NonCanonical);
+    }
+
+    let mut n: u128 = 0;
+    for &b in rest {
+        let b = b.to_ascii_lowercase();
+        let d = DIGITS
+            .iter()
+            .position(|&c| c == b)
+            .ok_or(AlphaIdError::UnexpectedChar)?;
+        n = n
+            .checked_mul(36)
+            .and_then(|n| n.checked_add(d as u128))
+            .ok_or(AlphaIdError::Overflow)?;
+    }
+    n.checked_mul(26)
+        .and_then(|n| n.checked_add(low as u128))
+        .ok_or(AlphaIdError::Overflow)
+}
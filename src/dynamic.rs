@@ -0,0 +1,122 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, Builder, Config, UnsignedInteger};
+
+/// An `AlphaId` which picks the number type at runtime, e.g. for
+/// configurations loaded from a database.
+///
+/// An instance is built from the configuration for every unsigned integer
+/// type it is valid for, so that the results are the same as the ones of
+/// an `AlphaId` of that type.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, AlphaIdError, Builder, DynAlphaId};
+///
+/// let config = Builder::<u128>::parse("pad=3").unwrap().build().to_config();
+/// let alphaid = DynAlphaId::from_config(config).unwrap();
+/// assert_eq!(alphaid.encode_u128(1350997667), Ok(b"90G7qb".to_vec()));
+/// assert_eq!(alphaid.decode_as::<u32>(b"90G7qb"), Ok(1350997667));
+/// assert_eq!(alphaid.decode_as::<u16>(b"90G7qb"), Err(AlphaIdError::Overflow));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynAlphaId {
+    u8: Option<AlphaId<u8>>,
+    u16: Option<AlphaId<u16>>,
+    u32: Option<AlphaId<u32>>,
+    u64: Option<AlphaId<u64>>,
+    u128: Option<AlphaId<u128>>,
+    usize: Option<AlphaId<usize>>,
+}
+
+/// The number types of [`DynAlphaId::decode_as`].
+pub trait DynInteger: UnsignedInteger {
+    /// Returns the instance of `alphaid` for this type.
+    #[doc(hidden)]
+    fn select(alphaid: &DynAlphaId) -> Option<&AlphaId<Self>>;
+}
+
+macro_rules! impl_dyn_integer {
+    ($($t:ident),*) => {
+        $(
+            impl DynInteger for $t {
+                fn select(alphaid: &DynAlphaId) -> Option<&AlphaId<Self>> {
+                    alphaid.$t.as_ref()
+                }
+            }
+        )*
+    };
+}
+
+impl_dyn_integer!(u8, u16, u32, u64, u128, usize);
+
+impl DynAlphaId {
+    /// Constructs the instances of every number type `config` is valid for.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` if it is valid for none, see
+    /// [`Config`].
+    pub fn from_config(config: Config) -> Result<Self, AlphaIdError> {
+        let alphaid = DynAlphaId {
+            u8: try_build(&config),
+            u16: try_build(&config),
+            u32: try_build(&config),
+            u64: try_build(&config),
+            u128: try_build(&config),
+            usize: try_build(&config),
+        };
+        if alphaid.u8.is_none()
+            && alphaid.u16.is_none()
+            && alphaid.u32.is_none()
+            && alphaid.u64.is_none()
+            && alphaid.u128.is_none()
+            && alphaid.usize.is_none()
+        {
+            return Err(AlphaIdError::InvalidConfig);
+        }
+        Ok(alphaid)
+    }
+
+    /// Returns the instance for the number type `N`, `None` if the
+    /// configuration is not valid for it.
+    pub fn get<N: DynInteger>(&self) -> Option<&AlphaId<N>> {
+        N::select(self)
+    }
+
+    /// Encode the numbers as a `u128`.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` if the configuration is not
+    /// valid for `u128`, e.g. because its
+    /// [`max_len`](crate::Builder::max_len) is too small.
+    pub fn encode_u128(&self, n: u128) -> Result<Vec<u8>, AlphaIdError> {
+        self.get::<u128>()
+            .ok_or(AlphaIdError::InvalidConfig)?
+            .encode(n)
+    }
+
+    /// Decode into numbers of type `N`.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` if the configuration is not
+    /// valid for `N`.
+    pub fn decode_as<N: DynInteger>(&self, v: &[u8]) -> Result<N, AlphaIdError> {
+        self.get::<N>()
+            .ok_or(AlphaIdError::InvalidConfig)?
+            .decode(v)
+    }
+}
+
+/// Builds an instance of `config` for `T`, `None` if building would panic.
+fn try_build<T: UnsignedInteger>(config: &Config) -> Option<AlphaId<T>> {
+    let mut unbounded = config.clone();
+    unbounded.max_len = None;
+    let mut alphaid = Builder::<T>::from_config(unbounded)
+        .try_build_marked()
+        .ok()?;
+    if let Some(max) = config.max_len {
+        if alphaid.max_encoded_len() > max {
+            return None;
+        }
+        alphaid.max_len = Some(max);
+    }
+    Some(alphaid)
+}
@@ -0,0 +1,179 @@
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::str::FromStr;
+
+/// Associates a type with the `AlphaId` configuration of its IDs.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, Encoding};
+/// use std::sync::OnceLock;
+///
+/// struct Users;
+///
+/// impl Encoding for Users {
+///     type Int = u64;
+///
+///     fn alphaid() -> &'static AlphaId<u64> {
+///         static ALPHAID: OnceLock<AlphaId<u64>> = OnceLock::new();
+///         ALPHAID.get_or_init(|| AlphaId::builder().pad(5).build())
+///     }
+/// }
+/// ```
+pub trait Encoding {
+    type Int: UnsignedInteger + 'static;
+
+    /// Returns the `AlphaId` encoding and decoding the IDs.
+    fn alphaid() -> &'static AlphaId<Self::Int>;
+}
+
+/// An encoded ID which is known to be valid for its [`Encoding`].
+///
+/// # Example
+///
+/// ```rust
+/// # use alphaid::{AlphaId, Encoding};
+/// # use std::sync::OnceLock;
+/// # struct Users;
+/// # impl Encoding for Users {
+/// #     type Int = u64;
+/// #     fn alphaid() -> &'static AlphaId<u64> {
+/// #         static ALPHAID: OnceLock<AlphaId<u64>> = OnceLock::new();
+/// #         ALPHAID.get_or_init(|| AlphaId::builder().pad(5).build())
+/// #     }
+/// # }
+/// use alphaid::EncodedId;
+///
+/// let id = EncodedId::<Users>::new(0).unwrap();
+/// assert_eq!(id.to_string(), "aaaab");
+/// assert!(id.starts_with("aaaa"));
+///
+/// let id: EncodedId<Users> = "baaab".parse().unwrap();
+/// assert_eq!(id.value(), 1);
+/// assert!("baaa".parse::<EncodedId<Users>>().is_err());
+/// assert!("baaaba".parse::<EncodedId<Users>>().is_err());
+/// ```
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::BigInt),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct EncodedId<E: Encoding> {
+    s: String,
+    value: E::Int,
+    _encoding: PhantomData<fn() -> E>,
+}
+
+impl<E: Encoding> EncodedId<E> {
+    /// Encodes the number.
+    pub fn new(value: E::Int) -> Result<Self, AlphaIdError> {
+        Ok(Self {
+            s: E::alphaid().encode_chars(value)?,
+            value,
+            _encoding: PhantomData,
+        })
+    }
+
+    /// Returns the number the ID decodes to.
+    pub fn value(&self) -> E::Int {
+        self.value
+    }
+
+    /// Returns the encoded ID.
+    pub fn as_str(&self) -> &str {
+        &self.s
+    }
+
+    /// Unwraps the encoded ID.
+    pub fn into_string(self) -> String {
+        self.s
+    }
+}
+
+/// Parses an encoded ID, accepting only the exact result of
+/// [`EncodedId::new`] like [`AlphaId::decode_strict`].
+impl<E: Encoding> FromStr for EncodedId<E> {
+    type Err = AlphaIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let alphaid = E::alphaid();
+        Ok(Self {
+            value: alphaid.decode_strict(alphaid.str_bytes(s)?)?,
+            s: s.to_string(),
+            _encoding: PhantomData,
+        })
+    }
+}
+
+impl<E: Encoding> Deref for EncodedId<E> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.s
+    }
+}
+
+impl<E: Encoding> AsRef<str> for EncodedId<E> {
+    fn as_ref(&self) -> &str {
+        &self.s
+    }
+}
+
+impl<E: Encoding> fmt::Display for EncodedId<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.s)
+    }
+}
+
+impl<E: Encoding> fmt::Debug for EncodedId<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EncodedId").field(&self.s).finish()
+    }
+}
+
+impl<E: Encoding> Clone for EncodedId<E> {
+    fn clone(&self) -> Self {
+        Self {
+            s: self.s.clone(),
+            value: self.value,
+            _encoding: PhantomData,
+        }
+    }
+}
+
+/// Compares the numbers, as the same number may have several encodings,
+/// e.g. with `PadMode::Random`.
+impl<E: Encoding> PartialEq for EncodedId<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<E: Encoding> Eq for EncodedId<E> {}
+
+impl<E: Encoding> PartialOrd for EncodedId<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by the numbers, consistent with `PartialEq`.
+impl<E: Encoding> Ord for EncodedId<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+/// Hashes the number, consistent with `PartialEq`.
+impl<E: Encoding> Hash for EncodedId<E> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        crate::hash_int(self.value, state);
+    }
+}
@@ -0,0 +1,172 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// Encodes numbers into a reusable buffer, see [`AlphaId::encoder`].
+///
+/// An `Encoder` is not `Sync`, create one per thread.
+pub struct Encoder<'a, T: UnsignedInteger, M = ()> {
+    alphaid: &'a AlphaId<T, M>,
+    bytes: Vec<u8>,
+    string: String,
+    _not_sync: PhantomData<Cell<()>>,
+}
+
+impl<'a, T: UnsignedInteger, M> Encoder<'a, T, M> {
+    /// Encode the numbers, the result is valid until the next call.
+    pub fn encode(&mut self, n: T) -> Result<&[u8], AlphaIdError> {
+        self.bytes.clear();
+        self.alphaid.encode_into(n, &mut self.bytes)?;
+        Ok(&self.bytes)
+    }
+
+    /// Encode the numbers like [`AlphaId::encode_chars`], the result is
+    /// valid until the next call.
+    pub fn encode_str(&mut self, n: T) -> Result<&str, AlphaIdError> {
+        self.string.clear();
+        self.alphaid.encode_fmt(n, &mut self.string)?;
+        Ok(&self.string)
+    }
+}
+
+/// Decodes strings through a reusable buffer, see [`AlphaId::decoder`].
+///
+/// A `Decoder` is not `Sync`, create one per thread.
+pub struct Decoder<'a, T: UnsignedInteger, M = ()> {
+    alphaid: &'a AlphaId<T, M>,
+    bytes: Vec<u8>,
+    _not_sync: PhantomData<Cell<()>>,
+}
+
+impl<'a, T: UnsignedInteger, M> Decoder<'a, T, M> {
+    /// Decode into numbers.
+    pub fn decode<V: AsRef<[u8]>>(&self, v: V) -> Result<T, AlphaIdError> {
+        self.alphaid.decode(v)
+    }
+
+    /// Decode a string like [`AlphaId::decode_str`].
+    pub fn decode_str(&mut self, s: &str) -> Result<T, AlphaIdError> {
+        if !self.alphaid.symbols.is_empty() {
+            return self.alphaid.decode(s);
+        }
+
+        self.alphaid.check_input_len(s.len())?;
+        self.bytes.clear();
+        for c in s.chars() {
+            let b = u8::try_from(c).map_err(|_| AlphaIdError::UnexpectedChar)?;
+            self.bytes.push(b);
+        }
+        self.alphaid.decode(&self.bytes)
+    }
+}
+
+/// Decodes an encoded result arriving in chunks, see
+/// [`AlphaId::stream_decoder`].
+///
+/// At most as many bytes as the longest encoded result are kept, a longer
+/// input is rejected as soon as it is pushed.
+pub struct StreamDecoder<'a, T: UnsignedInteger, M = ()> {
+    alphaid: &'a AlphaId<T, M>,
+    bytes: Vec<u8>,
+    limit: usize,
+}
+
+impl<'a, T: UnsignedInteger, M> StreamDecoder<'a, T, M> {
+    /// Appends the next chunk of the input.
+    ///
+    /// Returns `AlphaIdError::InvalidLength`, or `AlphaIdError::InputTooLong`
+    /// with [`Builder::max_input_len`](crate::Builder::max_input_len), if
+    /// the input got longer than any encoded result. The chunk is dropped
+    /// in that case.
+    pub fn push(&mut self, chunk: &[u8]) -> Result<(), AlphaIdError> {
+        let len = self.bytes.len() + chunk.len();
+        self.alphaid.check_input_len(len)?;
+        if len > self.limit {
+            return Err(AlphaIdError::InvalidLength);
+        }
+        self.bytes.extend_from_slice(chunk);
+        Ok(())
+    }
+
+    /// Decode the pushed input like [`AlphaId::decode`] and start over.
+    pub fn finish(&mut self) -> Result<T, AlphaIdError> {
+        let result = self.alphaid.decode(&self.bytes);
+        self.bytes.clear();
+        result
+    }
+
+    /// Discards the pushed input.
+    pub fn reset(&mut self) {
+        self.bytes.clear();
+    }
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Returns a [`StreamDecoder`] for an input arriving in chunks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError};
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let mut decoder = alphaid.stream_decoder();
+    /// decoder.push(b"90F").unwrap();
+    /// decoder.push(b"7qb").unwrap();
+    /// assert_eq!(decoder.finish(), Ok(1350997667));
+    ///
+    /// assert_eq!(decoder.push(b"90F7qb90F7qb"), Err(AlphaIdError::InvalidLength));
+    /// ```
+    pub fn stream_decoder(&self) -> StreamDecoder<'_, T, M> {
+        let confusable_len = self.confusables.iter().map(|(from, _)| from.len()).max();
+        StreamDecoder {
+            alphaid: self,
+            bytes: Vec::new(),
+            limit: self.max_encoded_len() * confusable_len.unwrap_or(1).max(1),
+        }
+    }
+
+    /// Returns an [`Encoder`] which reuses its output buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let mut encoder = alphaid.encoder();
+    /// assert_eq!(encoder.encode(1350997667), Ok(&b"90F7qb"[..]));
+    /// assert_eq!(encoder.encode_str(1), Ok("b"));
+    /// ```
+    pub fn encoder(&self) -> Encoder<'_, T, M> {
+        Encoder {
+            alphaid: self,
+            bytes: Vec::with_capacity(self.max_encoded_len()),
+            string: String::new(),
+            _not_sync: PhantomData,
+        }
+    }
+
+    /// Returns a [`Decoder`] which reuses its input buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let mut decoder = alphaid.decoder();
+    /// assert_eq!(decoder.decode_str("90F7qb"), Ok(1350997667));
+    /// ```
+    pub fn decoder(&self) -> Decoder<'_, T, M> {
+        Decoder {
+            alphaid: self,
+            bytes: Vec::new(),
+            _not_sync: PhantomData,
+        }
+    }
+}
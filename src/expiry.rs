@@ -0,0 +1,86 @@
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{from_u64, AlphaId, AlphaIdError, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode the numbers together with the time they are issued at, see
+    /// [`decode_with_ttl`](AlphaId::decode_with_ttl).
+    ///
+    /// The time is packed into the low 32 bits as seconds since the Unix
+    /// epoch, so the numbers must leave 32 bits of room in `T`. Returns
+    /// `AlphaIdError::Overflow` if they do not, or if the time is before
+    /// 1970 or after 2106.
+    ///
+    /// The time is not authenticated by itself: anyone can encode the same
+    /// number with a later time to extend its life. Set
+    /// [`Builder::tag`](crate::Builder::tag), which covers the time as well,
+    /// so that such inputs return `AlphaIdError::TagMismatch`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError};
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let alphaid = AlphaId::<u128>::builder().tag(*b"0123456789abcdef", 4).build();
+    /// let v = alphaid.encode_issued_at(42, SystemTime::now()).unwrap();
+    /// assert_eq!(alphaid.decode_with_ttl(&v, Duration::from_secs(60)), Ok(42));
+    ///
+    /// let issued = SystemTime::now() - Duration::from_secs(3600);
+    /// let v = alphaid.encode_issued_at(42, issued).unwrap();
+    /// assert_eq!(
+    ///     alphaid.decode_with_ttl(&v, Duration::from_secs(60)),
+    ///     Err(AlphaIdError::Expired)
+    /// );
+    /// ```
+    pub fn encode_issued_at(&self, n: T, issued: SystemTime) -> Result<Vec<u8>, AlphaIdError> {
+        let secs = issued
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| AlphaIdError::Overflow)?
+            .as_secs();
+        let secs = u32::try_from(secs).map_err(|_| AlphaIdError::Overflow)?;
+        let packed = n
+            .checked_mul(&time_base()?)
+            .and_then(|n| n.checked_add(&from_u64(u64::from(secs))?))
+            .ok_or(AlphaIdError::Overflow)?;
+        self.encode(packed)
+    }
+
+    /// Decode the result of [`encode_issued_at`](AlphaId::encode_issued_at)
+    /// into the numbers and the time they were issued at.
+    pub fn decode_issued_at<V: AsRef<[u8]>>(&self, v: V) -> Result<(T, SystemTime), AlphaIdError> {
+        let packed = self.decode(v)?;
+        let base = time_base()?;
+        let secs = (packed % base).to_usize().ok_or(AlphaIdError::Overflow)?;
+        Ok((packed / base, UNIX_EPOCH + Duration::from_secs(secs as u64)))
+    }
+
+    /// Decode the result of [`encode_issued_at`](AlphaId::encode_issued_at),
+    /// returning `AlphaIdError::Expired` if it was issued more than
+    /// `max_age` ago.
+    ///
+    /// Times in the future, e.g. from clock skew between services, count as
+    /// issued now.
+    pub fn decode_with_ttl<V: AsRef<[u8]>>(
+        &self,
+        v: V,
+        max_age: Duration,
+    ) -> Result<T, AlphaIdError> {
+        let (n, issued) = self.decode_issued_at(v)?;
+        let age = SystemTime::now()
+            .duration_since(issued)
+            .unwrap_or(Duration::ZERO);
+        if age > max_age {
+            return Err(AlphaIdError::Expired);
+        }
+        Ok(n)
+    }
+}
+
+/// Returns 2^32, the factor the numbers are shifted by.
+fn time_base<T: UnsignedInteger>() -> Result<T, AlphaIdError> {
+    let half = T::from_usize(1 << 16).ok_or(AlphaIdError::Overflow)?;
+    half.checked_mul(&half).ok_or(AlphaIdError::Overflow)
+}
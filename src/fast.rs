@@ -0,0 +1,130 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaIdError, DEFAULT_SEED};
+
+macro_rules! fast_codec {
+    ($(#[$attr:meta])* $name:ident, $int:ty, $max_len:expr) => {
+        $(#[$attr])*
+        #[derive(Clone)]
+        pub struct $name {
+            chars: [u8; 256],
+            index: [i16; 256],
+            base: $int,
+        }
+
+        impl $name {
+            /// Constructs an encoder with the characters set.
+            ///
+            /// # Panics
+            ///
+            /// Panics if there are not more than `16` characters, or the
+            /// characters are not unique.
+            pub const fn new(chars: &[u8]) -> Self {
+                assert!(chars.len() > 16, "chars size must large than 16");
+                let mut table = [0; 256];
+                let mut index = [-1; 256];
+                let mut i = 0;
+                while i < chars.len() {
+                    let b = chars[i] as usize;
+                    assert!(index[b] == -1, "duplicate characters are not allowed");
+                    index[b] = i as i16;
+                    table[i] = chars[i];
+                    i += 1;
+                }
+                $name {
+                    chars: table,
+                    index,
+                    base: chars.len() as $int,
+                }
+            }
+
+            /// Encode the numbers into `buf`, returning the number of bytes
+            /// written.
+            pub fn encode_to_slice(&self, mut n: $int, buf: &mut [u8]) -> Result<usize, AlphaIdError> {
+                let mut len = 0;
+                loop {
+                    let slot = buf.get_mut(len).ok_or(AlphaIdError::BufferTooSmall)?;
+                    let q = n / self.base;
+                    *slot = self.chars[(n - q * self.base) as usize];
+                    len += 1;
+                    n = q;
+                    if n == 0 {
+                        return Ok(len);
+                    }
+                }
+            }
+
+            /// Encode the numbers.
+            pub fn encode(&self, n: $int) -> Vec<u8> {
+                let mut buf = [0; $max_len];
+                let len = self
+                    .encode_to_slice(n, &mut buf)
+                    .expect("the buffer fits base 17");
+                buf[..len].to_vec()
+            }
+
+            /// Decode into numbers.
+            pub fn decode<V: AsRef<[u8]>>(&self, v: V) -> Result<$int, AlphaIdError> {
+                let mut n: $int = 0;
+                for &b in v.as_ref().iter().rev() {
+                    let d = self.index[b as usize];
+                    if d < 0 {
+                        return Err(AlphaIdError::UnexpectedChar);
+                    }
+                    n = n
+                        .checked_mul(self.base)
+                        .and_then(|n| n.checked_add(d as $int))
+                        .ok_or(AlphaIdError::Overflow)?;
+                }
+                Ok(n)
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new(DEFAULT_SEED.as_bytes())
+            }
+        }
+    };
+}
+
+fast_codec!(
+    /// An encoder of `u64`s with concrete arithmetic for hot paths, with a
+    /// characters set and no other options.
+    ///
+    /// The results are the same as the ones of an
+    /// [`AlphaId`](crate::AlphaId) built with the same characters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId64;
+    ///
+    /// static IDS: AlphaId64 = AlphaId64::new(b"0123456789abcdefghijklmnopqrstuvwxyz");
+    ///
+    /// assert_eq!(AlphaId64::default().encode(1350997667), b"90F7qb");
+    /// assert_eq!(IDS.encode(35), b"z");
+    /// assert_eq!(IDS.decode(b"z"), Ok(35));
+    /// ```
+    AlphaId64,
+    u64,
+    16
+);
+
+fast_codec!(
+    /// An encoder of `u128`s with concrete arithmetic for hot paths, see
+    /// [`AlphaId64`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId128;
+    ///
+    /// let alphaid = AlphaId128::default();
+    /// let v = alphaid.encode(u128::MAX);
+    /// assert_eq!(alphaid.decode(&v), Ok(u128::MAX));
+    /// ```
+    AlphaId128,
+    u128,
+    32
+);
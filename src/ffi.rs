@@ -0,0 +1,156 @@
+//! A C interface for linking the encoder into other languages.
+//!
+//! Build a shared library with
+//! `cargo rustc --release --features ffi --crate-type cdylib`.
+//!
+//! ```c
+//! typedef struct AlphaId AlphaId;
+//!
+//! AlphaId *alphaid_new(const char *settings);
+//! int alphaid_encode(const AlphaId *alphaid, uint64_t n, char *buf, size_t len);
+//! int alphaid_decode(const AlphaId *alphaid, const char *s, uint64_t *out);
+//! void alphaid_free(AlphaId *alphaid);
+//! ```
+//!
+//! Functions returning `int` return `0` on success, a negative
+//! `ALPHAID_ERR_*` code for invalid arguments and the positive code of an
+//! [`AlphaIdError`] otherwise, see [`error_code`].
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+use crate::{AlphaId, AlphaIdError, Builder};
+
+/// A null pointer or a string which is not valid UTF-8 was passed.
+pub const ALPHAID_ERR_ARGUMENT: c_int = -1;
+
+/// Returns the stable code of an error.
+///
+/// The codes are the positions of the variants in [`AlphaIdError`],
+/// starting at 1. New variants get new codes.
+pub fn error_code(e: AlphaIdError) -> c_int {
+    match e {
+        AlphaIdError::InvalidNumber => 1,
+        AlphaIdError::PadMissed => 2,
+        AlphaIdError::Overflow => 3,
+        AlphaIdError::UnexpectedChar => 4,
+        AlphaIdError::Blocked => 5,
+        AlphaIdError::InvalidLength => 6,
+        AlphaIdError::PrefixMissed => 7,
+        AlphaIdError::SuffixMissed => 8,
+        AlphaIdError::NonUtf8 => 9,
+        AlphaIdError::BufferTooSmall => 10,
+        AlphaIdError::WriteFailed => 11,
+        AlphaIdError::NonCanonical => 12,
+        AlphaIdError::EmptyInput => 13,
+        AlphaIdError::InputTooLong => 14,
+        AlphaIdError::InvalidConfig => 15,
+        AlphaIdError::UnknownName => 16,
+        AlphaIdError::UnknownVersion => 17,
+        AlphaIdError::Expired => 18,
+        AlphaIdError::Exhausted => 19,
+        AlphaIdError::TagMismatch => 20,
+        AlphaIdError::ChecksumMismatch => 21,
+        AlphaIdError::Zero => 22,
+    }
+}
+
+/// Constructs an `AlphaId<u64>` from the settings of [`Builder::parse`],
+/// or the default settings if `settings` is null.
+///
+/// Returns null if the settings are invalid.
+///
+/// # Safety
+///
+/// `settings` must be null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn alphaid_new(settings: *const c_char) -> *mut AlphaId<u64> {
+    let builder = if settings.is_null() {
+        Ok(Builder::new())
+    } else {
+        match CStr::from_ptr(settings).to_str() {
+            Ok(s) => Builder::parse(s),
+            Err(_) => return ptr::null_mut(),
+        }
+    };
+    match builder {
+        Ok(builder) => Box::into_raw(Box::new(builder.build())),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Encodes `n` into `buf` as a NUL-terminated string.
+///
+/// Returns the code of `AlphaIdError::BufferTooSmall` if the result and the
+/// NUL do not fit into `len` bytes.
+///
+/// # Safety
+///
+/// `alphaid` must come from [`alphaid_new`] and `buf` must be valid for
+/// `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn alphaid_encode(
+    alphaid: *const AlphaId<u64>,
+    n: u64,
+    buf: *mut c_char,
+    len: usize,
+) -> c_int {
+    let alphaid = match alphaid.as_ref() {
+        Some(alphaid) if !buf.is_null() => alphaid,
+        _ => return ALPHAID_ERR_ARGUMENT,
+    };
+    let buf = std::slice::from_raw_parts_mut(buf.cast::<u8>(), len);
+    let Some((nul, out)) = buf.split_last_mut() else {
+        return error_code(AlphaIdError::BufferTooSmall);
+    };
+    match alphaid.encode_to_slice(n, out) {
+        Ok(written) => {
+            if let Some(b) = out.get_mut(written) {
+                *b = 0;
+            } else {
+                *nul = 0;
+            }
+            0
+        }
+        Err(e) => error_code(e),
+    }
+}
+
+/// Decodes the NUL-terminated string `s` into `out`.
+///
+/// # Safety
+///
+/// `alphaid` must come from [`alphaid_new`], `s` must be a NUL-terminated
+/// string and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn alphaid_decode(
+    alphaid: *const AlphaId<u64>,
+    s: *const c_char,
+    out: *mut u64,
+) -> c_int {
+    let alphaid = match alphaid.as_ref() {
+        Some(alphaid) if !s.is_null() && !out.is_null() => alphaid,
+        _ => return ALPHAID_ERR_ARGUMENT,
+    };
+    match alphaid.decode(CStr::from_ptr(s).to_bytes()) {
+        Ok(n) => {
+            *out = n;
+            0
+        }
+        Err(e) => error_code(e),
+    }
+}
+
+/// Frees an `AlphaId` constructed by [`alphaid_new`].
+///
+/// # Safety
+///
+/// `alphaid` must be null or come from [`alphaid_new`], and must not be
+/// used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn alphaid_free(alphaid: *mut AlphaId<u64>) {
+    if !alphaid.is_null() {
+        drop(Box::from_raw(alphaid));
+    }
+}
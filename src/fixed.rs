@@ -0,0 +1,128 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::{AlphaIdError, UnsignedInteger, DEFAULT_SEED};
+
+/// An encoder with a fixed alphabet of `BASE` bytes and no other options.
+///
+/// The alphabet and the decode table are stored inline, so constructing,
+/// encoding into a slice and decoding never allocate. The results are the
+/// same as the ones of an [`AlphaId`](crate::AlphaId) built with the same
+/// characters.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::AlphaIdConst;
+///
+/// let alphaid = AlphaIdConst::<u32, 64>::default();
+/// let mut buf = [0; 6];
+/// assert_eq!(alphaid.encode_to_slice(1350997667, &mut buf), Ok(6));
+/// assert_eq!(&buf, b"90F7qb");
+/// assert_eq!(alphaid.decode(b"90F7qb"), Ok(1350997667));
+/// ```
+pub struct AlphaIdConst<T, const BASE: usize> {
+    chars: [u8; BASE],
+    index: [i16; 256],
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: UnsignedInteger, const BASE: usize> AlphaIdConst<T, BASE> {
+    /// Constructs an encoder with the characters set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BASE` is not larger than `16` or the characters are not
+    /// unique.
+    pub const fn new(chars: [u8; BASE]) -> Self {
+        assert!(BASE > 16, "chars size must large than 16");
+        let mut index = [-1; 256];
+        let mut i = 0;
+        while i < BASE {
+            let b = chars[i] as usize;
+            assert!(index[b] == -1, "duplicate characters are not allowed");
+            index[b] = i as i16;
+            i += 1;
+        }
+        AlphaIdConst {
+            chars,
+            index,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Encode the numbers into `buf`, returning the number of bytes written.
+    pub fn encode_to_slice(&self, mut n: T, buf: &mut [u8]) -> Result<usize, AlphaIdError> {
+        let base = T::from_usize(BASE).ok_or(AlphaIdError::InvalidNumber)?;
+        let mut len = 0;
+        loop {
+            let slot = buf.get_mut(len).ok_or(AlphaIdError::BufferTooSmall)?;
+            let d = (n % base).to_usize().ok_or(AlphaIdError::InvalidNumber)?;
+            *slot = self.chars[d];
+            len += 1;
+            n = n / base;
+            if n.is_zero() {
+                return Ok(len);
+            }
+        }
+    }
+
+    /// Encode the numbers.
+    pub fn encode(&self, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        // Enough for a `u128` in base 17.
+        let mut buf = [0; 32];
+        let len = self.encode_to_slice(n, &mut buf)?;
+        Ok(buf[..len].to_vec())
+    }
+
+    /// Decode into numbers.
+    pub fn decode<V: AsRef<[u8]>>(&self, v: V) -> Result<T, AlphaIdError> {
+        let base = T::from_usize(BASE).ok_or(AlphaIdError::InvalidNumber)?;
+        let mut n = T::zero();
+        let mut pow = Some(T::one());
+        for &b in v.as_ref() {
+            let d = match self.index[b as usize] {
+                -1 => return Err(AlphaIdError::UnexpectedChar),
+                d => T::from_i16(d).ok_or(AlphaIdError::InvalidNumber)?,
+            };
+            if !d.is_zero() {
+                let add = pow
+                    .and_then(|pow| pow.checked_mul(&d))
+                    .ok_or(AlphaIdError::Overflow)?;
+                n = n.checked_add(&add).ok_or(AlphaIdError::Overflow)?;
+            }
+            pow = pow.and_then(|pow| pow.checked_mul(&base));
+        }
+        Ok(n)
+    }
+}
+
+impl<T: UnsignedInteger> AlphaIdConst<T, 64> {
+    /// The encoder with the default characters set, usable in statics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaIdConst;
+    ///
+    /// static IDS: AlphaIdConst<u64, 64> = AlphaIdConst::DEFAULT;
+    ///
+    /// assert_eq!(IDS.encode(1350997667), Ok(b"90F7qb".to_vec()));
+    /// ```
+    pub const DEFAULT: Self = {
+        let seed = DEFAULT_SEED.as_bytes();
+        let mut chars = [0; 64];
+        let mut i = 0;
+        while i < 64 {
+            chars[i] = seed[i];
+            i += 1;
+        }
+        Self::new(chars)
+    };
+}
+
+impl<T: UnsignedInteger> Default for AlphaIdConst<T, 64> {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,95 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode a code of exactly `digit_count` decimal digits, such as
+    /// `000042`, so that its leading zeros survive the round trip.
+    ///
+    /// Every digit count gets its own range of numbers, `42` and `0042`
+    /// are encoded differently. Returns `AlphaIdError::Overflow` if `n`
+    /// has more than `digit_count` digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let v = alphaid.encode_fixed(42, 6).unwrap();
+    /// assert_eq!(alphaid.decode_fixed(&v), Ok((42, 6)));
+    /// assert_ne!(alphaid.encode_fixed(42, 4), Ok(v));
+    /// assert_eq!(format!("{:06}", 42), "000042");
+    /// ```
+    pub fn encode_fixed(&self, n: T, digit_count: u32) -> Result<Vec<u8>, AlphaIdError> {
+        self.encode_fixed_radix(n, digit_count, 10)
+    }
+
+    /// Decode the result of [`encode_fixed`](AlphaId::encode_fixed) into the
+    /// number and its digit count.
+    pub fn decode_fixed<V: AsRef<[u8]>>(&self, v: V) -> Result<(T, u32), AlphaIdError> {
+        self.decode_fixed_radix(v, 10)
+    }
+
+    /// Encode a code of exactly `digit_count` digits in base `radix`, see
+    /// [`encode_fixed`](AlphaId::encode_fixed).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is less than `2`.
+    pub fn encode_fixed_radix(
+        &self,
+        n: T,
+        digit_count: u32,
+        radix: u32,
+    ) -> Result<Vec<u8>, AlphaIdError> {
+        let radix = Self::radix(radix)?;
+        // The codes with fewer digits come first.
+        let mut offset = T::zero();
+        let mut pow = Some(T::one());
+        for _ in 0..digit_count {
+            let p = pow.ok_or(AlphaIdError::Overflow)?;
+            offset = offset.checked_add(&p).ok_or(AlphaIdError::Overflow)?;
+            pow = p.checked_mul(&radix);
+        }
+        if pow.is_some_and(|p| n >= p) {
+            return Err(AlphaIdError::Overflow);
+        }
+        self.encode(offset.checked_add(&n).ok_or(AlphaIdError::Overflow)?)
+    }
+
+    /// Decode the result of
+    /// [`encode_fixed_radix`](AlphaId::encode_fixed_radix) into the number
+    /// and its digit count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is less than `2`.
+    pub fn decode_fixed_radix<V: AsRef<[u8]>>(
+        &self,
+        v: V,
+        radix: u32,
+    ) -> Result<(T, u32), AlphaIdError> {
+        let radix = Self::radix(radix)?;
+        let n = self.decode(v)?;
+        let mut offset = T::zero();
+        let mut pow = Some(T::one());
+        let mut digit_count = 0;
+        loop {
+            let rest = n - offset;
+            match pow {
+                Some(p) if rest >= p => {
+                    offset = offset + p;
+                    pow = p.checked_mul(&radix);
+                    digit_count += 1;
+                }
+                _ => return Ok((rest, digit_count)),
+            }
+        }
+    }
+
+    fn radix(radix: u32) -> Result<T, AlphaIdError> {
+        assert!(radix >= 2, "radix must be at least 2");
+        T::from_usize(radix as usize).ok_or(AlphaIdError::InvalidNumber)
+    }
+}
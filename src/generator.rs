@@ -0,0 +1,219 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{AlphaId, AlphaIdError};
+
+/// The number of random bits below the timestamp of a [`SortableGen`] ID.
+const RANDOM_BITS: u32 = 80;
+
+/// Returns 128 random bits.
+type RandomBits = Box<dyn FnMut() -> u128 + Send>;
+
+/// Generates ULID-like IDs: 48 bits of milliseconds since the Unix epoch
+/// followed by 80 random bits, encoded with any `AlphaId<u128>`.
+///
+/// IDs generated by one `SortableGen` are strictly increasing, also within
+/// the same millisecond and when the clock goes backwards. Combined with
+/// [`Builder::sortable`](crate::Builder::sortable) the encoded results sort
+/// in the same order as the numbers.
+///
+/// The random bits come from the operating system's generator with the
+/// `rand` feature. Without it they come from a generator which is not
+/// cryptographically secure, so the IDs must not be used as secrets.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, SortableGen};
+///
+/// let gen = SortableGen::new(AlphaId::<u128>::builder().sortable(22).build());
+/// let a = gen.next_id().unwrap();
+/// let b = gen.next_id().unwrap();
+/// assert_eq!(a.len(), 22);
+/// assert!(a < b);
+/// ```
+pub struct SortableGen<M = ()> {
+    alphaid: AlphaId<u128, M>,
+    /// The last generated number and the source of the random bits.
+    state: Mutex<(u128, Option<RandomBits>)>,
+}
+
+impl<M> SortableGen<M> {
+    /// Creates a generator encoding with `alphaid`.
+    pub fn new(alphaid: AlphaId<u128, M>) -> Self {
+        Self {
+            alphaid,
+            state: Mutex::new((0, None)),
+        }
+    }
+
+    /// Draws the random bits from `rng` instead of the default source.
+    #[cfg(feature = "rand")]
+    pub fn with_rng<R>(self, mut rng: R) -> Self
+    where
+        R: rand_core::RngCore + rand_core::CryptoRng + Send + 'static,
+    {
+        let random = move || u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64());
+        Self {
+            state: Mutex::new((0, Some(Box::new(random)))),
+            ..self
+        }
+    }
+
+    /// Returns the next number.
+    pub fn next_raw(&self) -> u128 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis())
+            & ((1 << 48) - 1);
+
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let (last, rng) = &mut *state;
+        let n = if now > *last >> RANDOM_BITS {
+            let random = rng.get_or_insert_with(default_random);
+            let random = random() & ((1 << RANDOM_BITS) - 1);
+            now << RANDOM_BITS | random
+        } else {
+            *last + 1
+        };
+        *last = n;
+        n
+    }
+
+    /// Returns the next encoded ID.
+    pub fn next_id(&self) -> Result<Vec<u8>, AlphaIdError> {
+        self.alphaid.encode(self.next_raw())
+    }
+}
+
+/// The operating system's generator.
+#[cfg(feature = "rand")]
+fn default_random() -> RandomBits {
+    use rand_core::{OsRng, RngCore};
+
+    Box::new(|| u128::from(OsRng.next_u64()) << 64 | u128::from(OsRng.next_u64()))
+}
+
+/// Without `rand` a non-cryptographic generator seeded from the process'
+/// hash keys and the clock, enough to order IDs but easy to guess.
+#[cfg(not(feature = "rand"))]
+fn default_random() -> RandomBits {
+    let mut rng = crate::rng::SplitMix64::from_entropy();
+    Box::new(move || u128::from(rng.next_u64()) << 64 | u128::from(rng.next_u64()))
+}
+
+impl SortableGen {
+    /// Returns the milliseconds since the Unix epoch a number was generated
+    /// at.
+    pub fn timestamp_ms(n: u128) -> u64 {
+        (n >> RANDOM_BITS) as u64
+    }
+}
+
+/// The bits of the worker ID of a [`SnowflakeGen`] ID.
+const WORKER_BITS: u32 = 10;
+/// The bits of the sequence of a [`SnowflakeGen`] ID.
+const SEQUENCE_BITS: u32 = 12;
+
+/// Generates Snowflake IDs: 41 bits of milliseconds since an epoch, a 10
+/// bit worker ID and a 12 bit sequence, encoded with any `AlphaId<u64>`.
+///
+/// IDs generated by one `SnowflakeGen` are strictly increasing. If the
+/// clock goes backwards, or more than 4096 IDs are generated within one
+/// millisecond, the generator keeps counting from its last millisecond
+/// instead of waiting for the clock.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, SnowflakeGen};
+///
+/// let gen = SnowflakeGen::new(7, AlphaId::<u64>::new());
+/// let (n, slug) = gen.next_id().unwrap();
+/// assert_eq!(gen.decode(&slug), Ok(n));
+/// assert_eq!(SnowflakeGen::worker_id(n), 7);
+/// ```
+pub struct SnowflakeGen<M = ()> {
+    alphaid: AlphaId<u64, M>,
+    worker_id: u64,
+    epoch_ms: u64,
+    /// The millisecond and the sequence of the last generated number.
+    state: Mutex<(u64, u64)>,
+}
+
+impl<M> SnowflakeGen<M> {
+    /// Creates a generator for `worker_id` encoding with `alphaid`, the
+    /// epoch is 2020-01-01.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the worker ID does not fit into 10 bits.
+    pub fn new(worker_id: u16, alphaid: AlphaId<u64, M>) -> Self {
+        assert!(
+            u32::from(worker_id) < 1 << WORKER_BITS,
+            "worker_id must be less than 1024"
+        );
+        Self {
+            alphaid,
+            worker_id: u64::from(worker_id),
+            epoch_ms: SnowflakeGen::DEFAULT_EPOCH_MS,
+            state: Mutex::new((0, 0)),
+        }
+    }
+
+    /// Sets the epoch as milliseconds since the Unix epoch.
+    pub fn epoch_ms(mut self, epoch_ms: u64) -> Self {
+        self.epoch_ms = epoch_ms;
+        self
+    }
+
+    /// Returns the next number.
+    pub fn next_raw(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64)
+            .saturating_sub(self.epoch_ms);
+
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let (ms, sequence) = &mut *state;
+        if now > *ms {
+            *ms = now;
+            *sequence = 0;
+        } else if *sequence + 1 < 1 << SEQUENCE_BITS {
+            *sequence += 1;
+        } else {
+            *ms += 1;
+            *sequence = 0;
+        }
+        (*ms << (WORKER_BITS + SEQUENCE_BITS)) | (self.worker_id << SEQUENCE_BITS) | *sequence
+    }
+
+    /// Returns the next number and its encoded result.
+    pub fn next_id(&self) -> Result<(u64, Vec<u8>), AlphaIdError> {
+        let n = self.next_raw();
+        Ok((n, self.alphaid.encode(n)?))
+    }
+
+    /// Decodes an encoded result into the number.
+    pub fn decode<V: AsRef<[u8]>>(&self, v: V) -> Result<u64, AlphaIdError> {
+        self.alphaid.decode(v)
+    }
+
+    /// Returns the milliseconds since the Unix epoch a number was generated
+    /// at.
+    pub fn timestamp_ms(&self, n: u64) -> u64 {
+        (n >> (WORKER_BITS + SEQUENCE_BITS)) + self.epoch_ms
+    }
+}
+
+impl SnowflakeGen {
+    /// 2020-01-01 as milliseconds since the Unix epoch.
+    pub const DEFAULT_EPOCH_MS: u64 = 1_577_836_800_000;
+
+    /// Returns the worker ID a number was generated by.
+    pub fn worker_id(n: u64) -> u16 {
+        ((n >> SEQUENCE_BITS) & ((1 << WORKER_BITS) - 1)) as u16
+    }
+}
@@ -0,0 +1,191 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// The area a result of [`AlphaId::encode_latlng`] stands for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatLngBox {
+    pub min_lat: f64,
+    pub min_lng: f64,
+    pub max_lat: f64,
+    pub max_lng: f64,
+}
+
+impl LatLngBox {
+    /// Returns the latitude and longitude of the center.
+    pub fn center(&self) -> (f64, f64) {
+        (
+            (self.min_lat + self.max_lat) / 2.0,
+            (self.min_lng + self.max_lng) / 2.0,
+        )
+    }
+
+    /// Returns `true` if the point is inside the box.
+    pub fn contains(&self, lat: f64, lng: f64) -> bool {
+        (self.min_lat..=self.max_lat).contains(&lat) && (self.min_lng..=self.max_lng).contains(&lng)
+    }
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode a point into `precision` symbols, like a geohash.
+    ///
+    /// Every symbol narrows down the area of the previous ones, so nearby
+    /// points share prefixes. For alphabets of a power of two size the bits
+    /// of the symbols alternate between longitude and latitude, which gives
+    /// standard geohashes with the geohash alphabet. Otherwise every symbol
+    /// alternately splits the longitude or the latitude into as many parts
+    /// as there are symbols. Only the alphabet, the separators and the
+    /// affixes of this `AlphaId` are applied.
+    ///
+    /// Returns `AlphaIdError::InvalidNumber` for coordinates out of range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u64>::builder()
+    ///     .chars(b"0123456789bcdefghjkmnpqrstuvwxyz".to_vec())
+    ///     .build();
+    /// let v = alphaid.encode_latlng(57.64911, 10.40744, 11).unwrap();
+    /// assert_eq!(v, b"u4pruydqqvj".to_vec());
+    /// let area = alphaid.decode_latlng(&v).unwrap();
+    /// assert!(area.contains(57.64911, 10.40744));
+    /// ```
+    pub fn encode_latlng(
+        &self,
+        lat: f64,
+        lng: f64,
+        precision: usize,
+    ) -> Result<Vec<u8>, AlphaIdError> {
+        if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lng) {
+            return Err(AlphaIdError::InvalidNumber);
+        }
+        let (size, bits) = self.geo_digits();
+        let mut area = WORLD;
+        let mut out = self.prefix.to_vec();
+        for i in 0..precision {
+            let d = match bits {
+                Some(bits) => {
+                    let mut d = 0;
+                    for j in 0..bits {
+                        let bit = area.split_bit(i * bits + j, lat, lng);
+                        d = d << 1 | bit;
+                    }
+                    d
+                }
+                None => area.split(i, size, lat, lng),
+            };
+            if let Some(sep) = self.separator_before(i) {
+                out.push(sep);
+            }
+            if self.symbols.is_empty() {
+                out.push(self.chars[d]);
+            } else {
+                out.extend_from_slice(self.symbols[d].as_bytes());
+            }
+        }
+        out.extend_from_slice(&self.suffix);
+        Ok(out)
+    }
+
+    /// Decode the result of [`encode_latlng`](AlphaId::encode_latlng) into
+    /// the area it stands for.
+    pub fn decode_latlng<V: AsRef<[u8]>>(&self, v: V) -> Result<LatLngBox, AlphaIdError> {
+        let v = v
+            .as_ref()
+            .strip_prefix(&self.prefix[..])
+            .ok_or(AlphaIdError::PrefixMissed)?;
+        let v = v
+            .strip_suffix(&self.suffix[..])
+            .ok_or(AlphaIdError::SuffixMissed)?;
+        let digits = self.tokenize(v).ok_or(AlphaIdError::UnexpectedChar)?;
+
+        let (size, bits) = self.geo_digits();
+        let mut area = WORLD;
+        for (i, &d) in digits.iter().enumerate() {
+            match bits {
+                Some(bits) => {
+                    for j in 0..bits {
+                        let bit = d >> (bits - 1 - j) & 1;
+                        area.narrow_bit(i * bits + j, bit);
+                    }
+                }
+                None => area.narrow(i, size, d),
+            }
+        }
+        Ok(area)
+    }
+
+    /// Returns the number of symbols and the bits per symbol if it is a
+    /// power of two. The terminator of `PadMode::Random` is used too.
+    fn geo_digits(&self) -> (usize, Option<usize>) {
+        let size = self.size();
+        let bits = if size.is_power_of_two() {
+            Some(size.trailing_zeros() as usize)
+        } else {
+            None
+        };
+        (size, bits)
+    }
+}
+
+const WORLD: LatLngBox = LatLngBox {
+    min_lat: -90.0,
+    min_lng: -180.0,
+    max_lat: 90.0,
+    max_lng: 180.0,
+};
+
+impl LatLngBox {
+    /// Returns the range split by the `i`-th step, longitude first.
+    fn axis(&mut self, i: usize) -> (&mut f64, &mut f64) {
+        if i.is_multiple_of(2) {
+            (&mut self.min_lng, &mut self.max_lng)
+        } else {
+            (&mut self.min_lat, &mut self.max_lat)
+        }
+    }
+
+    /// Halves the range of the `i`-th bit, returning the half of the point.
+    fn split_bit(&mut self, i: usize, lat: f64, lng: f64) -> usize {
+        let v = if i.is_multiple_of(2) { lng } else { lat };
+        let (lo, hi) = self.axis(i);
+        let mid = (*lo + *hi) / 2.0;
+        if v >= mid {
+            *lo = mid;
+            1
+        } else {
+            *hi = mid;
+            0
+        }
+    }
+
+    fn narrow_bit(&mut self, i: usize, bit: usize) {
+        let (lo, hi) = self.axis(i);
+        let mid = (*lo + *hi) / 2.0;
+        if bit == 1 {
+            *lo = mid;
+        } else {
+            *hi = mid;
+        }
+    }
+
+    /// Splits the range of the `i`-th symbol into `size` parts, returning
+    /// the part of the point.
+    fn split(&mut self, i: usize, size: usize, lat: f64, lng: f64) -> usize {
+        let v = if i.is_multiple_of(2) { lng } else { lat };
+        let (lo, hi) = self.axis(i);
+        let part = ((v - *lo) / (*hi - *lo) * size as f64) as usize;
+        let d = part.min(size - 1);
+        self.narrow(i, size, d);
+        d
+    }
+
+    fn narrow(&mut self, i: usize, size: usize, d: usize) {
+        let (lo, hi) = self.axis(i);
+        let step = (*hi - *lo) / size as f64;
+        *hi = *lo + step * (d + 1) as f64;
+        *lo += step * d as f64;
+    }
+}
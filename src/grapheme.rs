@@ -0,0 +1,18 @@
+//! Splits alphabets into user-perceived characters.
+
+use alloc::vec::Vec;
+
+/// Splits `s` into extended grapheme clusters, so that emoji joined by
+/// modifiers or zero-width joiners, flags and keycaps are single symbols.
+#[cfg(feature = "grapheme")]
+pub(crate) fn split(s: &str) -> Vec<&str> {
+    unicode_segmentation::UnicodeSegmentation::graphemes(s, true).collect()
+}
+
+/// Splits `s` into its `char`s, the `grapheme` feature is disabled.
+#[cfg(not(feature = "grapheme"))]
+pub(crate) fn split(s: &str) -> Vec<&str> {
+    s.char_indices()
+        .map(|(i, c)| &s[i..i + c.len_utf8()])
+        .collect()
+}
@@ -0,0 +1,97 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// A number tagged with the kind of entity it identifies.
+///
+/// IDs of different kinds can not be mixed up: an `Id<u64, Order>` is not
+/// accepted where an `Id<u64, User>` is expected. The marker is zero-sized
+/// and needs no trait implementations.
+///
+/// ```compile_fail
+/// use alphaid::{AlphaId, Id};
+///
+/// struct User;
+/// struct Order;
+///
+/// let users = AlphaId::<u64, User>::default();
+/// users.encode_id(Id::<u64, Order>::new(1)).unwrap();
+/// ```
+pub struct Id<T, M> {
+    value: T,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<T, M> Id<T, M> {
+    /// Wraps a raw number.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Unwraps the raw number.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Changes the kind of entity the number identifies.
+    pub fn cast<N>(self) -> Id<T, N> {
+        Id::new(self.value)
+    }
+}
+
+impl<T: Copy, M> Id<T, M> {
+    /// Returns the raw number.
+    pub fn get(&self) -> T {
+        self.value
+    }
+}
+
+impl<T, M> From<T> for Id<T, M> {
+    fn from(value: T) -> Self {
+        Id::new(value)
+    }
+}
+
+impl<T: Clone, M> Clone for Id<T, M> {
+    fn clone(&self) -> Self {
+        Id::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, M> Copy for Id<T, M> {}
+
+impl<T: fmt::Debug, M> fmt::Debug for Id<T, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Id").field(&self.value).finish()
+    }
+}
+
+impl<T: PartialEq, M> PartialEq for Id<T, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, M> Eq for Id<T, M> {}
+
+impl<T: PartialOrd, M> PartialOrd for Id<T, M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord, M> Ord for Id<T, M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash, M> Hash for Id<T, M> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
@@ -0,0 +1,79 @@
+use core::convert::TryFrom;
+use core::ops::{Add, BitAnd, BitOr, Div, Mul, Rem, Shl, Shr, Sub};
+
+/// The unsigned primitive integers which can be encoded.
+///
+/// Without the `num` feature the few operations the encoder needs are
+/// implemented here instead of by the `num` traits.
+pub trait UnsignedInteger:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Shl<usize, Output = Self>
+    + Shr<usize, Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn is_zero(&self) -> bool;
+    fn max_value() -> Self;
+    fn checked_add(&self, v: &Self) -> Option<Self>;
+    fn checked_mul(&self, v: &Self) -> Option<Self>;
+    fn to_usize(&self) -> Option<usize>;
+    fn from_usize(n: usize) -> Option<Self>;
+    fn from_u8(n: u8) -> Option<Self>;
+    fn from_i16(n: i16) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned_integer {
+    ($($t:ty),*) => {$(
+        impl UnsignedInteger for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+
+            fn max_value() -> Self {
+                <$t>::MAX
+            }
+
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *v)
+            }
+
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *v)
+            }
+
+            fn to_usize(&self) -> Option<usize> {
+                usize::try_from(*self).ok()
+            }
+
+            fn from_usize(n: usize) -> Option<Self> {
+                <$t>::try_from(n).ok()
+            }
+
+            fn from_u8(n: u8) -> Option<Self> {
+                Some(n.into())
+            }
+
+            fn from_i16(n: i16) -> Option<Self> {
+                <$t>::try_from(n).ok()
+            }
+        }
+    )*};
+}
+
+impl_unsigned_integer!(u8, u16, u32, u64, usize, u128);
@@ -0,0 +1,48 @@
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{AlphaId, AlphaIdError};
+
+impl<M> AlphaId<u128, M> {
+    /// Encode an IP address of either family.
+    ///
+    /// The result is the [`encode_pair`](AlphaId::encode_pair) of the IP
+    /// version and the address as a number, so both families round-trip.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    /// use std::net::IpAddr;
+    ///
+    /// let alphaid = AlphaId::<u128>::new();
+    /// let ip: IpAddr = "192.168.1.1".parse().unwrap();
+    /// let v = alphaid.encode_ip(ip).unwrap();
+    /// assert_eq!(alphaid.decode_ip(&v), Ok(ip));
+    ///
+    /// let ip: IpAddr = "2001:db8::1".parse().unwrap();
+    /// let v = alphaid.encode_ip(ip).unwrap();
+    /// assert_eq!(alphaid.decode_ip(&v), Ok(ip));
+    /// ```
+    pub fn encode_ip(&self, ip: IpAddr) -> Result<Vec<u8>, AlphaIdError> {
+        match ip {
+            IpAddr::V4(ip) => self.encode_pair(4, u32::from(ip).into()),
+            IpAddr::V6(ip) => self.encode_pair(6, u128::from(ip)),
+        }
+    }
+
+    /// Decode the result of [`encode_ip`](AlphaId::encode_ip).
+    ///
+    /// Returns `AlphaIdError::InvalidNumber` for unknown IP versions.
+    pub fn decode_ip<V: AsRef<[u8]>>(&self, v: V) -> Result<IpAddr, AlphaIdError> {
+        match self.decode_pair(v)? {
+            (4, n) => {
+                let n = u32::try_from(n).map_err(|_| AlphaIdError::Overflow)?;
+                Ok(IpAddr::V4(Ipv4Addr::from(n)))
+            }
+            (6, n) => Ok(IpAddr::V6(Ipv6Addr::from(n))),
+            _ => Err(AlphaIdError::InvalidNumber),
+        }
+    }
+}
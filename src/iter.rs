@@ -0,0 +1,152 @@
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::iter::FusedIterator;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// Adds lazy encoding and decoding to every iterator.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, AlphaIdIterExt};
+///
+/// let alphaid = AlphaId::<u32>::new();
+/// let ids = [1, 1350997667];
+/// let slugs: Vec<_> = ids.iter().alpha_encode(&alphaid).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(slugs, [b"b".to_vec(), b"90F7qb".to_vec()]);
+///
+/// let decoded: Vec<u32> = slugs.iter().alpha_decode(&alphaid).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(decoded, ids);
+/// ```
+pub trait AlphaIdIterExt: Iterator + Sized {
+    /// Encode every number, see [`AlphaId::encode`].
+    fn alpha_encode<T, M>(self, alphaid: &AlphaId<T, M>) -> AlphaEncode<'_, Self, T, M>
+    where
+        T: UnsignedInteger,
+        Self::Item: Borrow<T>,
+    {
+        AlphaEncode {
+            iter: self,
+            alphaid,
+        }
+    }
+
+    /// Decode every encoded result, see [`AlphaId::decode`].
+    fn alpha_decode<T, M>(self, alphaid: &AlphaId<T, M>) -> AlphaDecode<'_, Self, T, M>
+    where
+        T: UnsignedInteger,
+        Self::Item: AsRef<[u8]>,
+    {
+        AlphaDecode {
+            iter: self,
+            alphaid,
+        }
+    }
+}
+
+impl<I: Iterator> AlphaIdIterExt for I {}
+
+/// The iterator of [`AlphaIdIterExt::alpha_encode`].
+pub struct AlphaEncode<'a, I, T: UnsignedInteger, M = ()> {
+    iter: I,
+    alphaid: &'a AlphaId<T, M>,
+}
+
+impl<I, T, M> Iterator for AlphaEncode<'_, I, T, M>
+where
+    I: Iterator,
+    I::Item: Borrow<T>,
+    T: UnsignedInteger,
+{
+    type Item = Result<Vec<u8>, AlphaIdError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.iter.next()?;
+        Some(self.alphaid.encode(*n.borrow()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, M> DoubleEndedIterator for AlphaEncode<'_, I, T, M>
+where
+    I: DoubleEndedIterator,
+    I::Item: Borrow<T>,
+    T: UnsignedInteger,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let n = self.iter.next_back()?;
+        Some(self.alphaid.encode(*n.borrow()))
+    }
+}
+
+impl<I, T, M> ExactSizeIterator for AlphaEncode<'_, I, T, M>
+where
+    I: ExactSizeIterator,
+    I::Item: Borrow<T>,
+    T: UnsignedInteger,
+{
+}
+
+impl<I, T, M> FusedIterator for AlphaEncode<'_, I, T, M>
+where
+    I: FusedIterator,
+    I::Item: Borrow<T>,
+    T: UnsignedInteger,
+{
+}
+
+/// The iterator of [`AlphaIdIterExt::alpha_decode`].
+pub struct AlphaDecode<'a, I, T: UnsignedInteger, M = ()> {
+    iter: I,
+    alphaid: &'a AlphaId<T, M>,
+}
+
+impl<I, T, M> Iterator for AlphaDecode<'_, I, T, M>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+    T: UnsignedInteger,
+{
+    type Item = Result<T, AlphaIdError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.iter.next()?;
+        Some(self.alphaid.decode(v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, M> DoubleEndedIterator for AlphaDecode<'_, I, T, M>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<[u8]>,
+    T: UnsignedInteger,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let v = self.iter.next_back()?;
+        Some(self.alphaid.decode(v))
+    }
+}
+
+impl<I, T, M> ExactSizeIterator for AlphaDecode<'_, I, T, M>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<[u8]>,
+    T: UnsignedInteger,
+{
+}
+
+impl<I, T, M> FusedIterator for AlphaDecode<'_, I, T, M>
+where
+    I: FusedIterator,
+    I::Item: AsRef<[u8]>,
+    T: UnsignedInteger,
+{
+}
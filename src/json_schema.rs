@@ -0,0 +1,92 @@
+//! The string schema of an [`EncodedId`](crate::EncodedId), shared by the
+//! `schemars` and `utoipa` implementations.
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{AlphaId, Encoding, UnsignedInteger};
+
+/// A `string` with a pattern and length bounds in characters.
+pub(crate) struct StringSchema {
+    pub(crate) name: &'static str,
+    pub(crate) pattern: String,
+    pub(crate) min_length: Option<usize>,
+    pub(crate) max_length: Option<usize>,
+}
+
+impl StringSchema {
+    pub(crate) fn of<E: Encoding>() -> Self {
+        // `a::b::Users` is named `Users`.
+        let name = core::any::type_name::<E>();
+        let name = name.split('<').next().unwrap_or(name);
+        let name = name.rsplit("::").next().unwrap_or(name);
+        E::alphaid().string_schema(name)
+    }
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Returns a pattern matching every encoded result, and the lengths of
+    /// the shortest and the longest one.
+    ///
+    /// The pattern only checks the characters, not the canonical form
+    /// which [`decode_strict`](AlphaId::decode_strict) checks.
+    fn string_schema(&self, name: &'static str) -> StringSchema {
+        let mut pattern = String::from("^");
+        if self.symbols.is_empty() {
+            // Every byte decodes from the char of the same value.
+            let text = |bytes: &[u8]| bytes.iter().map(|&b| char::from(b)).collect::<String>();
+            push_escaped(&mut pattern, &text(&self.prefix), false);
+            pattern.push('[');
+            let mut class: Vec<u8> = self.chars.to_vec();
+            class.extend(self.group.map(|(_, sep)| sep));
+            push_escaped(&mut pattern, &text(&class), true);
+            pattern.push_str("]+");
+            push_escaped(&mut pattern, &text(&self.suffix), false);
+        } else {
+            push_escaped(&mut pattern, &String::from_utf8_lossy(&self.prefix), false);
+            pattern.push_str("(?:");
+            for (i, symbol) in self.symbols.iter().enumerate() {
+                if i > 0 {
+                    pattern.push('|');
+                }
+                push_escaped(&mut pattern, symbol, false);
+            }
+            if let Some((_, sep)) = self.group {
+                pattern.push('|');
+                push_escaped(&mut pattern, &String::from(char::from(sep)), false);
+            }
+            pattern.push_str(")+");
+            push_escaped(&mut pattern, &String::from_utf8_lossy(&self.suffix), false);
+        }
+        pattern.push('$');
+
+        // Symbols take several bytes but one or more characters.
+        let (min_length, max_length) = if self.symbols.is_empty() {
+            let digits = self.width.unwrap_or(self.pad).max(1) as usize;
+            let seps = self.group.map_or(0, |(size, _)| (digits - 1) / size);
+            (
+                Some(self.prefix.len() + digits + seps + self.suffix.len()),
+                Some(self.max_encoded_len()),
+            )
+        } else {
+            (None, None)
+        };
+
+        StringSchema {
+            name,
+            pattern,
+            min_length,
+            max_length,
+        }
+    }
+}
+
+/// Appends `s` to a regular expression, escaping the syntax characters of
+/// ECMA 262, which JSON Schema patterns follow.
+fn push_escaped(pattern: &mut String, s: &str, in_class: bool) {
+    for c in s.chars() {
+        if "^$\\.*+?()[]{}|/".contains(c) || (in_class && c == '-') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+}
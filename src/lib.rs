@@ -10,6 +10,13 @@
 //! assert_eq!(alphaid.decode(b"90F7qb"), Ok(1350997667));
 //! ```
 //!
+//! The default settings are also available as free functions.
+//!
+//! ```rust
+//! assert_eq!(alphaid::encode(1350997667u32), "90F7qb");
+//! assert_eq!(alphaid::decode("90F7qb"), Ok(1350997667));
+//! ```
+//!
 //! ## Padding
 //! Specifies the minimum length of the encoded result.
 //!
@@ -38,40 +45,530 @@
 //! assert_eq!(alphaid.decode(b"AB"), Ok(0));
 //! ```
 //!
+//! ## `no_std`
+//! The crate only needs `alloc` when the default `std` feature is disabled.
+//! The `io` helpers and the `alphaid!` macro require `std`. Random
+//! padding and IDs need the `rand` feature, which draws from the operating
+//! system's generator through `getrandom`.
+//!
+//! The `num` dependency can be dropped as well by disabling the default
+//! `num` feature, [`UnsignedInteger`] is then implemented by the crate.
 //!
 //! ## Reference
 //!
 //! [Create Youtube-Like IDs](https://kvz.io/create-short-ids-with-php-like-youtube-or-tinyurl.html)
-use num::{Bounded, FromPrimitive, Integer, NumCast, ToPrimitive};
-use std::collections::HashMap;
-use std::marker::PhantomData;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "num")]
+use num::{Bounded, FromPrimitive, Integer, NumCast, PrimInt, ToPrimitive};
+
+extern crate alloc;
+
+#[cfg(feature = "uniffi")]
+::uniffi::setup_scaffolding!();
 
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "ascii")]
+mod ascii;
+#[cfg(feature = "base58check")]
+mod base58;
+mod blocklist;
+#[cfg(feature = "cache")]
+mod cache;
+mod char_class;
+mod codec;
+mod config;
+mod ct;
+#[cfg(feature = "cursor")]
+mod cursor;
+mod dec_str;
+#[cfg(feature = "diesel")]
+mod diesel;
+mod digits;
+mod dns;
+mod dynamic;
+mod encoded;
+mod encoder;
+#[cfg(feature = "std")]
+mod expiry;
+mod fast;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fixed;
+mod fixed_digits;
+#[cfg(feature = "std")]
+mod generator;
+#[cfg(feature = "geo")]
+mod geo;
+mod grapheme;
+mod id;
+#[cfg(not(feature = "num"))]
+mod int;
+#[cfg(feature = "ip")]
+mod ip;
+mod iter;
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+mod json_schema;
+mod lint;
+mod list;
+mod macros;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+mod migrate;
+mod mixed_radix;
+mod nonzero;
+mod obfuscate;
+#[cfg(feature = "bson")]
+mod object_id;
+mod option;
+mod pad;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod permute;
+#[cfg(feature = "php")]
+mod php;
+mod pipeline;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+mod proquint;
+mod qr;
+#[cfg(feature = "rand")]
+mod random;
+mod registry;
+mod rng;
+#[cfg(feature = "rocket")]
+mod rocket;
+#[cfg(feature = "rug")]
+mod rug;
+mod schema;
+#[cfg(feature = "schemars")]
+mod schemars;
+mod sequence;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "tokio")]
+mod service;
+mod set;
+mod shard;
+mod signed;
+mod simd;
+mod small;
+mod spatial;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "std")]
+mod stream;
+mod suggest;
+mod tag;
+#[cfg(feature = "std")]
+mod time;
+#[cfg(feature = "tracing")]
+mod tracing;
+mod tuple;
+#[cfg(feature = "primitive-types")]
+mod u256;
+#[cfg(feature = "uniffi")]
+pub mod uniffi;
+#[cfg(feature = "utoipa")]
+mod utoipa;
+mod validate;
+mod vanity;
+#[cfg(feature = "vectors")]
+pub mod vectors;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "web")]
+mod web;
+
+#[cfg(feature = "derive")]
+pub use alphaid_derive::{alphaid, AlphaEncoded};
+#[cfg(feature = "ascii")]
+pub use ascii::AsciiId;
+#[cfg(feature = "base58check")]
+pub use base58::{decode_base58check, encode_base58check};
+#[cfg(feature = "cache")]
+pub use cache::CachedAlphaId;
+pub use char_class::CharClass;
+pub use codec::Codec;
+pub use config::Config;
+#[cfg(feature = "cursor")]
+pub use cursor::{Cursor, CursorError};
+pub use dns::{decode_dns_label, encode_dns_label};
+pub use dynamic::{DynAlphaId, DynInteger};
+pub use encoded::{EncodedId, Encoding};
+pub use encoder::{Decoder, Encoder, StreamDecoder};
+pub use fast::{AlphaId128, AlphaId64};
+pub use fixed::AlphaIdConst;
+#[cfg(feature = "std")]
+pub use generator::{SnowflakeGen, SortableGen};
+#[cfg(feature = "geo")]
+pub use geo::LatLngBox;
+pub use id::Id;
+#[cfg(not(feature = "num"))]
+pub use int::UnsignedInteger;
+pub use iter::{AlphaDecode, AlphaEncode, AlphaIdIterExt};
+pub use lint::Lint;
+#[doc(hidden)]
+pub use macros::__private;
+pub use migrate::Migrator;
+pub use mixed_radix::MixedRadix;
+pub use nonzero::NonZeroInteger;
+pub use obfuscate::Obfuscator;
+#[cfg(feature = "php")]
+pub use php::PhpAlphaId;
+pub use qr::{qr_savings, QR_ALPHANUMERIC};
+#[cfg(feature = "rand")]
+pub use random::UniqueGen;
+pub use registry::{AlphaIdRegistry, PrefixRegistry, RegistryError, VersionedDecoder};
+pub use schema::Schema;
+pub use sequence::{SequenceSource, SequentialGen, SourceError, SourceGen};
+#[cfg(feature = "tokio")]
+pub use service::{IdService, ServiceError};
+#[doc(hidden)]
+pub use simd::__private as __simd;
+pub use small::SmallBytes;
+#[cfg(feature = "std")]
+pub use stats::{LengthStats, Stats};
+pub use suggest::SuggestedError;
+#[cfg(feature = "std")]
+pub use time::TimeUnit;
+pub use validate::{Problem, ValidationReport};
+
+use blocklist::Blocklist;
+#[cfg(feature = "blocklist")]
+pub use blocklist::ENGLISH_BLOCKLIST;
+use digits::Digits;
+use permute::DigitPermutation;
+use rng::SplitMix64;
+use simd::Ranges;
+
+/// The unsigned primitive integers which can be encoded.
+#[cfg(feature = "num")]
 pub trait UnsignedInteger:
-    Integer + Bounded + ToPrimitive + FromPrimitive + NumCast + Copy
+    Integer + PrimInt + Bounded + ToPrimitive + FromPrimitive + NumCast + Copy
 {
 }
 
+#[cfg(feature = "num")]
+impl UnsignedInteger for u8 {}
+#[cfg(feature = "num")]
 impl UnsignedInteger for u16 {}
+#[cfg(feature = "num")]
 impl UnsignedInteger for u32 {}
+#[cfg(feature = "num")]
 impl UnsignedInteger for u64 {}
+#[cfg(feature = "num")]
 impl UnsignedInteger for usize {}
+#[cfg(feature = "num")]
 impl UnsignedInteger for u128 {}
 
-static DEFAULT_SEED: &'static str =
-    "abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ-_";
+/// Converts a `u64` into `T`, returning `None` if it does not fit.
+pub(crate) fn from_u64<T: UnsignedInteger>(n: u64) -> Option<T> {
+    from_u128(n.into())
+}
 
-#[derive(Debug, PartialEq)]
+/// Converts a `u128` into `T`, returning `None` if it does not fit.
+pub(crate) fn from_u128<T: UnsignedInteger>(n: u128) -> Option<T> {
+    let mut acc = T::zero();
+    for i in (0..8).rev() {
+        let chunk = T::from_usize((n >> (16 * i) & 0xFFFF) as usize)?;
+        acc = if acc.is_zero() {
+            chunk
+        } else {
+            acc.checked_mul(&T::from_usize(1 << 16)?)?
+                .checked_add(&chunk)?
+        };
+    }
+    Some(acc)
+}
+
+/// Converts `n` into a `u128`.
+pub(crate) fn to_u128<T: UnsignedInteger>(n: T) -> u128 {
+    let chunk = match T::from_usize(1 << 16) {
+        Some(chunk) => chunk,
+        None => return n.to_usize().unwrap_or(0) as u128,
+    };
+    let mut acc = 0;
+    let mut rest = n;
+    let mut shift = 0;
+    while !rest.is_zero() {
+        acc |= ((rest % chunk).to_usize().unwrap_or(0) as u128) << shift;
+        rest = rest / chunk;
+        shift += 16;
+    }
+    acc
+}
+
+/// Hashes `n` in chunks of 16 bits, as `UnsignedInteger` does not require
+/// `Hash`.
+pub(crate) fn hash_int<T: UnsignedInteger, H: core::hash::Hasher>(n: T, state: &mut H) {
+    let chunk = match T::from_usize(1 << 16) {
+        Some(chunk) => chunk,
+        None => return state.write_usize(n.to_usize().unwrap_or(0)),
+    };
+    let mut rest = n;
+    while !rest.is_zero() {
+        state.write_usize((rest % chunk).to_usize().unwrap_or(0));
+        rest = rest / chunk;
+    }
+}
+
+const DEFAULT_SEED: &str = "abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ-_";
+
+/// The 62 ASCII letters and digits, which are selected as one word by a
+/// double click and survive any renderer.
+///
+/// ```rust
+/// use alphaid::{AlphaId, ALNUM62};
+///
+/// let alphaid = AlphaId::<u32>::builder().chars_str(ALNUM62).build();
+/// assert_eq!(alphaid.encode(u32::MAX), Ok(b"dmpFGe".to_vec()));
+/// ```
+pub const ALNUM62: &str = "abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// 64 emoji which render as a single glyph on all major platforms.
+pub const EMOJI: &str = "🐶🐱🐭🐹🐰🦊🐻🐼🐨🐯🦁🐮🐷🐸🐵🐔🐧🐦🐤🦆🦅🦉🦇🐺🐗🐴🦄🐝🐛🦋🐌🐞\
+                         🐜🐢🐍🦎🐙🦑🦀🐡🐠🐟🐬🐳🐋🦈🐊🐅🐆🦓🦍🐘🦏🐪🐫🦒🐃🐂🐄🐎🐖🐏🐑🐐";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(::uniffi::Error))]
+#[non_exhaustive]
 pub enum AlphaIdError {
     InvalidNumber,
     PadMissed,
     Overflow,
     UnexpectedChar,
+    Blocked,
+    InvalidLength,
+    PrefixMissed,
+    SuffixMissed,
+    NonUtf8,
+    BufferTooSmall,
+    WriteFailed,
+    NonCanonical,
+    EmptyInput,
+    InputTooLong,
+    InvalidConfig,
+    UnknownName,
+    UnknownVersion,
+    Expired,
+    Exhausted,
+    TagMismatch,
+    ChecksumMismatch,
+    Zero,
+}
+
+impl fmt::Display for AlphaIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AlphaIdError::InvalidNumber => "invalid number",
+            AlphaIdError::PadMissed => "pad missed",
+            AlphaIdError::Overflow => "number overflow",
+            AlphaIdError::UnexpectedChar => "unexpected character",
+            AlphaIdError::Blocked => "blocked word",
+            AlphaIdError::InvalidLength => "invalid length",
+            AlphaIdError::PrefixMissed => "prefix missed",
+            AlphaIdError::SuffixMissed => "suffix missed",
+            AlphaIdError::NonUtf8 => "invalid UTF-8",
+            AlphaIdError::BufferTooSmall => "buffer too small",
+            AlphaIdError::WriteFailed => "write failed",
+            AlphaIdError::NonCanonical => "not the canonical encoding",
+            AlphaIdError::EmptyInput => "empty input",
+            AlphaIdError::InputTooLong => "input too long",
+            AlphaIdError::InvalidConfig => "invalid configuration",
+            AlphaIdError::UnknownName => "unknown name",
+            AlphaIdError::UnknownVersion => "unknown version",
+            AlphaIdError::Expired => "expired",
+            AlphaIdError::Exhausted => "too many collisions",
+            AlphaIdError::TagMismatch => "tag mismatch",
+            AlphaIdError::ChecksumMismatch => "checksum mismatch",
+            AlphaIdError::Zero => "zero is not allowed",
+        })
+    }
+}
+
+impl core::error::Error for AlphaIdError {}
+
+#[cfg(any(feature = "metrics", feature = "tracing"))]
+impl AlphaIdError {
+    /// The snake case name of the variant, e.g. `unexpected_char`.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            AlphaIdError::InvalidNumber => "invalid_number",
+            AlphaIdError::PadMissed => "pad_missed",
+            AlphaIdError::Overflow => "overflow",
+            AlphaIdError::UnexpectedChar => "unexpected_char",
+            AlphaIdError::Blocked => "blocked",
+            AlphaIdError::InvalidLength => "invalid_length",
+            AlphaIdError::PrefixMissed => "prefix_missed",
+            AlphaIdError::SuffixMissed => "suffix_missed",
+            AlphaIdError::NonUtf8 => "non_utf8",
+            AlphaIdError::BufferTooSmall => "buffer_too_small",
+            AlphaIdError::WriteFailed => "write_failed",
+            AlphaIdError::NonCanonical => "non_canonical",
+            AlphaIdError::EmptyInput => "empty_input",
+            AlphaIdError::InputTooLong => "input_too_long",
+            AlphaIdError::InvalidConfig => "invalid_config",
+            AlphaIdError::UnknownName => "unknown_name",
+            AlphaIdError::UnknownVersion => "unknown_version",
+            AlphaIdError::Expired => "expired",
+            AlphaIdError::Exhausted => "exhausted",
+            AlphaIdError::TagMismatch => "tag_mismatch",
+            AlphaIdError::ChecksumMismatch => "checksum_mismatch",
+            AlphaIdError::Zero => "zero",
+        }
+    }
+}
+
+/// The order of the digits in the encoded result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "uniffi", derive(::uniffi::Enum))]
+pub enum DigitOrder {
+    /// The least significant digit comes first.
+    #[default]
+    LittleEndian,
+    /// The most significant digit comes first, like classic base62.
+    BigEndian,
+}
+
+/// The largest integer a JavaScript number represents exactly,
+/// `Number.MAX_SAFE_INTEGER`, see [`Builder::max_value`].
+pub const JS_SAFE: u64 = (1 << 53) - 1;
+
+/// An error of [`AlphaId::decode_str_with_position`] and
+/// [`AlphaId::decode_concat`].
+#[derive(Debug, PartialEq)]
+pub struct PositionedError {
+    pub error: AlphaIdError,
+    /// The index of the first unexpected `char` of the input, or the byte
+    /// offset of the ID which failed to decode, if any.
+    pub position: Option<usize>,
+}
+
+static DEFAULT: AlphaIdConst<u128, 64> = AlphaIdConst::DEFAULT;
+
+/// Encode the numbers with the default settings.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(alphaid::encode(1350997667u32), "90F7qb");
+/// assert_eq!(alphaid::encode(0u8), "a");
+/// ```
+pub fn encode<N: Into<u128>>(n: N) -> String {
+    let mut buf = [0; 22];
+    let len = DEFAULT
+        .encode_to_slice(n.into(), &mut buf)
+        .expect("any u128 fits into 22 symbols");
+    buf[..len].iter().map(|&b| b as char).collect()
+}
+
+/// Decode into numbers with the default settings.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(alphaid::decode("90F7qb"), Ok(1350997667));
+/// ```
+pub fn decode<V: AsRef<[u8]>>(v: V) -> Result<u128, AlphaIdError> {
+    DEFAULT.decode(v)
+}
+
+/// How the encoded result is filled up to the minimum length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "uniffi", derive(::uniffi::Enum))]
+#[non_exhaustive]
+pub enum PadMode {
+    /// Fills with the zero symbol and shifts the numbers, so that every
+    /// combination of the minimum length decodes to a distinct number.
+    #[default]
+    Shift,
+    /// Fills with random symbols after a terminator, so that small numbers
+    /// look like any other. The last character of the set becomes the
+    /// terminator and is no longer used as a digit. The filler is drawn
+    /// from the operating system's random number generator, so building
+    /// fails without the `rand` feature.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "rand")] {
+    /// use alphaid::{AlphaId, PadMode};
+    ///
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .pad(8)
+    ///     .pad_mode(PadMode::Random)
+    ///     .build();
+    /// let v = alphaid.encode(1).unwrap();
+    /// assert_eq!(v.len(), 8);
+    /// assert_eq!(&v[..2], b"b_");
+    /// assert_eq!(alphaid.decode(&v), Ok(1));
+    /// # }
+    /// ```
+    Random,
+    /// Fills with the zero symbol without changing the numbers, like
+    /// left-padding in other base-N implementations when used with
+    /// `DigitOrder::BigEndian`. Shorter inputs decode to the same numbers.
+    Fill,
+}
+
+impl PadMode {
+    fn is_random(self) -> bool {
+        self == PadMode::Random
+    }
 }
 
 /// A builder for a `AlphaId`.
+#[derive(Clone)]
 pub struct Builder<T: UnsignedInteger = u128> {
-    chars: Option<Vec<u8>>,
+    chars: Option<Cow<'static, [u8]>>,
+    symbols: Option<Vec<String>>,
     pad: Option<u32>,
+    pad_char: Option<u8>,
+    pad_mode: PadMode,
+    width: Option<u32>,
+    group: Option<(usize, u8)>,
+    prefix: Vec<u8>,
+    suffix: Vec<u8>,
+    confusables: Vec<(Vec<u8>, u8)>,
+    blocklist: Blocklist,
+    order: DigitOrder,
+    reject_empty: bool,
+    max_input_len: Option<usize>,
+    tag: Option<([u8; 16], u32)>,
+    sign: Option<u8>,
+    none_symbol: Option<u8>,
+    max_len: Option<usize>,
+    digit_seed: Option<u64>,
+    max_value: Option<u128>,
+    offset: Option<u128>,
+    tag_key_id: bool,
+    old_tag_keys: Vec<[u8; 16]>,
+    obfuscators: Vec<Arc<dyn Obfuscator<T>>>,
+    url_select_safe: bool,
+    shuffle_seed: Option<u64>,
+    allow_raw_bytes: bool,
+    allow_small_alphabet: bool,
     _data: PhantomData<T>,
 }
 
@@ -79,7 +576,33 @@ impl<T: UnsignedInteger> Default for Builder<T> {
     fn default() -> Self {
         Self {
             chars: None,
+            symbols: None,
             pad: None,
+            pad_char: None,
+            pad_mode: PadMode::default(),
+            width: None,
+            group: None,
+            prefix: Vec::new(),
+            suffix: Vec::new(),
+            confusables: Vec::new(),
+            blocklist: Blocklist::default(),
+            order: DigitOrder::default(),
+            reject_empty: false,
+            max_input_len: None,
+            tag: None,
+            sign: None,
+            none_symbol: None,
+            max_len: None,
+            digit_seed: None,
+            max_value: None,
+            offset: None,
+            tag_key_id: false,
+            old_tag_keys: Vec::new(),
+            obfuscators: Vec::new(),
+            url_select_safe: false,
+            shuffle_seed: None,
+            allow_raw_bytes: false,
+            allow_small_alphabet: false,
             _data: PhantomData,
         }
     }
@@ -97,187 +620,2729 @@ impl<T: UnsignedInteger> Builder<T> {
     ///
     /// Default to `abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ-_`.
     ///
+    /// Only printable ASCII characters are accepted unless
+    /// [`allow_raw_bytes`](Builder::allow_raw_bytes) is set.
+    ///
     /// # Panics
     ///
-    /// Panics if chars' size is less than `16`.
+    /// Panics when building if chars' size is not larger than `16`, see
+    /// [`allow_small_alphabet`](Builder::allow_small_alphabet).
     pub fn chars(mut self, chars: Vec<u8>) -> Self {
-        assert!(chars.len() > 16, "chars size must large than 16");
-        self.chars = Some(chars);
+        self.chars = Some(Cow::Owned(chars));
+        self.symbols = None;
         self
     }
 
-    /// Sets the pad which specifies the minimum
-    /// length of the encoded result.
-    ///
-    /// Default to 1.
+    /// Sets the characters set like [`chars`](Builder::chars), without
+    /// copying it unless it has to be reordered.
     ///
-    /// # Panics
+    /// ```rust
+    /// use alphaid::AlphaId;
     ///
-    /// Panics if pad is less than 0.
-    pub fn pad(mut self, pad: u32) -> Self {
-        assert!(pad > 0, "pad must large than 1");
-        self.pad = Some(pad);
+    /// let alphaid = AlphaId::<u32>::builder().chars_static(b"0123456789abcdefghij").build();
+    /// assert_eq!(alphaid.encode(1350997667), Ok(b"73ee3211".to_vec()));
+    /// ```
+    pub fn chars_static(mut self, chars: &'static [u8]) -> Self {
+        self.chars = Some(Cow::Borrowed(chars));
+        self.symbols = None;
         self
     }
 
-    /// Consumes the builder, returning a `AlphaId`.
+    /// Sets the characters set from a string, one user-perceived
+    /// character per symbol.
+    ///
+    /// Unlike [`chars`](Builder::chars), the symbols may be any Unicode
+    /// characters, e.g. Cyrillic, Greek or CJK letters. With the default
+    /// `grapheme` feature, emoji sequences joined by modifiers or
+    /// zero-width joiners count as a single symbol; without it every
+    /// `char` is one. Use [`AlphaId::encode_chars`] and
+    /// [`AlphaId::decode_str`] to work with `String`s; `encode` and
+    /// `decode` operate on the UTF-8 bytes.
     ///
     /// # Panics
     ///
-    /// Panics if there are duplicate characters in chars.
-    pub fn build(self) -> AlphaId<T> {
-        let chars = self
-            .chars
-            .unwrap_or_else(|| DEFAULT_SEED.as_bytes().to_vec());
+    /// Panics when building if the number of chars is not larger than `16`.
+    pub fn chars_str(self, chars: &str) -> Self {
+        if chars.is_ascii() {
+            return self.chars(chars.as_bytes().to_vec());
+        }
 
-        let index: HashMap<u8, T> = chars
-            .iter()
-            .enumerate()
-            .map(|(i, v)| (*v, T::from_usize(i).unwrap()))
+        let symbols: Vec<String> = grapheme::split(chars)
+            .into_iter()
+            .map(String::from)
             .collect();
+        self.symbols(symbols)
+    }
 
+    /// Sets the characters set to a list of strings, e.g. syllables or
+    /// short words, each of them used as one digit.
+    ///
+    /// The longest matching symbol is taken when decoding, so the results
+    /// can only be decoded unambiguously if no symbol is the concatenation
+    /// of other symbols.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a symbol is empty, and when building if the number of
+    /// symbols is not larger than `16`.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let symbols = [
+    ///     "ka", "ki", "ku", "ke", "ko", "sa", "shi", "su", "se", "so", "ta", "chi", "tsu", "te",
+    ///     "to", "na", "ni",
+    /// ];
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .symbols(symbols.iter().map(|s| s.to_string()).collect())
+    ///     .build();
+    /// assert_eq!(alphaid.encode_string(215), Ok("chitsu".to_string()));
+    /// assert_eq!(alphaid.decode(b"chitsu"), Ok(215));
+    /// ```
+    pub fn symbols(mut self, symbols: Vec<String>) -> Self {
         assert!(
-            chars.len() == index.len(),
-            "duplicate characters are not allowed"
+            symbols.iter().all(|s| !s.is_empty()),
+            "symbols must not be empty"
         );
-        let base = T::from_usize(chars.len()).expect("primitive number types");
-        let a: f64 = <f64 as NumCast>::from(T::max_value()).unwrap();
-        let b: f64 = <f64 as NumCast>::from(base.clone()).unwrap();
-        let max_pow_i = a.log(b) as u32;
-        AlphaId {
-            chars,
-            index,
-            base,
-            pad: self.pad.unwrap_or(1),
-            max_pow_i,
+        self.chars = None;
+        self.symbols = Some(symbols);
+        self
+    }
+
+    /// Sets the characters set to a list of words, joined by `sep` when
+    /// encoding, e.g. `amber-falcon-tide`.
+    ///
+    /// The words are looked up between the separators when decoding, so a
+    /// word may start with another one, like `act` and `action` in the
+    /// BIP39 list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a word is empty or contains the separator, and when building
+    /// if there are less than `17` words.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let words = [
+    ///     "amber", "falcon", "tide", "oak", "river", "stone", "cloud", "ember", "frost", "grove",
+    ///     "harbor", "iris", "jade", "kite", "lumen", "maple", "north",
+    /// ];
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .words(&words, b'-')
+    ///     .order(alphaid::DigitOrder::BigEndian)
+    ///     .build();
+    /// assert_eq!(alphaid.encode_string(291), Ok("falcon-amber-tide".to_string()));
+    /// assert_eq!(alphaid.decode(b"falcon-amber-tide"), Ok(291));
+    /// ```
+    #[cfg(feature = "words")]
+    pub fn words<S: AsRef<str>>(mut self, words: &[S], sep: u8) -> Self {
+        let symbols: Vec<String> = words.iter().map(|w| w.as_ref().to_string()).collect();
+        assert!(
+            symbols
+                .iter()
+                .all(|w| !w.is_empty() && !w.as_bytes().contains(&sep)),
+            "words must not be empty or contain the separator"
+        );
+        self.group = Some((1, sep));
+        self.symbols(symbols)
+    }
+
+    /// Sets the characters set to the 80 syllables of a consonant and a
+    /// vowel, e.g. `ba`, `zu`, so that every result alternates consonants
+    /// and vowels and can be read out loud.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().pronounceable().build();
+    /// let v = alphaid.encode_string(1350997667).unwrap();
+    /// assert_eq!(v, "tikapozoki");
+    /// assert_eq!(alphaid.decode(&v), Ok(1350997667));
+    /// ```
+    pub fn pronounceable(self) -> Self {
+        let mut symbols = Vec::with_capacity(80);
+        for c in "bdfghjklmnprstvz".chars() {
+            for v in "aeiou".chars() {
+                symbols.push([c, v].iter().collect());
+            }
         }
+        self.symbols(symbols)
     }
-}
 
-/// Used for encoding and decoding.
-pub struct AlphaId<T: UnsignedInteger = u128> {
-    chars: Vec<u8>,
-    index: HashMap<u8, T>,
-    base: T,
-    pad: u32,
-    max_pow_i: u32,
-}
+    /// Sets the characters set to the [`EMOJI`] preset.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().emoji().build();
+    /// assert_eq!(alphaid.encode_chars(1350997667), Ok("🦎🦄🐟🐢🐧🐱".to_string()));
+    /// assert_eq!(alphaid.decode_str("🦎🦄🐟🐢🐧🐱"), Ok(1350997667));
+    /// ```
+    pub fn emoji(self) -> Self {
+        self.chars_str(EMOJI)
+    }
 
-impl<T: UnsignedInteger> AlphaId<T> {
-    /// Returns a builder type to configure a new `AlphaId`.
-    pub fn builder() -> Builder<T> {
-        Builder::new()
+    /// Sets the characters set to [`QR_ALPHANUMERIC`], which QR codes store
+    /// in 5.5 bits per character instead of 8, see [`qr_savings`].
+    pub fn qr_alphanumeric(self) -> Self {
+        self.chars_static(QR_ALPHANUMERIC.as_bytes())
     }
 
-    /// Creates a new `AlphaId` with a default configuration.
-    pub fn new() -> Self {
-        Builder::new().build()
+    /// Requires the characters set and the group separator to be letters
+    /// and digits when building, so that a double click selects the whole
+    /// result. The default characters set contains `-` and `_`, use
+    /// [`ALNUM62`] instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if a character is neither a letter nor a digit.
+    ///
+    /// ```rust,should_panic
+    /// use alphaid::AlphaId;
+    ///
+    /// // The default characters set contains `-` and `_`.
+    /// AlphaId::<u32>::builder().url_select_safe(true).build();
+    /// ```
+    pub fn url_select_safe(mut self, safe: bool) -> Self {
+        self.url_select_safe = safe;
+        self
     }
 
-    /// Encode the numbers.
+    /// Shuffles the characters set with a pseudo random generator seeded by
+    /// `seed`, so that the results differ from those of other seeds.
     ///
-    /// # Example
+    /// The shuffle is the same on every platform and version. It hides the
+    /// order of the numbers only from casual observers.
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if combined with the sortable mode.
     ///
     /// ```rust
     /// use alphaid::AlphaId;
     ///
-    /// let alphaid = AlphaId::<u32>::new();
-    /// assert_eq!(alphaid.encode(0), Ok(b"a".to_vec()));
-    /// assert_eq!(alphaid.encode(1), Ok(b"b".to_vec()));
-    /// assert_eq!(alphaid.encode(1350997667), Ok(b"90F7qb".to_vec()));
+    /// let a = AlphaId::<u32>::builder().shuffle_seed(1).build();
+    /// let b = AlphaId::<u32>::builder().shuffle_seed(2).build();
+    /// assert_ne!(a.encode(1350997667), b.encode(1350997667));
+    /// assert_eq!(a.decode(a.encode(1350997667).unwrap()), Ok(1350997667));
     /// ```
-    pub fn encode(&self, mut n: T) -> Result<Vec<u8>, AlphaIdError> {
-        let mut out = vec![];
-        let mut i = 0;
-        loop {
-            i += 1;
-            if self.pad > 1 && self.pad == i {
-                n = n + T::one();
-            }
+    pub fn shuffle_seed(mut self, seed: u64) -> Self {
+        self.shuffle_seed = Some(seed);
+        self
+    }
 
-            if n.is_zero() {
-                if i <= self.pad {
-                    out.push(self.chars[0]);
-                    continue;
-                }
-                break;
-            }
+    /// Allows control characters, whitespace and non-ASCII bytes in the
+    /// characters set, e.g. to encode into Latin-1. The results of such an
+    /// alphabet may not be valid UTF-8, so [`AlphaId::encode_string`] and
+    /// [`AlphaId::decode_str`] map each byte, including those of the
+    /// affixes and the separator, to the `char` with the same code point.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .chars((0xa0..0xc0).collect())
+    ///     .allow_raw_bytes()
+    ///     .build();
+    /// assert_eq!(alphaid.encode(0), Ok(vec![0xa0]));
+    /// ```
+    pub fn allow_raw_bytes(mut self) -> Self {
+        self.allow_raw_bytes = true;
+        self
+    }
 
-            let a = n % self.base;
-            out.push(self.chars[a.to_usize().ok_or_else(|| AlphaIdError::InvalidNumber)?]);
-            n = n / self.base;
-        }
+    /// Allows characters sets of 2 to 16 characters, e.g. `ACGT` or the
+    /// digits of a dial pad. The results are much longer than with the
+    /// default characters set.
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if there are less than 2 characters, or less
+    /// than 3 with `PadMode::Random`, which uses one of them as terminator.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, DigitOrder};
+    ///
+    /// let alphaid = AlphaId::<u8>::builder()
+    ///     .chars(b"ACGT".to_vec())
+    ///     .allow_small_alphabet()
+    ///     .order(DigitOrder::BigEndian)
+    ///     .build();
+    /// assert_eq!(alphaid.encode(27), Ok(b"CGT".to_vec()));
+    /// assert_eq!(alphaid.decode(b"CGT"), Ok(27));
+    /// ```
+    pub fn allow_small_alphabet(mut self) -> Self {
+        self.allow_small_alphabet = true;
+        self
+    }
 
-        Ok(out)
+    /// Sets the pad which specifies the minimum
+    /// length of the encoded result.
+    ///
+    /// Default to 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if pad is less than 0.
+    pub fn pad(mut self, pad: u32) -> Self {
+        assert!(pad > 0, "pad must large than 1");
+        self.pad = Some(pad);
+        self
     }
 
-    /// Decode into numbers.
+    /// Sets the character used to fill up to the minimum length.
     ///
-    /// # Example
+    /// The character becomes the zero digit and swaps places with the first
+    /// character of the set. Default to the first character of the set.
     ///
     /// ```rust
     /// use alphaid::AlphaId;
     ///
-    /// let alphaid = AlphaId::<u32>::new();
-    /// assert_eq!(alphaid.decode(b"a"), Ok(0));
+    /// let alphaid = AlphaId::<u32>::builder().pad(5).pad_char(b'x').build();
+    /// assert_eq!(alphaid.encode(0), Ok(b"xxxxb".to_vec()));
+    /// assert_eq!(alphaid.encode(23), Ok(b"axxxb".to_vec()));
+    /// assert_eq!(alphaid.decode(b"xxxxb"), Ok(0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character is not in chars when building.
+    pub fn pad_char(mut self, c: u8) -> Self {
+        self.pad_char = Some(c);
+        self
+    }
+
+    /// Sets how the encoded result is filled up to the minimum length.
+    ///
+    /// Default to `PadMode::Shift`.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, PadMode};
+    ///
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .pad(4)
+    ///     .pad_mode(PadMode::Fill)
+    ///     .build();
+    /// assert_eq!(alphaid.encode(1), Ok(b"baaa".to_vec()));
     /// assert_eq!(alphaid.decode(b"b"), Ok(1));
-    /// assert_eq!(alphaid.decode(b"90F7qb"), Ok(1350997667));
-    ///```
-    pub fn decode<V: AsRef<[u8]>>(&self, v: V) -> Result<T, AlphaIdError> {
-        let v = v.as_ref();
-        let mut i: usize = 0;
-        let mut n = T::zero();
-        let mut unpad = self.pad > 1;
-        let mut prev = T::zero();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if blocked words are combined with
+    /// `PadMode::Random`, or with `PadMode::Fill` and a pad longer than 2.
+    pub fn pad_mode(mut self, mode: PadMode) -> Self {
+        self.pad_mode = mode;
+        self
+    }
 
-        while i < v.len() {
-            match self.index.get(&v[i as usize]) {
-                Some(t) => {
-                    let mut x = *t;
-
-                    if unpad {
-                        if x.is_zero() && i + 1 == v.len() {
-                            return Err(AlphaIdError::PadMissed);
-                        }
-
-                        if i + 1 >= self.pad as usize {
-                            if i > 1 && i + 1 > self.pad as usize {
-                                n = n + num::pow(self.base, i - 1) * (self.base - prev - T::one());
-                            }
-
-                            if !x.is_zero() {
-                                unpad = false;
-                                x = x - T::one();
-                            }
-                        }
-                    };
-
-                    prev = *t;
-
-                    if x.is_zero() {
-                        i += 1;
-                        continue;
-                    }
+    /// Inserts `sep` between every `size` symbols of the encoded result,
+    /// e.g. for licence keys like `W5bn-b3Fq-9xQa`.
+    ///
+    /// Separators are stripped from the input before decoding.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u64>::builder().group(4, b'.').build();
+    /// assert_eq!(alphaid.encode(u64::MAX), Ok(b"____.____.__p".to_vec()));
+    /// assert_eq!(alphaid.decode(b"____.____.__p"), Ok(u64::MAX));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if size is 0, or if the separator is in chars when building.
+    pub fn group(mut self, size: usize, sep: u8) -> Self {
+        assert!(size > 0, "group size must large than 0");
+        self.group = Some((size, sep));
+        self
+    }
 
-                    if i > self.max_pow_i as usize {
-                        return Err(AlphaIdError::Overflow);
-                    }
+    /// Sets the bytes prepended to the encoded result, e.g. `usr_` for
+    /// Stripe-style IDs.
+    ///
+    /// Decoding an input without the prefix returns
+    /// `AlphaIdError::PrefixMissed`.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError};
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().prefix(b"usr_").build();
+    /// assert_eq!(alphaid.encode(42), Ok(b"usr_G".to_vec()));
+    /// assert_eq!(alphaid.decode(b"usr_G"), Ok(42));
+    /// assert_eq!(alphaid.decode(b"ord_G"), Err(AlphaIdError::PrefixMissed));
+    /// ```
+    pub fn prefix(mut self, prefix: &[u8]) -> Self {
+        self.prefix = prefix.to_vec();
+        self
+    }
 
-                    let pow = num::pow(self.base, i);
-                    if T::max_value().div(pow) < x {
-                        return Err(AlphaIdError::Overflow);
-                    }
-                    let add = pow * x;
-                    if n + add > T::max_value() {
-                        return Err(AlphaIdError::Overflow);
-                    }
-                    n = n + add;
-                }
-                None => return Err(AlphaIdError::UnexpectedChar),
-            }
-            i += 1;
-        }
+    /// Sets the bytes appended to the encoded result.
+    ///
+    /// Decoding an input without the suffix returns
+    /// `AlphaIdError::SuffixMissed`.
+    pub fn suffix(mut self, suffix: &[u8]) -> Self {
+        self.suffix = suffix.to_vec();
+        self
+    }
 
-        Ok(n)
+    /// Sets the order of the digits in the encoded result.
+    ///
+    /// Default to `DigitOrder::LittleEndian`.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, DigitOrder};
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().order(DigitOrder::BigEndian).build();
+    /// assert_eq!(alphaid.encode(1350997667), Ok(b"bq7F09".to_vec()));
+    /// assert_eq!(alphaid.decode(b"bq7F09"), Ok(1350997667));
+    /// ```
+    pub fn order(mut self, order: DigitOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Rejects inputs which are empty once the affixes and separators are
+    /// stripped with `AlphaIdError::EmptyInput` when decoding.
+    ///
+    /// Default to `false`, in which case they decode like any other input.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError};
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().reject_empty(true).build();
+    /// assert_eq!(alphaid.decode(b""), Err(AlphaIdError::EmptyInput));
+    /// ```
+    pub fn reject_empty(mut self, reject: bool) -> Self {
+        self.reject_empty = reject;
+        self
+    }
+
+    /// Rejects inputs longer than `len` bytes, affixes included, with
+    /// `AlphaIdError::InputTooLong` before looking at them when decoding.
+    ///
+    /// Unlimited by default.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError};
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().max_input_len(8).build();
+    /// assert_eq!(alphaid.decode(b"90F7qb"), Ok(1350997667));
+    /// assert_eq!(alphaid.decode(&[b'?'; 10_000][..]), Err(AlphaIdError::InputTooLong));
+    /// ```
+    pub fn max_input_len(mut self, len: usize) -> Self {
+        self.max_input_len = Some(len);
+        self
+    }
+
+    /// Appends `len` symbols derived from the SipHash-2-4 of the numbers
+    /// under `key`, which are verified when decoding.
+    ///
+    /// The tag stops guessing valid IDs by enumeration, but it is not a
+    /// signature: it is as strong as its `len` symbols, and never stronger
+    /// than 64 bits. The tag takes the least significant digits, so the
+    /// numbers must leave room for `len` digits in `T`.
+    ///
+    /// Decoding an input with a wrong tag returns
+    /// `AlphaIdError::TagMismatch`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if `len` is zero or `len` digits do not fit
+    /// into `T`.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError};
+    ///
+    /// let alphaid = AlphaId::<u64>::builder().tag(*b"0123456789abcdef", 3).build();
+    /// let v = alphaid.encode(42).unwrap();
+    /// assert_eq!(v.len(), 4);
+    /// assert_eq!(alphaid.decode(&v), Ok(42));
+    ///
+    /// let forged = AlphaId::<u64>::new().encode(43 * 64 * 64 * 64).unwrap();
+    /// assert_eq!(alphaid.decode(&forged), Err(AlphaIdError::TagMismatch));
+    /// ```
+    pub fn tag(mut self, key: [u8; 16], len: u32) -> Self {
+        self.tag = Some((key, len));
+        self
+    }
+
+    /// Accepts tags of the retired `keys` when decoding, so the key of
+    /// [`tag`](Builder::tag) can be changed without invalidating the IDs
+    /// issued before. New IDs are always tagged with the current key.
+    ///
+    /// Every key is tried in turn, which makes a wrong tag a little more
+    /// likely to be accepted by chance, unless the key is picked up front
+    /// by [`tag_key_id`](Builder::tag_key_id).
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let old = AlphaId::<u64>::builder().tag(*b"old key 0123456!", 3).build();
+    /// let v = old.encode(42).unwrap();
+    ///
+    /// let new = AlphaId::<u64>::builder()
+    ///     .tag(*b"new key 0123456!", 3)
+    ///     .old_tag_keys(&[*b"old key 0123456!"])
+    ///     .build();
+    /// assert_eq!(new.decode(&v), Ok(42));
+    /// assert_ne!(new.encode(42).unwrap(), v);
+    /// ```
+    pub fn old_tag_keys(mut self, keys: &[[u8; 16]]) -> Self {
+        self.old_tag_keys = keys.to_vec();
+        self
+    }
+
+    /// Embeds a key ID symbol above the tag of [`tag`](Builder::tag), so
+    /// that decoding only tries the keys of
+    /// [`old_tag_keys`](Builder::old_tag_keys) with the same ID. The ID is
+    /// derived from the key itself, so it does not change when keys are
+    /// added or retired.
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if no tag is set, or if the tag and the key ID
+    /// do not fit into `T`.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let old = AlphaId::<u64>::builder()
+    ///     .tag(*b"old key 0123456!", 3)
+    ///     .tag_key_id()
+    ///     .build();
+    /// let v = old.encode(42).unwrap();
+    /// assert_eq!(v.len(), 5);
+    ///
+    /// let new = AlphaId::<u64>::builder()
+    ///     .tag(*b"new key 0123456!", 3)
+    ///     .old_tag_keys(&[*b"old key 0123456!"])
+    ///     .tag_key_id()
+    ///     .build();
+    /// assert_eq!(new.decode(&v), Ok(42));
+    /// assert_ne!(new.encode(42).unwrap()[3], v[3]);
+    /// ```
+    pub fn tag_key_id(mut self) -> Self {
+        self.tag_key_id = true;
+        self
+    }
+
+    /// Adds a reversible transform applied to the numbers before they are
+    /// encoded, and reversed after decoding.
+    ///
+    /// Transforms added by repeated calls are applied in order, before the
+    /// tag of [`tag`](Builder::tag). Each must be a permutation of `T`:
+    /// `backward` must undo
+    /// `forward` for every value. The settings returned by
+    /// [`AlphaId::to_config`] do not include it.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, Obfuscator};
+    ///
+    /// struct Xor(u32);
+    ///
+    /// impl Obfuscator<u32> for Xor {
+    ///     fn forward(&self, n: u32) -> u32 {
+    ///         n ^ self.0
+    ///     }
+    ///
+    ///     fn backward(&self, n: u32) -> u32 {
+    ///         n ^ self.0
+    ///     }
+    /// }
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().obfuscator(Xor(0x5bd1_e995)).build();
+    /// assert_eq!(alphaid.encode(1), AlphaId::<u32>::new().encode(0x5bd1_e994));
+    /// assert_eq!(alphaid.decode(alphaid.encode(1).unwrap()), Ok(1));
+    /// ```
+    pub fn obfuscator<O: Obfuscator<T> + 'static>(mut self, obfuscator: O) -> Self {
+        self.obfuscators.push(Arc::new(obfuscator));
+        self
+    }
+
+    /// Sets the symbol which marks negative numbers in
+    /// [`AlphaId::encode_signed`], placed right after the prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if the sign is in chars, or is the group
+    /// separator.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().chars_str(alphaid::ALNUM62).sign(b'-').build();
+    /// assert_eq!(alphaid.encode_signed(-42), Ok(b"-G".to_vec()));
+    /// assert_eq!(alphaid.encode_signed(42), Ok(b"G".to_vec()));
+    /// assert_eq!(alphaid.decode_signed::<i32>(b"-G"), Ok(-42));
+    /// ```
+    pub fn sign(mut self, sign: u8) -> Self {
+        self.sign = Some(sign);
+        self
+    }
+
+    /// Sets the symbol which [`AlphaId::encode_opt`] encodes `None` as,
+    /// between the affixes.
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if the symbol is in chars, or is the group
+    /// separator or the sign.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().none_symbol(b'~').build();
+    /// assert_eq!(alphaid.encode_opt(None), Ok(b"~".to_vec()));
+    /// assert_eq!(alphaid.decode_opt(b"~"), Ok(None));
+    /// assert_eq!(alphaid.decode_opt(b"b"), Ok(Some(1)));
+    /// ```
+    pub fn none_symbol(mut self, symbol: u8) -> Self {
+        self.none_symbol = Some(symbol);
+        self
+    }
+
+    /// Maps the digits of every number by seeded permutations depending on
+    /// their position, so that consecutive numbers differ in all but their
+    /// leading digit. The lengths of the encoded results are kept.
+    ///
+    /// Unlike [`shuffle_seed`](Builder::shuffle_seed), which only changes
+    /// which symbol stands for a digit, neighbouring numbers no longer
+    /// share long runs of symbols. Decoding reverses the permutation.
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if combined with
+    /// [`sortable`](Builder::sortable) mode.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().permute_digits(7).build();
+    /// let a = alphaid.encode(1350997667).unwrap();
+    /// let b = alphaid.encode(1350997668).unwrap();
+    /// assert_eq!(a.len(), 6);
+    /// assert!(a.iter().zip(&b).filter(|(x, y)| x == y).count() <= 1);
+    /// assert_eq!(alphaid.decode(&a), Ok(1350997667));
+    /// ```
+    pub fn permute_digits(mut self, seed: u64) -> Self {
+        self.digit_seed = Some(seed);
+        self
+    }
+
+    /// Bounds the numbers to `max`, e.g. to [`JS_SAFE`] for IDs which end
+    /// up as JavaScript numbers. Encoding a larger number, or decoding an
+    /// input which represents one, returns `AlphaIdError::Overflow`.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError, JS_SAFE};
+    ///
+    /// let alphaid = AlphaId::<u64>::builder().max_value(JS_SAFE).build();
+    /// assert_eq!(alphaid.encode(JS_SAFE), Ok(b"________5".to_vec()));
+    /// assert_eq!(alphaid.encode(JS_SAFE + 1), Err(AlphaIdError::Overflow));
+    /// assert_eq!(alphaid.decode(b"aaaaaaaa6"), Err(AlphaIdError::Overflow));
+    /// ```
+    pub fn max_value(mut self, max: T) -> Self {
+        self.max_value = Some(to_u128(max));
+        self
+    }
+
+    /// Adds `offset` to the numbers before encoding and subtracts it after
+    /// decoding, so that the first sequential IDs are not as short as `b`.
+    /// Encoding a number which would exceed `T::MAX`, or decoding an input
+    /// which represents a number below the offset, returns
+    /// `AlphaIdError::Overflow`.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError};
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().offset(1_000_000).build();
+    /// assert_eq!(alphaid.encode(1), Ok(b"bjQd".to_vec()));
+    /// assert_eq!(alphaid.decode(b"bjQd"), Ok(1));
+    /// assert_eq!(alphaid.decode(b"b"), Err(AlphaIdError::Overflow));
+    /// assert_eq!(alphaid.encode(u32::MAX), Err(AlphaIdError::Overflow));
+    /// ```
+    pub fn offset(mut self, offset: T) -> Self {
+        self.offset = Some(to_u128(offset));
+        self
+    }
+
+    /// Bounds the length of the encoded results to `N` bytes, e.g. the
+    /// size of a `VARCHAR(N)` column. Encoding a result which would be
+    /// longer returns `AlphaIdError::InvalidLength`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if [`max_encoded_len`](AlphaId::max_encoded_len)
+    /// exceeds `N`, so that every number of `T` fits.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u64>::builder().max_len::<12>().build();
+    /// assert_eq!(alphaid.encode(u64::MAX), Ok(b"__________p".to_vec()));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// use alphaid::AlphaId;
+    ///
+    /// AlphaId::<u64>::builder().pad(4).prefix(b"usr_").max_len::<12>().build();
+    /// ```
+    pub fn max_len<const N: usize>(mut self) -> Self {
+        self.max_len = Some(N);
+        self
+    }
+
+    /// Enables the sortable mode, in which every encoded result is exactly
+    /// `width` symbols long, most significant digit first, and padded with
+    /// the zero symbol, so that byte-wise order matches numeric order.
+    ///
+    /// The alphabet is sorted by byte value and the [`pad`](Builder::pad)
+    /// is ignored. Encoding a number which does not fit into `width`
+    /// symbols returns `AlphaIdError::Overflow`, decoding an input of
+    /// another length returns `AlphaIdError::InvalidLength`.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().sortable(4).build();
+    /// assert_eq!(alphaid.encode(0), Ok(b"----".to_vec()));
+    /// assert_eq!(alphaid.encode(64), Ok(b"--0-".to_vec()));
+    /// assert_eq!(alphaid.decode(b"--0-"), Ok(64));
+    /// assert!(alphaid.encode(63).unwrap() < alphaid.encode(64).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if width is 0.
+    pub fn sortable(mut self, width: u32) -> Self {
+        assert!(width > 0, "width must large than 0");
+        self.width = Some(width);
+        self.order = DigitOrder::BigEndian;
+        self
+    }
+
+    /// Sets the substitutions applied to the input before decoding.
+    ///
+    /// Each entry maps a byte sequence that is easily mistaken for a
+    /// character of the alphabet (e.g. `O` for `0`, or `rn` for `m` in an
+    /// alphabet without `r`) to that character. Longer sequences are
+    /// matched first. Off by default.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .chars(b"abcdefghijklmnopqrstuvwxyz23456789".to_vec())
+    ///     .map_confusables(vec![(b"0".to_vec(), b'o'), (b"1".to_vec(), b'l')])
+    ///     .build();
+    /// assert_eq!(alphaid.decode(b"1"), alphaid.decode(b"l"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a mapping is empty, or when building if a sequence is made
+    /// only of bytes which appear in the encoded results, i.e. the alphabet,
+    /// the separator, the affixes, the sign and the none symbol. Such a
+    /// sequence could be part of a valid input, which would then decode to
+    /// another number.
+    pub fn map_confusables(mut self, map: Vec<(Vec<u8>, u8)>) -> Self {
+        assert!(
+            map.iter().all(|(from, _)| !from.is_empty()),
+            "confusable sequence must not be empty"
+        );
+        self.confusables = map;
+        self
+    }
+
+    /// Adds words which must not appear in the encoded result.
+    ///
+    /// Words are matched as case-insensitive substrings. An encoding which
+    /// contains a blocked word is deterministically replaced by the first
+    /// clean alternative, which is two symbols longer and still decodes to
+    /// the same number.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.encode(77826), Ok(b"cat".to_vec()));
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().block_words(&["cat"]).build();
+    /// assert_eq!(alphaid.encode(77826), Ok(b"dbuba".to_vec()));
+    /// assert_eq!(alphaid.decode(b"dbuba"), Ok(77826));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if a word is empty.
+    pub fn block_words<S: AsRef<str>>(mut self, words: &[S]) -> Self {
+        self.blocklist.extend(words);
+        self
+    }
+
+    /// Adds words which the encoded result must never be equal to, such as
+    /// route keywords like `new` or `edit`.
+    ///
+    /// Words are compared ignoring case. An encoding equal to a
+    /// reserved word is replaced the same way as one containing a blocked
+    /// word, see [`block_words`](Builder::block_words).
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().reserved(&["new"]).build();
+    /// assert!(alphaid.is_reserved(b"new"));
+    /// assert_eq!(alphaid.encode(90381), Ok(b"ofxba".to_vec()));
+    /// assert_eq!(alphaid.decode(b"ofxba"), Ok(90381));
+    /// ```
+    pub fn reserved<S: AsRef<str>>(mut self, words: &[S]) -> Self {
+        self.blocklist.reserve(words);
+        self
+    }
+
+    /// Ensures every encoded result contains a symbol which is not an ASCII
+    /// digit, so that spreadsheets and JSON parsers do not mistake it for a
+    /// number.
+    ///
+    /// A result of only digits is replaced the same way as one containing
+    /// a blocked word, see [`block_words`](Builder::block_words).
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if every symbol only consists of ASCII digits.
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.encode(26), Ok(b"0".to_vec()));
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().never_numeric().build();
+    /// assert_eq!(alphaid.encode(26), Ok(b"1ba".to_vec()));
+    /// assert_eq!(alphaid.decode(b"1ba"), Ok(26));
+    /// ```
+    pub fn never_numeric(mut self) -> Self {
+        self.blocklist.block_numeric();
+        self
+    }
+
+    /// Ensures every encoded result starts with a symbol of `class`, e.g.
+    /// not with a `-`, which would be mistaken for a command line option.
+    /// The affixes are not checked.
+    ///
+    /// A result starting with another symbol is replaced the same way as
+    /// one containing a blocked word, see
+    /// [`block_words`](Builder::block_words).
+    ///
+    /// # Panics
+    ///
+    /// Panics when building if no symbol starts with a character of the
+    /// class, or in [`DigitOrder::BigEndian`] order if the zero symbol,
+    /// which replaced results start with, does not. The zero symbol is the
+    /// one after [`pad_char`](Builder::pad_char) and
+    /// [`shuffle_seed`](Builder::shuffle_seed) are applied.
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, CharClass};
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.encode(62), Ok(b"-".to_vec()));
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().first_char(CharClass::Letter).build();
+    /// assert_eq!(alphaid.encode(62), Ok(b"aca".to_vec()));
+    /// assert_eq!(alphaid.decode(b"aca"), Ok(62));
+    /// ```
+    pub fn first_char(mut self, class: CharClass) -> Self {
+        self.blocklist.require_first(class);
+        self
+    }
+
+    /// Adds the built-in list of English profanity to the blocked words.
+    #[cfg(feature = "blocklist")]
+    pub fn default_blocklist(self) -> Self {
+        self.block_words(ENGLISH_BLOCKLIST)
+    }
+
+    /// Consumes the builder, returning a `AlphaId`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are duplicate characters in chars.
+    pub fn build(self) -> AlphaId<T> {
+        self.build_marked()
+    }
+
+    /// Consumes the builder, returning a `AlphaId` for the IDs marked by `M`.
+    ///
+    /// See [`Id`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are duplicate characters in chars.
+    pub fn build_marked<M>(self) -> AlphaId<T, M> {
+        match self.try_build_marked() {
+            Ok(alphaid) => alphaid,
+            Err(msg) => panic!("{}", msg),
+        }
+    }
+
+    /// Builds the `AlphaId`, returning the message [`build`](Builder::build)
+    /// panics with if the settings are invalid.
+    ///
+    /// Every check runs on the alphabet after sorting, shuffling and
+    /// swapping in the padding character, so the settings accepted by
+    /// [`parse`](Builder::parse) always build.
+    pub(crate) fn try_build_marked<M>(self) -> Result<AlphaId<T, M>, &'static str> {
+        fn check(ok: bool, msg: &'static str) -> Result<(), &'static str> {
+            match ok {
+                true => Ok(()),
+                false => Err(msg),
+            }
+        }
+
+        // `pad` asserts this, but `from_config` takes any number.
+        check(self.pad != Some(0), "pad must large than 1")?;
+        check(
+            !self.blocklist.has_empty_word(),
+            "blocked word must not be empty",
+        )?;
+        check(
+            cfg!(feature = "rand") || !self.pad_mode.is_random(),
+            "PadMode::Random requires the rand feature",
+        )?;
+        check(
+            self.group.is_none_or(|(size, _)| size > 0),
+            "group size must large than 0",
+        )?;
+        let mut symbols = self.symbols.unwrap_or_default();
+        if self.width.is_some() {
+            symbols.sort();
+        }
+        let mut chars = match self.chars {
+            Some(chars) => chars,
+            None if symbols.is_empty() => Cow::Borrowed(DEFAULT_SEED.as_bytes()),
+            None => Cow::Borrowed(&[][..]),
+        };
+        if self.width.is_some() {
+            check(
+                self.blocklist.is_empty(),
+                "sortable mode can not be combined with blocked words",
+            )?;
+            chars.to_mut().sort_unstable();
+        }
+        if let Some(seed) = self.shuffle_seed {
+            check(
+                self.width.is_none(),
+                "shuffle_seed can not be combined with sortable mode",
+            )?;
+            let mut rng = SplitMix64::new(seed);
+            for i in (1..chars.len()).rev() {
+                chars.to_mut().swap(i, rng.below(i + 1));
+            }
+            for i in (1..symbols.len()).rev() {
+                symbols.swap(i, rng.below(i + 1));
+            }
+        }
+        if let Some(pad_char) = self.pad_char {
+            check(
+                self.width.is_none(),
+                "pad_char can not be combined with sortable mode",
+            )?;
+            let symbol = (pad_char as char).to_string();
+            match chars.iter().position(|&c| c == pad_char) {
+                Some(0) => {}
+                Some(i) => chars.to_mut().swap(0, i),
+                None => {
+                    let i = symbols
+                        .iter()
+                        .position(|s| *s == symbol)
+                        .ok_or("pad_char must be in chars")?;
+                    symbols.swap(0, i);
+                }
+            }
+        }
+
+        if symbols.is_empty() {
+            macros::check_chars(&chars, self.allow_raw_bytes, self.allow_small_alphabet)?;
+        } else {
+            check(
+                symbols.iter().all(|s| !s.is_empty()),
+                "symbols must not be empty",
+            )?;
+            check(
+                self.allow_raw_bytes
+                    || symbols
+                        .iter()
+                        .all(|s| !s.chars().any(|c| c.is_control() || c.is_whitespace())),
+                "chars must be printable characters",
+            )?;
+            macros::check_size(symbols.len(), self.allow_small_alphabet)?;
+        }
+        // The `String` APIs map the bytes of a `chars` alphabet to the code
+        // points of the same value, which differ from UTF-8 above ASCII.
+        let raw = symbols.is_empty() && self.allow_raw_bytes;
+        check(
+            raw || self.group.is_none_or(|(_, sep)| sep.is_ascii()),
+            "separator must be ASCII unless raw bytes are allowed",
+        )?;
+        let text = |affix: &[u8]| match symbols.is_empty() {
+            true => affix.is_ascii(),
+            false => core::str::from_utf8(affix).is_ok(),
+        };
+        check(
+            raw || text(&self.prefix) && text(&self.suffix),
+            "affixes must be ASCII, or UTF-8 with chars_str, unless raw bytes are allowed",
+        )?;
+
+        if self.url_select_safe {
+            check(
+                chars.iter().all(u8::is_ascii_alphanumeric)
+                    && symbols.iter().all(|s| s.chars().all(char::is_alphanumeric))
+                    && self
+                        .group
+                        .is_none_or(|(_, sep)| sep.is_ascii_alphanumeric()),
+                "chars must be letters or digits to be selectable",
+            )?;
+        }
+
+        let mut size = chars.len().max(symbols.len());
+        check(
+            T::from_usize(size.saturating_sub(1)).is_some(),
+            "chars size must fit into the number type",
+        )?;
+
+        let symbol_index: BTreeMap<String, T> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.clone(), T::from_usize(i).unwrap()))
+            .collect();
+
+        check(
+            symbols.len() == symbol_index.len(),
+            "duplicate characters are not allowed",
+        )?;
+
+        let index: BTreeMap<u8, T> = chars
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (*v, T::from_usize(i).unwrap()))
+            .collect();
+        if let Some((_, sep)) = self.group {
+            check(
+                !index.contains_key(&sep) && !symbol_index.contains_key(&(sep as char).to_string()),
+                "separator must not be in chars",
+            )?;
+        }
+        if let Some(sign) = self.sign {
+            check(
+                !index.contains_key(&sign)
+                    && !symbol_index.contains_key(&(sign as char).to_string())
+                    && self.group.is_none_or(|(_, sep)| sep != sign),
+                "sign must not be in chars or the separator",
+            )?;
+        }
+        if let Some(none) = self.none_symbol {
+            check(
+                !index.contains_key(&none)
+                    && !symbol_index.contains_key(&(none as char).to_string())
+                    && self.group.is_none_or(|(_, sep)| sep != none)
+                    && self.sign != Some(none),
+                "none symbol must not be in chars, the separator or the sign",
+            )?;
+        }
+
+        // A sequence made only of bytes which are also output could be part
+        // of a valid input, which would then decode to another number.
+        let (group, prefix, suffix) = (self.group, &self.prefix, &self.suffix);
+        let (sign, none_symbol) = (self.sign, self.none_symbol);
+        let output = |b: u8| {
+            index.contains_key(&b)
+                || symbols.iter().any(|s| s.as_bytes().contains(&b))
+                || group.is_some_and(|(_, sep)| sep == b)
+                || prefix.contains(&b)
+                || suffix.contains(&b)
+                || sign == Some(b)
+                || none_symbol == Some(b)
+        };
+        let mut confusables = self.confusables;
+        for (from, to) in &confusables {
+            check(index.contains_key(to), "confusable target must be in chars")?;
+            check(
+                from.iter().any(|&b| !output(b)),
+                "confusable must contain a byte which is not in the encoded results",
+            )?;
+        }
+        confusables.sort_by_key(|(from, _)| core::cmp::Reverse(from.len()));
+
+        let max_symbol_len = symbols.iter().map(String::len).max().unwrap_or(0);
+        let ascii = symbols.is_empty()
+            && chars.is_ascii()
+            && self.prefix.is_ascii()
+            && self.suffix.is_ascii()
+            && self.group.is_none_or(|(_, sep)| sep.is_ascii());
+
+        if self.blocklist.blocks_numeric() {
+            check(
+                !chars.iter().all(u8::is_ascii_digit)
+                    || symbols
+                        .iter()
+                        .any(|s| !s.bytes().all(|b| b.is_ascii_digit())),
+                "never_numeric needs a symbol which is not an ASCII digit",
+            )?;
+        }
+        if let Some(class) = self.blocklist.first() {
+            let first = |d: usize| match symbols.get(d) {
+                Some(symbol) => symbol.as_bytes()[0],
+                None => chars[d],
+            };
+            check(
+                (0..size).any(|d| class.contains(first(d))),
+                "first_char needs a symbol of its class",
+            )?;
+            check(
+                self.order == DigitOrder::LittleEndian || class.contains(first(0)),
+                "first_char needs the zero symbol in its class in big endian order",
+            )?;
+        }
+        check(
+            size > 2 || !self.pad_mode.is_random(),
+            "random padding needs at least 3 chars",
+        )?;
+        if self.pad_mode.is_random() {
+            check(
+                self.blocklist.is_empty(),
+                "random padding can not be combined with blocked words",
+            )?;
+            size -= 1;
+        }
+        check(
+            self.blocklist.is_empty()
+                || self.pad_mode != PadMode::Fill
+                || self.pad.unwrap_or(1) <= 2,
+            "fill padding longer than 2 can not be combined with blocked words",
+        )?;
+        let base = T::from_usize(size).ok_or("chars size must fit into the number type")?;
+        check(
+            self.offset
+                .is_none_or(|offset| from_u128::<T>(offset).is_some()),
+            "offset must fit into the number type",
+        )?;
+        let ranges = if symbols.is_empty() {
+            Ranges::new(&chars)
+        } else {
+            None
+        };
+        let mut powers = alloc::vec![T::one()];
+        while let Some(pow) = powers.last().and_then(|pow| pow.checked_mul(&base)) {
+            powers.push(pow);
+        }
+
+        if let Some((_, len)) = self.tag {
+            check(len > 0, "tag length must not be zero")?;
+            check(
+                (len as usize + self.tag_key_id as usize) < powers.len(),
+                "tag must fit into the number type",
+            )?;
+        }
+        check(
+            self.tag.is_some() || !self.tag_key_id,
+            "tag_key_id needs a tag",
+        )?;
+
+        let bits = if size.is_power_of_two() {
+            Some(size.trailing_zeros() as usize)
+        } else {
+            None
+        };
+
+        let mut table = [-1; 256];
+        for (i, &b) in chars.iter().enumerate() {
+            table[b as usize] = i as i16;
+        }
+        let digit_permutation = match self.digit_seed {
+            Some(seed) => {
+                check(
+                    self.width.is_none(),
+                    "permute_digits can not be combined with sortable mode",
+                )?;
+                Some(Arc::new(DigitPermutation::new(seed, size, powers.len())))
+            }
+            None => None,
+        };
+        let alphaid = AlphaId {
+            chars,
+            index: Arc::new(table),
+            ranges: ranges.map(Arc::new),
+            base,
+            pad: if self.width.is_some() {
+                1
+            } else {
+                self.pad.unwrap_or(1)
+            },
+            pad_mode: self.pad_mode,
+            width: self.width,
+            group: self.group,
+            prefix: self.prefix.into(),
+            suffix: self.suffix.into(),
+            powers: powers.into(),
+            bits,
+            confusables: confusables.into(),
+            symbols: symbols.into(),
+            symbol_index: Arc::new(symbol_index),
+            max_symbol_len,
+            ascii,
+            blocklist: Arc::new(self.blocklist),
+            order: self.order,
+            reject_empty: self.reject_empty,
+            max_input_len: self.max_input_len,
+            tag: self.tag,
+            sign: self.sign,
+            none_symbol: self.none_symbol,
+            max_len: self.max_len,
+            digit_seed: self.digit_seed,
+            max_value: self.max_value,
+            offset: self.offset,
+            tag_key_id: self.tag_key_id,
+            old_tag_keys: self.old_tag_keys.into(),
+            obfuscators: self.obfuscators.into(),
+            digit_permutation,
+            _marker: PhantomData,
+        };
+        if let Some(max) = alphaid.max_len {
+            check(
+                alphaid.max_encoded_len() <= max,
+                "encoded results can be longer than max_len",
+            )?;
+        }
+        Ok(alphaid)
+    }
+}
+
+/// Used for encoding and decoding.
+///
+/// The optional marker type `M` tags the [`Id`]s this instance encodes and
+/// decodes, see [`encode_id`](AlphaId::encode_id).
+pub struct AlphaId<T: UnsignedInteger = u128, M = ()> {
+    chars: Cow<'static, [u8]>,
+    /// The digit value of every byte, `-1` if it is not in the alphabet.
+    index: Arc<[i16; 256]>,
+    ranges: Option<Arc<Ranges>>,
+    base: T,
+    pad: u32,
+    pad_mode: PadMode,
+    width: Option<u32>,
+    group: Option<(usize, u8)>,
+    prefix: Arc<[u8]>,
+    suffix: Arc<[u8]>,
+    /// The powers of the base which fit into `T`.
+    powers: Arc<[T]>,
+    /// The bits per digit if the base is a power of two.
+    bits: Option<usize>,
+    confusables: Arc<[(Vec<u8>, u8)]>,
+    symbols: Arc<[String]>,
+    symbol_index: Arc<BTreeMap<String, T>>,
+    max_symbol_len: usize,
+    ascii: bool,
+    blocklist: Arc<Blocklist>,
+    order: DigitOrder,
+    reject_empty: bool,
+    max_input_len: Option<usize>,
+    tag: Option<([u8; 16], u32)>,
+    sign: Option<u8>,
+    none_symbol: Option<u8>,
+    max_len: Option<usize>,
+    digit_seed: Option<u64>,
+    max_value: Option<u128>,
+    offset: Option<u128>,
+    tag_key_id: bool,
+    /// The keys of [`Builder::old_tag_keys`].
+    old_tag_keys: Arc<[[u8; 16]]>,
+    obfuscators: Arc<[Arc<dyn Obfuscator<T>>]>,
+    digit_permutation: Option<Arc<DigitPermutation>>,
+    _marker: PhantomData<fn() -> M>,
+}
+
+/// Cloning is cheap, the tables are shared between the clones and the
+/// characters set is at most 256 bytes.
+impl<T: UnsignedInteger, M> Clone for AlphaId<T, M> {
+    fn clone(&self) -> Self {
+        AlphaId {
+            chars: self.chars.clone(),
+            index: Arc::clone(&self.index),
+            ranges: self.ranges.clone(),
+            base: self.base,
+            pad: self.pad,
+            pad_mode: self.pad_mode,
+            width: self.width,
+            group: self.group,
+            prefix: Arc::clone(&self.prefix),
+            suffix: Arc::clone(&self.suffix),
+            powers: Arc::clone(&self.powers),
+            bits: self.bits,
+            confusables: Arc::clone(&self.confusables),
+            symbols: Arc::clone(&self.symbols),
+            symbol_index: Arc::clone(&self.symbol_index),
+            max_symbol_len: self.max_symbol_len,
+            ascii: self.ascii,
+            blocklist: Arc::clone(&self.blocklist),
+            order: self.order,
+            reject_empty: self.reject_empty,
+            max_input_len: self.max_input_len,
+            tag: self.tag,
+            sign: self.sign,
+            none_symbol: self.none_symbol,
+            max_len: self.max_len,
+            digit_seed: self.digit_seed,
+            max_value: self.max_value,
+            offset: self.offset,
+            tag_key_id: self.tag_key_id,
+            old_tag_keys: Arc::clone(&self.old_tag_keys),
+            obfuscators: Arc::clone(&self.obfuscators),
+            digit_permutation: self.digit_permutation.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: UnsignedInteger, M> fmt::Debug for AlphaId<T, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("AlphaId");
+        if self.symbols.is_empty() {
+            d.field("chars", &String::from_utf8_lossy(&self.chars));
+        } else {
+            d.field("symbols", &self.symbols);
+        }
+        d.field("base", &self.base())
+            .field("pad", &self.pad)
+            .field("pad_mode", &self.pad_mode)
+            .field("order", &self.order)
+            .field("width", &self.width)
+            .field("group", &self.group)
+            .field("prefix", &String::from_utf8_lossy(&self.prefix))
+            .field("suffix", &String::from_utf8_lossy(&self.suffix))
+            .finish_non_exhaustive()
+    }
+}
+
+/// Two `AlphaId`s are equal if they have the same settings and share the
+/// same obfuscators, if any.
+impl<T: UnsignedInteger, M> PartialEq for AlphaId<T, M> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_obfuscators = self.obfuscators.len() == other.obfuscators.len()
+            && self
+                .obfuscators
+                .iter()
+                .zip(other.obfuscators.iter())
+                .all(|(a, b)| Arc::ptr_eq(a, b));
+        same_obfuscators && self.to_config() == other.to_config()
+    }
+}
+
+impl<T: UnsignedInteger, M> Default for AlphaId<T, M> {
+    fn default() -> Self {
+        Builder::new().build_marked()
+    }
+}
+
+impl<T: UnsignedInteger> AlphaId<T> {
+    /// Returns a builder type to configure a new `AlphaId`.
+    pub fn builder() -> Builder<T> {
+        Builder::new()
+    }
+
+    /// Creates a new `AlphaId` with a default configuration.
+    pub fn new() -> Self {
+        Builder::new().build()
+    }
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode a typed ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, Id};
+    ///
+    /// struct User;
+    ///
+    /// let users = AlphaId::<u64, User>::default();
+    /// let id: Id<u64, User> = users.decode_id(b"90F7qb").unwrap();
+    /// assert_eq!(id.get(), 1350997667);
+    /// assert_eq!(users.encode_id(id), Ok(b"90F7qb".to_vec()));
+    /// ```
+    pub fn encode_id(&self, id: Id<T, M>) -> Result<Vec<u8>, AlphaIdError> {
+        self.encode(id.get())
+    }
+
+    /// Decode into a typed ID.
+    pub fn decode_id<V: AsRef<[u8]>>(&self, v: V) -> Result<Id<T, M>, AlphaIdError> {
+        self.decode(v).map(Id::new)
+    }
+
+    /// Encode the numbers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.encode(0), Ok(b"a".to_vec()));
+    /// assert_eq!(alphaid.encode(1), Ok(b"b".to_vec()));
+    /// assert_eq!(alphaid.encode(1350997667), Ok(b"90F7qb".to_vec()));
+    /// ```
+    pub fn encode(&self, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        let mut out = Vec::new();
+        self.encode_into(n, &mut out)?;
+        Ok(out)
+    }
+
+    /// Encode the numbers, appending the result to `out`.
+    ///
+    /// `out` is left unchanged if an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let mut out = b"id=".to_vec();
+    /// alphaid.encode_into(1350997667, &mut out).unwrap();
+    /// assert_eq!(out, b"id=90F7qb");
+    /// ```
+    pub fn encode_into(&self, n: T, out: &mut Vec<u8>) -> Result<(), AlphaIdError> {
+        #[cfg(feature = "metrics")]
+        let start = out.len();
+        let result = self.write_bytes(n, out);
+        #[cfg(feature = "metrics")]
+        metrics::encoded(result.as_ref().map(|_| out.len() - start));
+        result
+    }
+
+    fn write_bytes(&self, n: T, out: &mut Vec<u8>) -> Result<(), AlphaIdError> {
+        if !self.symbols.is_empty() {
+            let mut encoded = String::new();
+            self.write_chars(n, &mut encoded)?;
+            self.check_len(encoded.len())?;
+            out.extend_from_slice(encoded.as_bytes());
+            return Ok(());
+        }
+
+        let start = out.len();
+        out.extend_from_slice(&self.prefix);
+        let mut count = 0;
+        let result = self.encode_digits(n, |d| {
+            if let Some(sep) = self.separator_before(count) {
+                out.push(sep);
+            }
+            count += 1;
+            out.push(self.chars[d]);
+        });
+        if let Err(e) = result {
+            out.truncate(start);
+            return Err(e);
+        }
+        out.extend_from_slice(&self.suffix);
+        if let Err(e) = self.check_len(out.len() - start) {
+            out.truncate(start);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Encode the numbers into `buf`, returning the number of bytes written.
+    ///
+    /// Returns `AlphaIdError::BufferTooSmall` if the result does not fit,
+    /// a buffer of [`max_encoded_len`](AlphaId::max_encoded_len) bytes is
+    /// always large enough. With the default alphabet and no padding or
+    /// affixes the longest results are:
+    ///
+    /// | type   | bytes |
+    /// |--------|-------|
+    /// | `u16`  | 3     |
+    /// | `u32`  | 6     |
+    /// | `u64`  | 11    |
+    /// | `u128` | 22    |
+    ///
+    /// Nothing is allocated unless a blocklist or symbol alphabet is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError};
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let mut buf = [0; 6];
+    /// assert_eq!(alphaid.encode_to_slice(1350997667, &mut buf), Ok(6));
+    /// assert_eq!(&buf, b"90F7qb");
+    /// assert_eq!(
+    ///     alphaid.encode_to_slice(1350997667, &mut buf[..5]),
+    ///     Err(AlphaIdError::BufferTooSmall)
+    /// );
+    /// ```
+    pub fn encode_to_slice(&self, n: T, buf: &mut [u8]) -> Result<usize, AlphaIdError> {
+        if !self.symbols.is_empty() || !self.blocklist.is_empty() {
+            let v = self.encode(n)?;
+            let dst = buf.get_mut(..v.len()).ok_or(AlphaIdError::BufferTooSmall)?;
+            dst.copy_from_slice(&v);
+            return Ok(v.len());
+        }
+
+        let result = self.write_slice(n, buf);
+        #[cfg(feature = "metrics")]
+        metrics::encoded(result.as_ref().copied());
+        result
+    }
+
+    fn write_slice(&self, n: T, buf: &mut [u8]) -> Result<usize, AlphaIdError> {
+        let little_endian = self.order == DigitOrder::LittleEndian;
+        let mut len = 0;
+        let mut put = |b: u8| {
+            if let Some(slot) = buf.get_mut(len) {
+                *slot = b;
+            }
+            len += 1;
+        };
+        self.prefix.iter().for_each(|&b| put(b));
+        let start = self.prefix.len();
+        let mut count = 0;
+        self.emit_digits(n, |d| {
+            if little_endian {
+                if let Some(sep) = self.separator_before(count) {
+                    put(sep);
+                }
+            }
+            count += 1;
+            put(self.chars[d]);
+        })?;
+        if len > buf.len() {
+            return Err(AlphaIdError::BufferTooSmall);
+        }
+
+        if !little_endian {
+            // The digits were written least significant first.
+            buf[start..len].reverse();
+            if let Some((size, sep)) = self.group {
+                let seps = (count - 1) / size;
+                if len + seps > buf.len() {
+                    return Err(AlphaIdError::BufferTooSmall);
+                }
+                // Spread the digits from the back so none is overwritten
+                // before it is moved.
+                for p in (0..count).rev() {
+                    let to = start + p + p / size;
+                    buf[to] = buf[start + p];
+                    if self.separator_before(p).is_some() {
+                        buf[to - 1] = sep;
+                    }
+                }
+                len += seps;
+            }
+        }
+
+        let end = len + self.suffix.len();
+        let dst = buf.get_mut(len..end).ok_or(AlphaIdError::BufferTooSmall)?;
+        dst.copy_from_slice(&self.suffix);
+        Ok(end)
+    }
+
+    /// Returns the length in bytes of the encoded result of `n`.
+    ///
+    /// The affixes and the group separators are counted, so the result
+    /// equals the length of [`encode`](AlphaId::encode). With a blocklist
+    /// or a symbol alphabet the result is encoded to be measured. Returns
+    /// [`max_encoded_len`](AlphaId::max_encoded_len) if `n` can not be
+    /// encoded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().prefix(b"id_").build();
+    /// assert_eq!(alphaid.encoded_len(1), 4);
+    /// assert_eq!(alphaid.encoded_len(1350997667), 9);
+    /// ```
+    pub fn encoded_len(&self, n: T) -> usize {
+        if !self.symbols.is_empty() || !self.blocklist.is_empty() {
+            return self
+                .encode_iter(n)
+                .map_or_else(|_| self.max_encoded_len(), Iterator::count);
+        }
+
+        let digits = match self.digits(n) {
+            Ok(digits) => digits.count(),
+            Err(_) => return self.max_encoded_len(),
+        };
+        let seps = self.group.map_or(0, |(size, _)| (digits - 1) / size);
+        self.prefix.len() + digits + seps + self.suffix.len()
+    }
+
+    /// Returns the number of symbols the values up to `n` are encoded to,
+    /// not counting affixes, separators and blocklist replacements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u64>::new();
+    /// assert_eq!(alphaid.len_for(63), 1);
+    /// assert_eq!(alphaid.len_for(1_000_000_000), 5);
+    /// ```
+    pub fn len_for(&self, n: T) -> usize {
+        match self.digits(n) {
+            Ok(digits) => digits.count(),
+            Err(_) => self.width.unwrap_or(0) as usize,
+        }
+    }
+
+    /// Returns the largest value which is encoded to at most `len` symbols,
+    /// not counting affixes, separators and blocklist replacements.
+    ///
+    /// Returns `None` if no value is that short, or if an
+    /// [`obfuscator`](Builder::obfuscator) is set, whose results are not in
+    /// the order of the values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u64>::new();
+    /// assert_eq!(alphaid.max_value_for_len(0), None);
+    /// assert_eq!(alphaid.max_value_for_len(1), Some(63));
+    /// assert_eq!(alphaid.max_value_for_len(6), Some(64u64.pow(6) - 1));
+    /// ```
+    pub fn max_value_for_len(&self, len: usize) -> Option<T> {
+        if !self.obfuscators.is_empty() {
+            return None;
+        }
+        let fits = |n| self.digits(n).is_ok_and(|digits| digits.count() <= len);
+        if !fits(T::zero()) {
+            return None;
+        }
+
+        // The length never decreases with the value.
+        let two = T::one() + T::one();
+        let (mut lo, mut hi) = (T::zero(), T::max_value());
+        while lo < hi {
+            let mid = hi - (hi - lo) / two;
+            if fits(mid) {
+                lo = mid;
+            } else {
+                hi = mid - T::one();
+            }
+        }
+        Some(lo)
+    }
+
+    /// Returns an upper bound of the length in bytes of an encoded result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// assert_eq!(AlphaId::<u64>::new().max_encoded_len(), 11);
+    /// assert_eq!(AlphaId::<u128>::new().max_encoded_len(), 22);
+    /// ```
+    pub fn max_encoded_len(&self) -> usize {
+        let mut digits = 1;
+        let mut n = T::max_value();
+        while n >= self.base {
+            n = n / self.base;
+            digits += 1;
+        }
+        let digits = match (self.width, self.pad_mode) {
+            (Some(width), _) => width as usize,
+            (None, PadMode::Shift) if self.pad > 1 => (digits + 1).max(self.pad as usize),
+            (None, _) => digits.max(self.pad as usize),
+        };
+        // A replaced representation appends two digits.
+        let digits = if self.blocklist.is_empty() {
+            digits
+        } else {
+            digits + 2
+        };
+        let seps = self.group.map_or(0, |(size, _)| (digits - 1) / size);
+
+        let (symbol_len, prefix, suffix) = if self.symbols.is_empty() {
+            (1, self.prefix.len(), self.suffix.len())
+        } else {
+            (
+                self.max_symbol_len,
+                String::from_utf8_lossy(&self.prefix).len(),
+                String::from_utf8_lossy(&self.suffix).len(),
+            )
+        };
+        prefix + digits * symbol_len + seps + suffix
+    }
+
+    /// Encode the numbers into an iterator over the bytes of the result.
+    ///
+    /// The digits are computed while iterating unless a blocklist or
+    /// big-endian digits are used. The bytes are the same as the ones
+    /// returned by [`encode`](AlphaId::encode).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let v: Vec<u8> = alphaid.encode_iter(1350997667).unwrap().collect();
+    /// assert_eq!(v, b"90F7qb");
+    /// ```
+    pub fn encode_iter(&self, n: T) -> Result<impl Iterator<Item = u8> + '_, AlphaIdError> {
+        let (lazy, buffered) =
+            if self.blocklist.is_empty() && self.order == DigitOrder::LittleEndian {
+                (Some(self.digits(n)?), None)
+            } else {
+                let mut digits = Vec::new();
+                self.encode_digits(n, |d| digits.push(d))?;
+                (None, Some(digits))
+            };
+        let digits = lazy
+            .into_iter()
+            .flatten()
+            .chain(buffered.into_iter().flatten());
+
+        let symbols = digits.enumerate().flat_map(move |(i, d)| {
+            let symbol = match self.symbols.get(d) {
+                Some(symbol) => symbol.as_bytes(),
+                None => core::slice::from_ref(&self.chars[d]),
+            };
+            self.separator_before(i)
+                .into_iter()
+                .chain(symbol.iter().copied())
+        });
+        Ok(self
+            .prefix
+            .iter()
+            .copied()
+            .chain(symbols)
+            .chain(self.suffix.iter().copied()))
+    }
+
+    /// Encode a batch of numbers.
+    ///
+    /// Stops at the first number which can not be encoded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(
+    ///     alphaid.encode_batch(&[0, 1, 1350997667]),
+    ///     Ok(vec![b"a".to_vec(), b"b".to_vec(), b"90F7qb".to_vec()])
+    /// );
+    /// ```
+    pub fn encode_batch(&self, ns: &[T]) -> Result<Vec<Vec<u8>>, AlphaIdError> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            ::tracing::debug_span!(target: "alphaid", "encode_batch", len = ns.len()).entered();
+        let capacity = self.max_encoded_len();
+        let mut scratch = Vec::with_capacity(capacity);
+        ns.iter()
+            .map(|&n| {
+                scratch.clear();
+                self.encode_into(n, &mut scratch)?;
+                Ok(scratch.as_slice().to_vec())
+            })
+            .collect()
+    }
+
+    /// Decode a batch of encoded results.
+    ///
+    /// Stops at the first result which can not be decoded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.decode_batch(&["a", "b", "90F7qb"]), Ok(vec![0, 1, 1350997667]));
+    /// ```
+    pub fn decode_batch<V: AsRef<[u8]>>(&self, vs: &[V]) -> Result<Vec<T>, AlphaIdError> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            ::tracing::debug_span!(target: "alphaid", "decode_batch", len = vs.len()).entered();
+        vs.iter().map(|v| self.decode(v)).collect()
+    }
+
+    /// Encode the numbers into a UTF-8 `String`, the same as
+    /// [`encode_chars`](AlphaId::encode_chars), so that
+    /// [`decode_str`](AlphaId::decode_str) decodes it.
+    ///
+    /// The output of [`encode`](AlphaId::encode) is reused if every byte
+    /// which can be emitted is ASCII. The bytes of a raw byte alphabet are
+    /// mapped to the `char` with the same code point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.encode_string(1350997667), Ok("90F7qb".to_string()));
+    /// ```
+    pub fn encode_string(&self, n: T) -> Result<String, AlphaIdError> {
+        if self.ascii {
+            let v = self.encode(n)?;
+            // SAFETY: the alphabet, affixes and separator are all ASCII.
+            return Ok(unsafe { String::from_utf8_unchecked(v) });
+        }
+
+        let encoded = self.encode_chars(n)?;
+        self.check_len(encoded.len())?;
+        Ok(encoded)
+    }
+
+    /// Encode the numbers into a `String`.
+    ///
+    /// Bytes of an alphabet set by [`Builder::chars`] are mapped to the
+    /// `char` with the same code point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .chars_str("абвгдежзийклмнопрстуфхцчшщъыьэюя")
+    ///     .build();
+    /// assert_eq!(alphaid.encode_chars(0), Ok("а".to_string()));
+    /// assert_eq!(alphaid.encode_chars(33), Ok("бб".to_string()));
+    /// ```
+    pub fn encode_chars(&self, n: T) -> Result<String, AlphaIdError> {
+        let mut out = String::new();
+        self.encode_fmt(n, &mut out)?;
+        Ok(out)
+    }
+
+    /// Encode the numbers into a [`fmt::Write`], such as a `String` or a
+    /// `fmt::Formatter`, the same way as [`encode_chars`](AlphaId::encode_chars).
+    ///
+    /// Returns `AlphaIdError::WriteFailed` if the writer fails. Part of the
+    /// result may have been written if an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fmt::Write;
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let mut out = String::from("/users/");
+    /// alphaid.encode_fmt(1350997667, &mut out).unwrap();
+    /// assert_eq!(out, "/users/90F7qb");
+    /// ```
+    pub fn encode_fmt<W: fmt::Write + ?Sized>(&self, n: T, w: &mut W) -> Result<(), AlphaIdError> {
+        #[cfg(feature = "metrics")]
+        {
+            let mut w = metrics::CountingWriter::new(w);
+            let result = self.write_chars(n, &mut w);
+            metrics::encoded(result.as_ref().map(|_| w.len()));
+            result
+        }
+        #[cfg(not(feature = "metrics"))]
+        self.write_chars(n, w)
+    }
+
+    fn write_chars<W: fmt::Write + ?Sized>(&self, n: T, w: &mut W) -> Result<(), AlphaIdError> {
+        let mut result = self.write_affix(w, &self.prefix);
+        let mut count = 0;
+        self.encode_digits(n, |d| {
+            if result.is_err() {
+                return;
+            }
+            if let Some(sep) = self.separator_before(count) {
+                result = w.write_char(sep as char);
+            }
+            count += 1;
+            result = result.and_then(|_| match self.symbols.get(d) {
+                Some(symbol) => w.write_str(symbol),
+                None => w.write_char(self.chars[d] as char),
+            });
+        })?;
+        result
+            .and_then(|_| self.write_affix(w, &self.suffix))
+            .map_err(|_| AlphaIdError::WriteFailed)
+    }
+
+    /// Writes a prefix or suffix the same way as the symbols are written.
+    ///
+    /// Building makes sure the affixes are ASCII for a `chars` alphabet
+    /// unless raw bytes are allowed, and UTF-8 for symbols, so the result
+    /// equals the one of [`encode_string`](AlphaId::encode_string).
+    fn write_affix<W: fmt::Write + ?Sized>(&self, w: &mut W, affix: &[u8]) -> fmt::Result {
+        match core::str::from_utf8(affix) {
+            Ok(affix) if !self.symbols.is_empty() => w.write_str(affix),
+            _ => affix.iter().try_for_each(|&b| w.write_char(b as char)),
+        }
+    }
+
+    /// Returns the separator to insert before the symbol at `position`.
+    fn separator_before(&self, position: usize) -> Option<u8> {
+        match self.group {
+            Some((size, sep)) if position > 0 && position.is_multiple_of(size) => Some(sep),
+            _ => None,
+        }
+    }
+
+    /// Emits the index of every output symbol in the configured order,
+    /// replacing representations which contain a blocked word.
+    fn encode_digits<F: FnMut(usize)>(&self, n: T, emit: F) -> Result<(), AlphaIdError> {
+        if self.blocklist.is_empty() && self.order == DigitOrder::LittleEndian {
+            return self.emit_digits(n, emit);
+        }
+
+        let mut digits = Vec::new();
+        self.emit_digits(n, |d| digits.push(d))?;
+        if !self.blocklist.is_empty() {
+            digits = self.avoid_blocked(digits)?;
+        }
+        if self.order == DigitOrder::BigEndian {
+            digits.reverse();
+        }
+        digits.into_iter().for_each(emit);
+        Ok(())
+    }
+
+    /// Returns the first of the representations of `digits` which does not
+    /// contain a blocked word.
+    ///
+    /// The alternatives rotate every digit by `k` and append `k` followed by
+    /// a zero digit. Canonical encodings never end with a zero digit, so
+    /// decoding can tell them apart.
+    fn avoid_blocked(&self, digits: Vec<usize>) -> Result<Vec<usize>, AlphaIdError> {
+        if !self.is_blocked(&digits) {
+            return Ok(digits);
+        }
+
+        let size = self.size();
+        for k in 1..size {
+            let mut alt: Vec<usize> = digits.iter().map(|d| (d + k) % size).collect();
+            alt.push(k);
+            alt.push(0);
+            if !self.is_blocked(&alt) {
+                return Ok(alt);
+            }
+        }
+
+        Err(AlphaIdError::Blocked)
+    }
+
+    fn is_blocked(&self, digits: &[usize]) -> bool {
+        let mut out = Vec::new();
+        let mut render = |d: usize| match self.symbols.get(d) {
+            Some(symbol) => out.extend_from_slice(symbol.as_bytes()),
+            None => out.push(self.chars[d]),
+        };
+        match self.order {
+            DigitOrder::LittleEndian => digits.iter().for_each(|&d| render(d)),
+            DigitOrder::BigEndian => digits.iter().rev().for_each(|&d| render(d)),
+        }
+        self.blocklist.matches(&out)
+    }
+
+    /// Returns true if `v` equals one of the words set by
+    /// [`Builder::reserved`], ignoring case.
+    pub fn is_reserved(&self, v: &[u8]) -> bool {
+        self.blocklist.is_reserved(v)
+    }
+
+    /// Returns the characters set, empty if the alphabet was set by
+    /// [`Builder::chars_str`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().pad(3).build();
+    /// assert_eq!(alphaid.alphabet().len(), 64);
+    /// assert_eq!(alphaid.base(), 64);
+    /// assert_eq!(alphaid.pad(), 3);
+    /// ```
+    pub fn alphabet(&self) -> &[u8] {
+        &self.chars
+    }
+
+    /// Returns the symbols set by [`Builder::chars_str`].
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    /// Returns the number of digit values, one less than the alphabet size
+    /// in `PadMode::Random`.
+    pub fn base(&self) -> usize {
+        self.base
+            .to_usize()
+            .expect("the base fits the alphabet size")
+    }
+
+    /// Returns the minimum length of the encoded result.
+    pub fn pad(&self) -> u32 {
+        self.pad
+    }
+
+    fn size(&self) -> usize {
+        self.chars.len().max(self.symbols.len())
+    }
+
+    /// Emits the index of every output symbol, least significant first.
+    fn emit_digits<F: FnMut(usize)>(&self, n: T, emit: F) -> Result<(), AlphaIdError> {
+        self.digits(n)?.for_each(emit);
+        Ok(())
+    }
+
+    /// Returns the index of every output symbol, least significant first.
+    fn digits(&self, n: T) -> Result<Digits<T>, AlphaIdError> {
+        let n = self.forward(n)?;
+        if let Some(width) = self.width {
+            let mut rest = n;
+            for _ in 0..width {
+                rest = rest / self.base;
+            }
+            if !rest.is_zero() {
+                return Err(AlphaIdError::Overflow);
+            }
+            return Ok(Digits::fixed(n, self.base, width).with_bits(self.bits));
+        }
+
+        let digits = match self.pad_mode {
+            PadMode::Shift => Digits::shifted(n, self.base, self.pad),
+            PadMode::Random => Digits::random(n, self.base, self.pad, self.size()),
+            PadMode::Fill => Digits::filled(n, self.base, self.pad),
+        };
+        Ok(digits.with_bits(self.bits))
+    }
+
+    /// Decode into numbers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.decode(b"a"), Ok(0));
+    /// assert_eq!(alphaid.decode(b"b"), Ok(1));
+    /// assert_eq!(alphaid.decode(b"90F7qb"), Ok(1350997667));
+    ///```
+    pub fn decode<V: AsRef<[u8]>>(&self, v: V) -> Result<T, AlphaIdError> {
+        let v = v.as_ref();
+        let result = self.decode_bytes(v);
+        #[cfg(feature = "metrics")]
+        metrics::decoded(result.as_ref().map(|_| ()));
+        #[cfg(feature = "tracing")]
+        if let Err(e) = &result {
+            tracing::decode_failed(self, v, e);
+        }
+        result
+    }
+
+    fn decode_bytes(&self, v: &[u8]) -> Result<T, AlphaIdError> {
+        self.check_input_len(v.len())?;
+        let v = v
+            .strip_prefix(&self.prefix[..])
+            .ok_or(AlphaIdError::PrefixMissed)?;
+        let v = v
+            .strip_suffix(&self.suffix[..])
+            .ok_or(AlphaIdError::SuffixMissed)?;
+        let mut v = self.unconfuse(v);
+        if !self.symbols.is_empty() {
+            return self.decode_symbols(&v);
+        }
+        if let Some((_, sep)) = self.group {
+            v.to_mut().retain(|&b| b != sep);
+        }
+        if self.reject_empty && v.is_empty() {
+            return Err(AlphaIdError::EmptyInput);
+        }
+
+        if let Some(ranges) = self.ranges.as_ref().filter(|_| v.len() >= simd::CHUNK) {
+            let mut digits = Vec::with_capacity(v.len());
+            // Unexpected bytes are reported by the scalar path.
+            if ranges.translate(&v, &mut digits) {
+                return self.decode_ordered(v.len(), digits.iter().map(|&d| T::from_u8(d)));
+            }
+        }
+
+        self.decode_ordered(v.len(), v.iter().map(|&b| self.digit(b)))
+    }
+
+    /// Decode an input which is known to be the result of
+    /// [`encode`](AlphaId::encode), skipping the overflow, length and
+    /// canonical form checks of [`decode`](AlphaId::decode).
+    ///
+    /// The affixes and the alphabet are still validated. Any other input
+    /// decodes to an unspecified number, e.g. one which does not fit into
+    /// `T` wraps around instead of returning `AlphaIdError::Overflow`.
+    ///
+    /// Only alphabets without a blocklist, padded with
+    /// [`PadMode::Fill`] or unpadded, take the fast path. Other
+    /// configurations are decoded like [`decode`](AlphaId::decode).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.decode_unchecked(b"90F7qb"), Ok(1350997667));
+    /// assert!(alphaid.decode_unchecked(b"90F7q?").is_err());
+    /// ```
+    pub fn decode_unchecked<V: AsRef<[u8]>>(&self, v: V) -> Result<T, AlphaIdError> {
+        let v = v.as_ref();
+        let fast = self.symbols.is_empty()
+            && self.blocklist.is_empty()
+            && (self.pad_mode == PadMode::Fill || self.pad <= 1 && self.pad_mode == PadMode::Shift);
+        if !fast {
+            return self.decode(v);
+        }
+
+        let v = v
+            .strip_prefix(&self.prefix[..])
+            .ok_or(AlphaIdError::PrefixMissed)?;
+        let v = v
+            .strip_suffix(&self.suffix[..])
+            .ok_or(AlphaIdError::SuffixMissed)?;
+        let v = self.unconfuse(v);
+        let sep = self.group.map(|(_, sep)| sep);
+        let base = to_u128(self.base);
+        let accumulate = |n: u128, b: u8| -> Result<u128, AlphaIdError> {
+            match self.index[b as usize] {
+                -1 => Err(AlphaIdError::UnexpectedChar),
+                d => Ok(n.wrapping_mul(base).wrapping_add(d as u128)),
+            }
+        };
+        let mut digits = v.iter().copied().filter(|&b| Some(b) != sep);
+        let n = match self.order {
+            DigitOrder::LittleEndian => digits.rev().try_fold(0, accumulate)?,
+            DigitOrder::BigEndian => digits.try_fold(0, accumulate)?,
+        };
+        let n = from_u128(n & to_u128(T::max_value())).expect("masked to fit");
+        self.backward(n)
+    }
+
+    /// Returns `AlphaIdError::InvalidLength` if an encoded result of `len`
+    /// bytes exceeds [`Builder::max_len`].
+    fn check_len(&self, len: usize) -> Result<(), AlphaIdError> {
+        match self.max_len {
+            Some(max) if len > max => Err(AlphaIdError::InvalidLength),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns `AlphaIdError::InputTooLong` if `len` exceeds
+    /// [`Builder::max_input_len`].
+    fn check_input_len(&self, len: usize) -> Result<(), AlphaIdError> {
+        match self.max_input_len {
+            Some(max) if len > max => Err(AlphaIdError::InputTooLong),
+            _ => Ok(()),
+        }
+    }
+
+    /// Decodes the digit values of `len` input symbols in input order.
+    fn decode_ordered<I>(&self, len: usize, digits: I) -> Result<T, AlphaIdError>
+    where
+        I: DoubleEndedIterator<Item = Option<T>>,
+    {
+        let n = match self.order {
+            DigitOrder::LittleEndian => self.decode_digits(len, digits),
+            DigitOrder::BigEndian
+                if self.blocklist.is_empty()
+                    && (self.pad_mode == PadMode::Fill
+                        || self.pad <= 1 && self.pad_mode == PadMode::Shift) =>
+            {
+                self.accumulate_horner(len, digits)
+            }
+            DigitOrder::BigEndian => self.decode_digits(len, digits.rev()),
+        };
+        self.backward(n?)
+    }
+
+    /// Returns `true` if `v` decodes without error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().prefix(b"id_").build();
+    /// assert!(alphaid.is_valid(b"id_90F7qb"));
+    /// assert!(!alphaid.is_valid(b"90F7qb"));
+    /// assert!(!alphaid.is_valid(b"id_________"));
+    /// ```
+    pub fn is_valid<V: AsRef<[u8]>>(&self, v: V) -> bool {
+        self.decode(v).is_ok()
+    }
+
+    /// Decode and encode again, returning the result of
+    /// [`encode`](AlphaId::encode) for the same numbers.
+    ///
+    /// The random filler of `PadMode::Random` is drawn anew.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.canonicalize(b"baaa"), Ok(b"b".to_vec()));
+    /// ```
+    pub fn canonicalize<V: AsRef<[u8]>>(&self, v: V) -> Result<Vec<u8>, AlphaIdError> {
+        self.encode(self.decode(v)?)
+    }
+
+    /// Decode with this `AlphaId` and encode the numbers with `target`,
+    /// e.g. to migrate IDs to another alphabet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let old = AlphaId::<u32>::new();
+    /// let new = AlphaId::<u32>::builder().pad(8).prefix(b"id_").build();
+    /// assert_eq!(old.transcode(&new, b"90F7qb"), Ok(b"id_90F7qbab".to_vec()));
+    /// assert_eq!(new.transcode(&old, b"id_90F7qbab"), Ok(b"90F7qb".to_vec()));
+    /// ```
+    pub fn transcode<N, V: AsRef<[u8]>>(
+        &self,
+        target: &AlphaId<T, N>,
+        v: V,
+    ) -> Result<Vec<u8>, AlphaIdError> {
+        target.encode(self.decode(v)?)
+    }
+
+    /// Decode an encoded result at the start of `v`, returning the numbers
+    /// and the rest of `v`.
+    ///
+    /// The result ends at the first byte which is not part of the alphabet
+    /// or a separator, followed by the suffix if one is set. If the suffix
+    /// starts with symbols, the result ends before the last occurrence of
+    /// the suffix which leaves a valid encoding. Confusable characters end
+    /// the result too.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .chars(b"abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ".to_vec())
+    ///     .build();
+    /// let path = b"90F7qb-thumbnail.jpg";
+    /// let (n, rest) = alphaid.decode_prefix(path).unwrap();
+    /// assert_eq!(alphaid.encode(n), Ok(b"90F7qb".to_vec()));
+    /// assert_eq!(rest, b"-thumbnail.jpg");
+    /// ```
+    pub fn decode_prefix<'a>(&self, v: &'a [u8]) -> Result<(T, &'a [u8]), AlphaIdError> {
+        let body = v
+            .strip_prefix(&self.prefix[..])
+            .ok_or(AlphaIdError::PrefixMissed)?;
+        // The suffix can start with symbols, so every symbol boundary which
+        // it follows is a candidate, and the longest which decodes wins.
+        let mut ends = Vec::new();
+        let mut end = 0;
+        let mut symbols = 0;
+        loop {
+            let rest = &body[end..];
+            let sep = match (self.group, rest.first()) {
+                (Some((_, sep)), Some(&b)) if b == sep && symbols > 0 => 1,
+                _ => 0,
+            };
+            match self.symbol_len(&rest[sep..]) {
+                Some(len) => {
+                    end += sep + len;
+                    symbols += 1;
+                    if body[end..].starts_with(&self.suffix) {
+                        ends.push(end);
+                    }
+                }
+                None => break,
+            }
+        }
+        if symbols == 0 {
+            return Err(AlphaIdError::UnexpectedChar);
+        }
+
+        let mut result = Err(AlphaIdError::SuffixMissed);
+        for end in ends.into_iter().rev() {
+            let len = self.prefix.len() + end + self.suffix.len();
+            let (encoded, rest) = v.split_at(len);
+            match self.decode(encoded) {
+                Ok(n) => return Ok((n, rest)),
+                Err(e) if result == Err(AlphaIdError::SuffixMissed) => result = Err(e),
+                Err(_) => {}
+            }
+        }
+        result
+    }
+
+    /// Decode into numbers, skipping every byte which is not part of the
+    /// alphabet, such as whitespace or punctuation from copy and paste.
+    ///
+    /// Everything before the prefix and after the suffix is skipped if they
+    /// are present, and confusable characters are replaced first. Returns
+    /// the numbers and the number of skipped bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .chars(b"abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ".to_vec())
+    ///     .build();
+    /// let n = alphaid.decode(b"90F7qb").unwrap();
+    /// assert_eq!(alphaid.decode_lossy(b" 90F7-qb.\n"), Ok((n, 4)));
+    /// ```
+    pub fn decode_lossy<V: AsRef<[u8]>>(&self, v: V) -> Result<(T, usize), AlphaIdError> {
+        let mut v = v.as_ref();
+        let mut skipped = 0;
+        if !self.prefix.is_empty() {
+            if let Some(i) = v
+                .windows(self.prefix.len())
+                .position(|w| w == &self.prefix[..])
+            {
+                skipped += i;
+                v = &v[i + self.prefix.len()..];
+            }
+        }
+        if !self.suffix.is_empty() {
+            if let Some(i) = v
+                .windows(self.suffix.len())
+                .rposition(|w| w == &self.suffix[..])
+            {
+                skipped += v.len() - i - self.suffix.len();
+                v = &v[..i];
+            }
+        }
+        let v = self.unconfuse(v);
+
+        let mut kept = self.prefix.to_vec();
+        let mut rest = &v[..];
+        while !rest.is_empty() {
+            let len = match self.symbol_len(rest) {
+                Some(len) => {
+                    kept.extend_from_slice(&rest[..len]);
+                    len
+                }
+                None => {
+                    // Skip a whole UTF-8 character.
+                    let len = 1 + rest[1..].iter().take_while(|&&b| b & 0xC0 == 0x80).count();
+                    skipped += len;
+                    len
+                }
+            };
+            rest = &rest[len..];
+        }
+        kept.extend_from_slice(&self.suffix);
+        Ok((self.decode(kept)?, skipped))
+    }
+
+    /// Returns the length of the symbol at the start of `v`.
+    fn symbol_len(&self, v: &[u8]) -> Option<usize> {
+        if self.symbols.is_empty() {
+            return self.digit(*v.first()?).map(|_| 1);
+        }
+        let s = match core::str::from_utf8(v) {
+            Ok(s) => s,
+            Err(e) => core::str::from_utf8(&v[..e.valid_up_to()]).ok()?,
+        };
+        self.match_symbol(s).map(|(_, len)| len)
+    }
+
+    /// Decode into numbers, accepting only the exact result of
+    /// [`encode`](AlphaId::encode).
+    ///
+    /// Returns `AlphaIdError::NonCanonical` for other representations of
+    /// the same number, e.g. with redundant zero symbols, confusable
+    /// characters or misplaced separators. The random filler of
+    /// `PadMode::Random` may be any symbols.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError};
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.decode(b"ba"), Ok(1));
+    /// assert_eq!(alphaid.decode_strict(b"b"), Ok(1));
+    /// assert_eq!(alphaid.decode_strict(b"ba"), Err(AlphaIdError::NonCanonical));
+    /// ```
+    pub fn decode_strict<V: AsRef<[u8]>>(&self, v: V) -> Result<T, AlphaIdError> {
+        let v = v.as_ref();
+        let result = self.decode_canonical(v);
+        #[cfg(feature = "metrics")]
+        metrics::decoded(result.as_ref().map(|_| ()));
+        #[cfg(feature = "tracing")]
+        if let Err(e) = &result {
+            tracing::decode_failed(self, v, e);
+        }
+        result
+    }
+
+    fn decode_canonical(&self, v: &[u8]) -> Result<T, AlphaIdError> {
+        let n = self.decode_bytes(v)?;
+        let body = &v[self.prefix.len()..v.len() - self.suffix.len()];
+        let mut tokens = self.tokenize(body).ok_or(AlphaIdError::NonCanonical)?;
+        let mut expected = Vec::new();
+        self.encode_digits(n, |d| expected.push(d))?;
+
+        if self.order == DigitOrder::BigEndian {
+            tokens.reverse();
+            expected.reverse();
+        }
+        let end = match self.pad_mode {
+            // Only the digits and the terminator are canonical.
+            PadMode::Random => expected
+                .iter()
+                .position(|&d| d == self.size() - 1)
+                .map_or(expected.len(), |i| i + 1),
+            PadMode::Shift | PadMode::Fill => expected.len(),
+        };
+        if tokens.len() != expected.len() || tokens[..end] != expected[..end] {
+            return Err(AlphaIdError::NonCanonical);
+        }
+        Ok(n)
+    }
+
+    /// Splits the input between the affixes into symbol indices, returning
+    /// `None` for anything but symbols and correctly placed separators.
+    fn tokenize(&self, body: &[u8]) -> Option<Vec<usize>> {
+        let mut tokens = Vec::new();
+        let mut after_sep = false;
+        let mut rest = body;
+        while let Some(&b) = rest.first() {
+            if self.group.is_some_and(|(_, sep)| sep == b) {
+                if after_sep || self.separator_before(tokens.len()).is_none() {
+                    return None;
+                }
+                after_sep = true;
+                rest = &rest[1..];
+                continue;
+            }
+            if after_sep != self.separator_before(tokens.len()).is_some() {
+                return None;
+            }
+            after_sep = false;
+
+            let (t, len) = if self.symbols.is_empty() {
+                (self.digit(b)?, 1)
+            } else {
+                self.match_symbol(core::str::from_utf8(rest).ok()?)?
+            };
+            tokens.push(t.to_usize()?);
+            rest = &rest[len..];
+        }
+        if after_sep {
+            return None;
+        }
+        Some(tokens)
+    }
+
+    /// Decode a string into numbers.
+    ///
+    /// For alphabets set by [`Builder::chars_str`] the input is split at the
+    /// longest symbol matching at each position.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .chars_str("абвгдежзийклмнопрстуфхцчшщъыьэюя")
+    ///     .build();
+    /// assert_eq!(alphaid.decode_str("а"), Ok(0));
+    /// assert_eq!(alphaid.decode_str("бб"), Ok(33));
+    /// ```
+    pub fn decode_str(&self, s: &str) -> Result<T, AlphaIdError> {
+        self.decode(self.str_bytes(s)?)
+    }
+
+    /// Maps a string to the bytes `encode` produces, the reverse of
+    /// [`encode_chars`](AlphaId::encode_chars).
+    pub(crate) fn str_bytes<'a>(&self, s: &'a str) -> Result<Cow<'a, [u8]>, AlphaIdError> {
+        self.check_input_len(s.len())?;
+        if self.symbols.is_empty() && !s.is_ascii() {
+            return s
+                .chars()
+                .map(|c| u8::try_from(c).map_err(|_| AlphaIdError::UnexpectedChar))
+                .collect::<Result<Vec<u8>, _>>()
+                .map(Cow::Owned);
+        }
+
+        Ok(Cow::Borrowed(s.as_bytes()))
+    }
+
+    /// Decode a string into numbers like [`decode_str`](AlphaId::decode_str),
+    /// reporting the position of an unexpected character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError, PositionedError};
+    ///
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .chars_str("абвгдежзийклмнопрстуфхцчшщъыьэюя")
+    ///     .build();
+    /// assert_eq!(
+    ///     alphaid.decode_str_with_position("ббxб"),
+    ///     Err(PositionedError {
+    ///         error: AlphaIdError::UnexpectedChar,
+    ///         position: Some(2),
+    ///     })
+    /// );
+    /// ```
+    pub fn decode_str_with_position(&self, s: &str) -> Result<T, PositionedError> {
+        self.decode_str(s).map_err(|error| PositionedError {
+            position: match error {
+                AlphaIdError::UnexpectedChar => self.unexpected_char_position(s),
+                _ => None,
+            },
+            error,
+        })
+    }
+
+    /// Returns the index of the first `char` of `s` which can not be decoded.
+    fn unexpected_char_position(&self, s: &str) -> Option<usize> {
+        let prefix = String::from_utf8_lossy(&self.prefix);
+        let suffix = String::from_utf8_lossy(&self.suffix);
+        let body = s.strip_prefix(&*prefix)?.strip_suffix(&*suffix)?;
+        let offset = prefix.chars().count();
+        let is_sep = |c: char| self.group.is_some_and(|(_, sep)| sep as char == c);
+
+        if self.symbols.is_empty() {
+            return body
+                .chars()
+                .position(|c| {
+                    let known = u8::try_from(c).is_ok_and(|b| {
+                        self.digit(b).is_some()
+                            || self.confusables.iter().any(|(from, _)| from.contains(&b))
+                    });
+                    !known && !is_sep(c)
+                })
+                .map(|i| offset + i);
+        }
+
+        let mut rest = body;
+        let mut position = offset;
+        while let Some(c) = rest.chars().next() {
+            let len = match self.match_symbol(rest) {
+                Some((_, len)) => len,
+                None if is_sep(c) => c.len_utf8(),
+                None => return Some(position),
+            };
+            position += rest[..len].chars().count();
+            rest = &rest[len..];
+        }
+        None
+    }
+
+    /// Splits `v` into the symbols set by [`Builder::chars_str`] and decodes
+    /// them.
+    ///
+    /// The parts between separators are split independently, so a symbol
+    /// which starts with another one is found when both are separated.
+    fn decode_symbols(&self, v: &[u8]) -> Result<T, AlphaIdError> {
+        let sep = self.group.map(|(_, sep)| sep);
+        let mut digits = Vec::new();
+        for part in v.split(|&b| Some(b) == sep) {
+            let mut rest = core::str::from_utf8(part).map_err(|_| AlphaIdError::UnexpectedChar)?;
+            while !rest.is_empty() {
+                let (t, len) = self
+                    .match_symbol(rest)
+                    .ok_or(AlphaIdError::UnexpectedChar)?;
+                digits.push(Some(t));
+                rest = &rest[len..];
+            }
+        }
+        if self.reject_empty && digits.is_empty() {
+            return Err(AlphaIdError::EmptyInput);
+        }
+        if self.order == DigitOrder::BigEndian {
+            digits.reverse();
+        }
+
+        let n = self.decode_digits(digits.len(), digits.into_iter())?;
+        self.backward(n)
+    }
+
+    /// Returns the value and byte length of the longest symbol `s` starts with.
+    fn match_symbol(&self, s: &str) -> Option<(T, usize)> {
+        (1..=self.max_symbol_len.min(s.len()))
+            .rev()
+            .filter(|&len| s.is_char_boundary(len))
+            .find_map(|len| self.symbol_index.get(&s[..len]).map(|t| (*t, len)))
+    }
+
+    /// Decodes the digit values of `len` input symbols, least significant
+    /// first. `None` marks a symbol outside of the alphabet.
+    fn decode_digits<I>(&self, len: usize, digits: I) -> Result<T, AlphaIdError>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        if self.blocklist.is_empty() {
+            return self.accumulate_digits(len, digits);
+        }
+
+        let mut digits: Vec<Option<T>> = digits.collect();
+        if digits.len() < 3 || digits[digits.len() - 1] != Some(T::zero()) {
+            return self.accumulate_digits(len, digits.into_iter());
+        }
+
+        // An alternative picked by `avoid_blocked`.
+        digits.pop();
+        let k = digits
+            .pop()
+            .flatten()
+            .filter(|k| !k.is_zero())
+            .ok_or(AlphaIdError::UnexpectedChar)?;
+        let size = self.base;
+        self.accumulate_digits(
+            digits.len(),
+            // `d + size` could overflow a small `T` with a large base.
+            digits
+                .into_iter()
+                .map(|d| d.map(|d| if d >= k { d - k } else { d + (size - k) })),
+        )
+    }
+
+    /// Accumulates the digit values of `len` input symbols, most
+    /// significant first. Padding must not be shifted.
+    fn accumulate_horner<I>(&self, len: usize, digits: I) -> Result<T, AlphaIdError>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        if self.width.is_some_and(|width| width as usize != len) {
+            return Err(AlphaIdError::InvalidLength);
+        }
+
+        let limit = T::max_value() / self.base;
+        let mut n = T::zero();
+        for digit in digits {
+            let d = digit.ok_or(AlphaIdError::UnexpectedChar)?;
+            if n > limit || T::max_value() - n * self.base < d {
+                return Err(AlphaIdError::Overflow);
+            }
+            n = match self.bits {
+                Some(bits) => n << bits | d,
+                None => n * self.base + d,
+            };
+        }
+        Ok(n)
+    }
+
+    /// Accumulates the digit values of `len` input symbols, least
+    /// significant first. `None` marks a symbol outside of the alphabet.
+    fn accumulate_digits<I>(&self, len: usize, digits: I) -> Result<T, AlphaIdError>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        if self.width.is_some_and(|width| width as usize != len) {
+            return Err(AlphaIdError::InvalidLength);
+        }
+
+        let mut n = T::zero();
+        let mut unpad = self.pad > 1 && self.pad_mode == PadMode::Shift;
+        let mut prev = T::zero();
+
+        let mut digits = digits.enumerate();
+        while let Some((i, digit)) = digits.next() {
+            let t = digit.ok_or(AlphaIdError::UnexpectedChar)?;
+            if t == self.base {
+                // The terminator of `PadMode::Random`, followed by filler.
+                if digits.any(|(_, d)| d.is_none()) {
+                    return Err(AlphaIdError::UnexpectedChar);
+                }
+                break;
+            }
+            let mut x = t;
+
+            if unpad {
+                if x.is_zero() && i + 1 == len {
+                    return Err(AlphaIdError::PadMissed);
+                }
+
+                if i + 1 >= self.pad as usize {
+                    let skipped = self.base - prev - T::one();
+                    if i > 1 && i + 1 > self.pad as usize && !skipped.is_zero() {
+                        n = self
+                            .powers
+                            .get(i - 1)
+                            .and_then(|pow| pow.checked_mul(&skipped))
+                            .and_then(|add| n.checked_add(&add))
+                            .ok_or(AlphaIdError::Overflow)?;
+                    }
+
+                    if !x.is_zero() {
+                        unpad = false;
+                        x = x - T::one();
+                    }
+                }
+            };
+
+            prev = t;
+
+            if x.is_zero() {
+                continue;
+            }
+
+            let pow = *self.powers.get(i).ok_or(AlphaIdError::Overflow)?;
+            if T::max_value() / pow < x {
+                return Err(AlphaIdError::Overflow);
+            }
+            let add = match self.bits {
+                Some(bits) => x << (bits * i),
+                None => pow * x,
+            };
+            n = n.checked_add(&add).ok_or(AlphaIdError::Overflow)?;
+        }
+
+        Ok(n)
+    }
+
+    /// Returns the digit value of a byte of the alphabet.
+    fn digit(&self, b: u8) -> Option<T> {
+        match self.index[b as usize] {
+            -1 => None,
+            d => T::from_i16(d),
+        }
+    }
+
+    /// Replaces the configured confusable sequences in the input.
+    fn unconfuse<'a>(&self, v: &'a [u8]) -> Cow<'a, [u8]> {
+        if self.confusables.is_empty() {
+            return Cow::Borrowed(v);
+        }
+
+        let mut out = Vec::with_capacity(v.len());
+        let mut i = 0;
+        'outer: while i < v.len() {
+            for (from, to) in self.confusables.iter() {
+                if v[i..].starts_with(from) {
+                    out.push(*to);
+                    i += from.len();
+                    continue 'outer;
+                }
+            }
+            out.push(v[i]);
+            i += 1;
+        }
+        Cow::Owned(out)
     }
 }
@@ -0,0 +1,100 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Builder, UnsignedInteger, DEFAULT_SEED};
+
+/// Pairs of characters which are easily mistaken for each other.
+const CONFUSABLE_PAIRS: &[(char, char)] = &[
+    ('0', 'O'),
+    ('0', 'o'),
+    ('O', 'o'),
+    ('1', 'l'),
+    ('1', 'I'),
+    ('I', 'l'),
+    ('2', 'Z'),
+    ('5', 'S'),
+    ('8', 'B'),
+    ('u', 'v'),
+    ('U', 'V'),
+];
+
+/// A non-fatal problem of the settings, returned by [`Builder::lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Lint {
+    /// Two characters of the alphabet which are easily mistaken for each
+    /// other, such as `0` and `O`.
+    Confusable(char, char),
+    /// A character of the alphabet or the separator which is
+    /// percent-encoded in URLs.
+    NeedsUrlEncoding(char),
+    /// Both cases of a letter, which are mixed up where the input is
+    /// case-insensitive, e.g. in host names or when read aloud.
+    MixedCase(char),
+    /// Vowels, which let encodings spell words without blocked words.
+    Vowels,
+}
+
+impl<T: UnsignedInteger> Builder<T> {
+    /// Returns the non-fatal problems of the settings, e.g. for reviewing
+    /// configurations. [`build`](Builder::build) accepts them all.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{Builder, Lint};
+    ///
+    /// let lints = Builder::<u32>::new()
+    ///     .chars(b"0123456789ABCDEFGHJKMNPQRSTVWXYZ".to_vec())
+    ///     .lint();
+    /// assert_eq!(
+    ///     lints,
+    ///     [
+    ///         Lint::Confusable('2', 'Z'),
+    ///         Lint::Confusable('5', 'S'),
+    ///         Lint::Confusable('8', 'B'),
+    ///         Lint::Vowels,
+    ///     ]
+    /// );
+    /// ```
+    pub fn lint(&self) -> Vec<Lint> {
+        let symbols: Vec<String> = match (&self.chars, &self.symbols) {
+            (Some(chars), _) => chars.iter().map(|&b| char::from(b).into()).collect(),
+            (None, Some(symbols)) => symbols.clone(),
+            (None, None) => DEFAULT_SEED.chars().map(String::from).collect(),
+        };
+        let has = |c: char| {
+            symbols
+                .iter()
+                .any(|s| s.len() == c.len_utf8() && s.starts_with(c))
+        };
+        let mut lints = Vec::new();
+
+        for &(a, b) in CONFUSABLE_PAIRS {
+            if has(a) && has(b) {
+                lints.push(Lint::Confusable(a, b));
+            }
+        }
+
+        let separator = self.group.map(|(_, sep)| char::from(sep));
+        let mut url = Vec::new();
+        for c in symbols.iter().flat_map(|s| s.chars()).chain(separator) {
+            let unreserved = c.is_ascii_alphanumeric() || "-._~".contains(c);
+            if !unreserved && !url.contains(&c) {
+                url.push(c);
+            }
+        }
+        lints.extend(url.into_iter().map(Lint::NeedsUrlEncoding));
+
+        lints.extend(
+            ('a'..='z')
+                .filter(|&c| has(c) && has(c.to_ascii_uppercase()))
+                .map(Lint::MixedCase),
+        );
+
+        if self.blocklist.is_empty() && "aeiouAEIOU".chars().any(has) {
+            lints.push(Lint::Vowels);
+        }
+        lints
+    }
+}
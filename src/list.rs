@@ -0,0 +1,111 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, PositionedError, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode the numbers one by one, joined by `delim`, e.g. `Qb.7xF.a2`.
+    ///
+    /// Every item is a complete result of [`encode`](AlphaId::encode), so
+    /// it can be split off and decoded on its own. An empty list is encoded
+    /// as an empty result.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` if `delim` is a character of
+    /// the alphabet, the group separator or in the affixes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let v = alphaid.encode_list(&[1350997667, 0, 64], b'.').unwrap();
+    /// assert_eq!(v, b"90F7qb.a.ab");
+    /// assert_eq!(alphaid.decode_list(&v, b'.'), Ok(vec![1350997667, 0, 64]));
+    /// ```
+    pub fn encode_list(&self, items: &[T], delim: u8) -> Result<Vec<u8>, AlphaIdError> {
+        self.check_delimiter(delim)?;
+        let mut out = Vec::new();
+        for (i, &n) in items.iter().enumerate() {
+            if i > 0 {
+                out.push(delim);
+            }
+            out.extend_from_slice(&self.encode(n)?);
+        }
+        Ok(out)
+    }
+
+    /// Decode the result of [`encode_list`](AlphaId::encode_list).
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` for the delimiters rejected by
+    /// `encode_list`, and the error of the first item which fails.
+    pub fn decode_list<V: AsRef<[u8]>>(&self, v: V, delim: u8) -> Result<Vec<T>, AlphaIdError> {
+        self.check_delimiter(delim)?;
+        let v = v.as_ref();
+        if v.is_empty() {
+            return Ok(Vec::new());
+        }
+        v.split(|&b| b == delim)
+            .map(|item| self.decode(item))
+            .collect()
+    }
+
+    /// Decode IDs of `width` bytes each, concatenated without delimiters,
+    /// e.g. those of [`sortable`](crate::Builder::sortable) mode, whose
+    /// results are all [`max_encoded_len`](AlphaId::max_encoded_len) bytes
+    /// long.
+    ///
+    /// The position of an error is the byte offset of the ID which failed.
+    /// Returns `AlphaIdError::InvalidLength` if `width` is zero or the input
+    /// ends with an incomplete ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError, PositionedError};
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().sortable(6).build();
+    /// let width = alphaid.max_encoded_len();
+    /// assert_eq!(alphaid.decode_concat(b"------------", width), Ok(vec![0, 0]));
+    /// assert_eq!(
+    ///     alphaid.decode_concat(b"------?-----", width),
+    ///     Err(PositionedError {
+    ///         error: AlphaIdError::UnexpectedChar,
+    ///         position: Some(6),
+    ///     })
+    /// );
+    /// ```
+    pub fn decode_concat<V: AsRef<[u8]>>(
+        &self,
+        v: V,
+        width: usize,
+    ) -> Result<Vec<T>, PositionedError> {
+        let v = v.as_ref();
+        if width == 0 || v.len() % width != 0 {
+            return Err(PositionedError {
+                error: AlphaIdError::InvalidLength,
+                position: Some(v.len() - v.len() % width.max(1)),
+            });
+        }
+        v.chunks(width)
+            .enumerate()
+            .map(|(i, chunk)| {
+                self.decode(chunk).map_err(|error| PositionedError {
+                    error,
+                    position: Some(i * width),
+                })
+            })
+            .collect()
+    }
+
+    fn check_delimiter(&self, delim: u8) -> Result<(), AlphaIdError> {
+        let in_alphabet = self.chars.contains(&delim)
+            || self.symbols.iter().any(|s| s.as_bytes().contains(&delim));
+        let in_format = self.group.is_some_and(|(_, sep)| sep == delim)
+            || self.prefix.contains(&delim)
+            || self.suffix.contains(&delim);
+        if in_alphabet || in_format {
+            return Err(AlphaIdError::InvalidConfig);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+/// Checks the size of an alphabet of `size` symbols the way
+/// `Builder::build` does.
+pub(crate) const fn check_size(size: usize, small: bool) -> Result<(), &'static str> {
+    if small {
+        if size < 2 {
+            return Err("chars size must be at least 2");
+        }
+    } else if size <= 16 {
+        return Err("chars size must large than 16");
+    }
+    Ok(())
+}
+
+/// Checks a `chars` alphabet, shared by `Builder::build` and the
+/// compile-time checks of the `alphaid!` macro.
+///
+/// `raw` allows bytes which are not printable ASCII and `small` allows
+/// alphabets of at most 16 characters.
+pub(crate) const fn check_chars(chars: &[u8], raw: bool, small: bool) -> Result<(), &'static str> {
+    let mut i = 0;
+    while i < chars.len() {
+        if !raw && !chars[i].is_ascii_graphic() {
+            return Err("chars must be printable characters");
+        }
+        let mut j = i + 1;
+        while j < chars.len() {
+            if chars[i] == chars[j] {
+                return Err("duplicate characters are not allowed");
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    check_size(chars.len(), small)
+}
+
+#[doc(hidden)]
+pub mod __private {
+    /// Checks the characters of the `alphaid!` macro in a const context.
+    pub const fn check_chars(chars: &str) {
+        if let Err(msg) = super::check_chars(chars.as_bytes(), false, false) {
+            panic!("{}", msg);
+        }
+    }
+
+    /// Checks the padding of the `alphaid!` macro in a const context.
+    pub const fn check_pad(pad: u32) {
+        assert!(pad > 0, "pad must large than 1");
+    }
+}
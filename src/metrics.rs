@@ -0,0 +1,69 @@
+//! Metrics of encoding and decoding, recorded with the `metrics` crate to
+//! whichever recorder the application installs.
+//!
+//! | name              | kind      | labels   |
+//! |-------------------|-----------|----------|
+//! | [`ENCODES`]       | counter   | `result` |
+//! | [`DECODES`]       | counter   | `result` |
+//! | [`ENCODED_LEN`]   | histogram |          |
+//!
+//! `result` is `ok` or the reason of the failure, the snake case name of
+//! the [`AlphaIdError`], e.g. `unexpected_char`, `overflow` or
+//! `tag_mismatch`. A spike of failed decodes usually means someone is
+//! enumerating IDs.
+//!
+//! [`AlphaId::encode`](crate::AlphaId::encode) and the other methods
+//! producing a complete result are recorded, as are
+//! [`AlphaId::decode`](crate::AlphaId::decode) and the methods built on it.
+//! [`AlphaId::encode_iter`](crate::AlphaId::encode_iter) and
+//! [`AlphaId::decode_unchecked`](crate::AlphaId::decode_unchecked) are not.
+use core::fmt;
+
+use ::metrics::{counter, histogram};
+
+use crate::AlphaIdError;
+
+/// The number of encodes.
+pub const ENCODES: &str = "alphaid_encodes_total";
+/// The number of decodes.
+pub const DECODES: &str = "alphaid_decodes_total";
+/// The length in bytes of the encoded results.
+pub const ENCODED_LEN: &str = "alphaid_encoded_len";
+
+pub(crate) fn encoded(result: Result<usize, &AlphaIdError>) {
+    match result {
+        Ok(len) => {
+            counter!(ENCODES, "result" => "ok").increment(1);
+            histogram!(ENCODED_LEN).record(len as f64);
+        }
+        Err(e) => counter!(ENCODES, "result" => e.name()).increment(1),
+    }
+}
+
+pub(crate) fn decoded(result: Result<(), &AlphaIdError>) {
+    let result = result.map_or_else(AlphaIdError::name, |_| "ok");
+    counter!(DECODES, "result" => result).increment(1);
+}
+
+/// Counts the bytes written through a `fmt::Write`.
+pub(crate) struct CountingWriter<'a, W: ?Sized> {
+    w: &'a mut W,
+    len: usize,
+}
+
+impl<'a, W: fmt::Write + ?Sized> CountingWriter<'a, W> {
+    pub(crate) fn new(w: &'a mut W) -> Self {
+        Self { w, len: 0 }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for CountingWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.len += s.len();
+        self.w.write_str(s)
+    }
+}
@@ -0,0 +1,100 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, Config, UnsignedInteger};
+
+/// Re-encodes IDs of an old configuration with a new one, e.g. to rotate
+/// the alphabet of stored IDs.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, Migrator};
+///
+/// let migrator = Migrator::new(
+///     AlphaId::<u32>::new(),
+///     AlphaId::<u32>::builder().prefix(b"usr_").pad(4).build(),
+/// );
+/// assert_eq!(migrator.migrate(b"90F7qb"), Ok(b"usr_90F8qb".to_vec()));
+///
+/// let pairs: Vec<_> = migrator.migrate_iter(vec!["b", "?"]).collect();
+/// assert_eq!(pairs[0], ("b", Ok(b"usr_baab".to_vec())));
+/// assert!(pairs[1].1.is_err());
+/// ```
+pub struct Migrator<T: UnsignedInteger = u128> {
+    old: AlphaId<T>,
+    new: AlphaId<T>,
+}
+
+impl<T: UnsignedInteger> Migrator<T> {
+    /// Creates a migrator decoding with `old` and encoding with `new`.
+    pub fn new(old: AlphaId<T>, new: AlphaId<T>) -> Self {
+        Self { old, new }
+    }
+
+    /// Creates a migrator from the old and the new configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics like [`AlphaId::from_config`] does.
+    pub fn from_configs(old: Config, new: Config) -> Self {
+        Self::new(AlphaId::from_config(old), AlphaId::from_config(new))
+    }
+
+    /// Re-encodes a single ID, returning the error of decoding it with the
+    /// old or encoding it with the new configuration.
+    pub fn migrate<V: AsRef<[u8]>>(&self, v: V) -> Result<Vec<u8>, AlphaIdError> {
+        self.new.encode(self.old.decode(v)?)
+    }
+
+    /// Re-encodes the IDs lazily, yielding every old ID with the result of
+    /// migrating it. Errors are reported per item and do not stop the
+    /// iteration.
+    pub fn migrate_iter<'a, I>(
+        &'a self,
+        vs: I,
+    ) -> impl Iterator<Item = (I::Item, Result<Vec<u8>, AlphaIdError>)> + 'a
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+        I::IntoIter: 'a,
+    {
+        vs.into_iter().map(move |v| {
+            let new = self.migrate(&v);
+            (v, new)
+        })
+    }
+
+    /// Re-encodes a batch of IDs, returning the result of every ID in order.
+    pub fn migrate_batch<V: AsRef<[u8]>>(&self, vs: &[V]) -> Vec<Result<Vec<u8>, AlphaIdError>> {
+        vs.iter().map(|v| self.migrate(v)).collect()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: UnsignedInteger + Send + Sync> Migrator<T> {
+    /// Re-encodes a batch of IDs on the rayon thread pool, like
+    /// [`migrate_batch`](Migrator::migrate_batch).
+    ///
+    /// Large tables are best migrated in chunks of a few thousand IDs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, Migrator};
+    ///
+    /// let migrator = Migrator::new(
+    ///     AlphaId::<u32>::new(),
+    ///     AlphaId::<u32>::builder().chars_str("0123456789abcdefghij").build(),
+    /// );
+    /// let old = AlphaId::<u32>::new().encode_batch(&(0..10_000).collect::<Vec<_>>()).unwrap();
+    /// assert_eq!(migrator.par_migrate_batch(&old), migrator.migrate_batch(&old));
+    /// ```
+    pub fn par_migrate_batch<V: AsRef<[u8]> + Sync>(
+        &self,
+        vs: &[V],
+    ) -> Vec<Result<Vec<u8>, AlphaIdError>> {
+        use rayon::prelude::*;
+
+        vs.par_iter().map(|v| self.migrate(v)).collect()
+    }
+}
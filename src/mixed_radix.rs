@@ -0,0 +1,125 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaIdError, Codec, UnsignedInteger};
+
+/// Encodes numbers into a fixed number of positions, each with its own
+/// alphabet, most significant position first.
+///
+/// The base of every position is the size of its alphabet, so the
+/// numbers up to the product of all sizes minus one can be encoded.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaIdError, MixedRadix};
+///
+/// // A letter followed by three digits.
+/// let letters: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+/// let digits: &[u8] = b"0123456789";
+/// let codec = MixedRadix::<u32>::new(&[letters, digits, digits, digits]);
+/// assert_eq!(codec.encode(0), Ok(b"A000".to_vec()));
+/// assert_eq!(codec.encode(1042), Ok(b"B042".to_vec()));
+/// assert_eq!(codec.decode(b"Z999"), Ok(25_999));
+/// assert_eq!(codec.encode(26_000), Err(AlphaIdError::Overflow));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MixedRadix<T: UnsignedInteger = u128> {
+    /// The alphabet and the base of every position.
+    positions: Vec<(Vec<u8>, T)>,
+    /// The digit value of every byte per position, `-1` if it is not in
+    /// the alphabet.
+    index: Vec<[i16; 256]>,
+}
+
+impl<T: UnsignedInteger> MixedRadix<T> {
+    /// Constructs a codec with the alphabet of every position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no positions, an alphabet has less than `2`
+    /// characters or does not fit into `T`, or the characters of an
+    /// alphabet are not unique.
+    pub fn new<A: AsRef<[u8]>>(alphabets: &[A]) -> Self {
+        assert!(!alphabets.is_empty(), "positions must not be empty");
+        let mut positions = Vec::with_capacity(alphabets.len());
+        let mut index = Vec::with_capacity(alphabets.len());
+        for chars in alphabets {
+            let chars = chars.as_ref();
+            assert!(chars.len() >= 2, "chars size must be at least 2");
+            let base =
+                T::from_usize(chars.len()).expect("chars size must fit into the number type");
+            let mut table = [-1; 256];
+            for (i, &b) in chars.iter().enumerate() {
+                assert!(
+                    table[b as usize] == -1,
+                    "duplicate characters are not allowed"
+                );
+                table[b as usize] = i as i16;
+            }
+            positions.push((chars.to_vec(), base));
+            index.push(table);
+        }
+        Self { positions, index }
+    }
+
+    /// Returns the number of positions, the length of every encoded
+    /// result.
+    pub fn encoded_len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Encode the numbers.
+    ///
+    /// Returns `AlphaIdError::Overflow` if the number does not fit into
+    /// the positions.
+    pub fn encode(&self, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        let mut out = alloc::vec![0; self.positions.len()];
+        let mut rest = n;
+        for ((chars, base), slot) in self.positions.iter().zip(&mut out).rev() {
+            let d = (rest % *base).to_usize().expect("digits fit into usize");
+            *slot = chars[d];
+            rest = rest / *base;
+        }
+        if !rest.is_zero() {
+            return Err(AlphaIdError::Overflow);
+        }
+        Ok(out)
+    }
+
+    /// Decode into numbers.
+    ///
+    /// Returns `AlphaIdError::InvalidLength` if the input does not have
+    /// one symbol per position.
+    pub fn decode<V: AsRef<[u8]>>(&self, v: V) -> Result<T, AlphaIdError> {
+        let v = v.as_ref();
+        if v.len() != self.positions.len() {
+            return Err(AlphaIdError::InvalidLength);
+        }
+        let mut n = T::zero();
+        for ((&b, table), (_, base)) in v.iter().zip(&self.index).zip(&self.positions) {
+            let d = match table[b as usize] {
+                -1 => return Err(AlphaIdError::UnexpectedChar),
+                d => T::from_i16(d).ok_or(AlphaIdError::Overflow)?,
+            };
+            n = n
+                .checked_mul(base)
+                .and_then(|n| n.checked_add(&d))
+                .ok_or(AlphaIdError::Overflow)?;
+        }
+        Ok(n)
+    }
+}
+
+impl<T: UnsignedInteger> Codec<T> for MixedRadix<T> {
+    fn encode(&self, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        MixedRadix::encode(self, n)
+    }
+
+    fn decode(&self, v: &[u8]) -> Result<T, AlphaIdError> {
+        MixedRadix::decode(self, v)
+    }
+
+    fn max_len(&self) -> usize {
+        self.encoded_len()
+    }
+}
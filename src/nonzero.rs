@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// The non-zero counterparts of the unsigned integers, e.g. `NonZeroU64`
+/// for `u64`.
+pub trait NonZeroInteger: Copy {
+    type Int: UnsignedInteger;
+
+    /// Returns the value as the primitive type.
+    fn get(self) -> Self::Int;
+
+    /// Returns `None` if `n` is zero.
+    fn new(n: Self::Int) -> Option<Self>;
+}
+
+macro_rules! impl_non_zero {
+    ($($t:ty => $int:ty),*) => {
+        $(
+            impl NonZeroInteger for $t {
+                type Int = $int;
+
+                fn get(self) -> $int {
+                    <$t>::get(self)
+                }
+
+                fn new(n: $int) -> Option<Self> {
+                    <$t>::new(n)
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroUsize => usize,
+    NonZeroU128 => u128
+);
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode a non-zero number.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::num::NonZeroU32;
+    /// use alphaid::{AlphaId, AlphaIdError};
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let n = NonZeroU32::new(1350997667).unwrap();
+    /// assert_eq!(alphaid.encode_nonzero(n), Ok(b"90F7qb".to_vec()));
+    /// assert_eq!(alphaid.decode_nonzero(b"90F7qb"), Ok(n));
+    /// assert_eq!(alphaid.decode_nonzero::<NonZeroU32>(b"a"), Err(AlphaIdError::Zero));
+    /// ```
+    pub fn encode_nonzero<N>(&self, n: N) -> Result<Vec<u8>, AlphaIdError>
+    where
+        N: NonZeroInteger<Int = T>,
+    {
+        self.encode(n.get())
+    }
+
+    /// Decode into a non-zero number, returning `AlphaIdError::Zero` if the
+    /// input decodes to zero.
+    pub fn decode_nonzero<N>(&self, v: &[u8]) -> Result<N, AlphaIdError>
+    where
+        N: NonZeroInteger<Int = T>,
+    {
+        N::new(self.decode(v)?).ok_or(AlphaIdError::Zero)
+    }
+}
@@ -0,0 +1,9 @@
+/// A reversible transform of the numbers, see
+/// [`Builder::obfuscator`](crate::Builder::obfuscator).
+pub trait Obfuscator<T>: Send + Sync {
+    /// Transforms a number before it is encoded.
+    fn forward(&self, n: T) -> T;
+
+    /// Reverses [`forward`](Obfuscator::forward) after decoding.
+    fn backward(&self, n: T) -> T;
+}
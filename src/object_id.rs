@@ -0,0 +1,40 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError};
+
+impl<M> AlphaId<u128, M> {
+    /// Encode the 12 bytes of a MongoDB ObjectId, e.g. `ObjectId::bytes`,
+    /// as a big-endian number.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u128>::new();
+    /// let oid = [0x65, 0x0b, 0x2c, 0x1d, 0x9f, 0x3a, 0x4e, 0x12, 0x34, 0x56, 0x78, 0x9a];
+    /// let v = alphaid.encode_object_id(&oid).unwrap();
+    /// assert_eq!(v.len(), 16);
+    /// assert_eq!(alphaid.decode_object_id(&v), Ok(oid));
+    /// ```
+    pub fn encode_object_id(&self, oid: &[u8; 12]) -> Result<Vec<u8>, AlphaIdError> {
+        let mut bytes = [0; 16];
+        bytes[4..].copy_from_slice(oid);
+        self.encode(u128::from_be_bytes(bytes))
+    }
+
+    /// Decode the result of [`encode_object_id`](AlphaId::encode_object_id)
+    /// into the 12 bytes of an ObjectId.
+    ///
+    /// Returns `AlphaIdError::Overflow` if the number does not fit into 12
+    /// bytes.
+    pub fn decode_object_id<V: AsRef<[u8]>>(&self, v: V) -> Result<[u8; 12], AlphaIdError> {
+        let bytes = self.decode(v)?.to_be_bytes();
+        if bytes[..4] != [0; 4] {
+            return Err(AlphaIdError::Overflow);
+        }
+        let mut oid = [0; 12];
+        oid.copy_from_slice(&bytes[4..]);
+        Ok(oid)
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode an optional number, `None` as the symbol of
+    /// [`Builder::none_symbol`](crate::Builder::none_symbol).
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` for `None` if no symbol is set.
+    pub fn encode_opt(&self, n: Option<T>) -> Result<Vec<u8>, AlphaIdError> {
+        match n {
+            Some(n) => self.encode(n),
+            None => {
+                let none = self.none_symbol.ok_or(AlphaIdError::InvalidConfig)?;
+                let mut v = self.prefix.to_vec();
+                v.push(none);
+                v.extend_from_slice(&self.suffix);
+                Ok(v)
+            }
+        }
+    }
+
+    /// Decode the result of [`encode_opt`](AlphaId::encode_opt).
+    pub fn decode_opt(&self, v: &[u8]) -> Result<Option<T>, AlphaIdError> {
+        if let Some(none) = self.none_symbol {
+            let body = v
+                .strip_prefix(&self.prefix[..])
+                .and_then(|v| v.strip_suffix(&self.suffix[..]));
+            if body == Some(&[none][..]) {
+                return Ok(None);
+            }
+        }
+        self.decode(v).map(Some)
+    }
+}
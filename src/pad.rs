@@ -0,0 +1,46 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode the numbers with another [`pad`](crate::Builder::pad) than
+    /// the configured one, so that one instance can serve several minimum
+    /// lengths.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` if `pad` is zero. The pad is
+    /// ignored in sortable mode, like the one of the builder.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.encode_with_pad(1, 4), Ok(b"baab".to_vec()));
+    /// assert_eq!(alphaid.decode_with_pad(b"baab", 4), Ok(1));
+    /// assert_eq!(alphaid.encode(1), Ok(b"b".to_vec()));
+    /// ```
+    pub fn encode_with_pad(&self, n: T, pad: u32) -> Result<Vec<u8>, AlphaIdError> {
+        self.with_pad(pad)?.encode(n)
+    }
+
+    /// Decode the result of [`encode_with_pad`](AlphaId::encode_with_pad)
+    /// with the same pad.
+    pub fn decode_with_pad<V: AsRef<[u8]>>(&self, v: V, pad: u32) -> Result<T, AlphaIdError> {
+        self.with_pad(pad)?.decode(v)
+    }
+
+    /// Returns this instance with `pad`, sharing its tables.
+    fn with_pad(&self, pad: u32) -> Result<Cow<'_, Self>, AlphaIdError> {
+        if pad == 0 {
+            return Err(AlphaIdError::InvalidConfig);
+        }
+        if pad == self.pad || self.width.is_some() {
+            return Ok(Cow::Borrowed(self));
+        }
+        let mut alphaid = self.clone();
+        alphaid.pad = pad;
+        Ok(Cow::Owned(alphaid))
+    }
+}
@@ -0,0 +1,46 @@
+use rayon::prelude::*;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+impl<T: UnsignedInteger + Send + Sync, M> AlphaId<T, M> {
+    /// Encode a batch of numbers on the rayon thread pool, like
+    /// [`encode_batch`](AlphaId::encode_batch).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let ns: Vec<u32> = (0..10_000).collect();
+    /// let vs = alphaid.par_encode_batch(&ns).unwrap();
+    /// assert_eq!(vs, alphaid.encode_batch(&ns).unwrap());
+    /// ```
+    pub fn par_encode_batch(&self, ns: &[T]) -> Result<Vec<Vec<u8>>, AlphaIdError> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!(target: "alphaid", "par_encode_batch", len = ns.len()).entered();
+        ns.par_iter().map(|&n| self.encode(n)).collect()
+    }
+
+    /// Decode a batch of encoded results on the rayon thread pool, like
+    /// [`decode_batch`](AlphaId::decode_batch).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// assert_eq!(alphaid.par_decode_batch(&["a", "b", "90F7qb"]), Ok(vec![0, 1, 1350997667]));
+    /// ```
+    pub fn par_decode_batch<V: AsRef<[u8]> + Sync>(
+        &self,
+        vs: &[V],
+    ) -> Result<Vec<T>, AlphaIdError> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!(target: "alphaid", "par_decode_batch", len = vs.len()).entered();
+        vs.par_iter().map(|v| self.decode(v)).collect()
+    }
+}
@@ -0,0 +1,118 @@
+use alloc::vec::Vec;
+
+use crate::rng::SplitMix64;
+use crate::UnsignedInteger;
+
+/// The most digits of a number, those of `u128::MAX` in base 2.
+const MAX_DIGITS: usize = 128;
+
+/// The seeded permutations of [`Builder::permute_digits`](crate::Builder::permute_digits).
+///
+/// Every digit below the most significant one is mapped by the
+/// permutation of its position after adding the previous mapped digit,
+/// so that a change of one digit changes all digits above it. The most
+/// significant digit is mapped among the non-zero digits, which keeps the
+/// number of digits and makes the mapping a bijection on each length.
+///
+/// Numbers of the longest length only have their lower digits mapped,
+/// unless their leading digit is the one of `T::max_value()`, in which case
+/// the result could exceed it and they are left unchanged.
+pub(crate) struct DigitPermutation {
+    base: usize,
+    /// The permutation of `0..base` per position and its inverse.
+    lower: Vec<(Vec<u32>, Vec<u32>)>,
+    /// The permutation of `0..base - 1` per position and its inverse.
+    leading: Vec<(Vec<u32>, Vec<u32>)>,
+}
+
+impl DigitPermutation {
+    pub(crate) fn new(seed: u64, base: usize, positions: usize) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let mut permutation = |size: usize| {
+            let mut forward: Vec<u32> = (0..size as u32).collect();
+            for i in (1..size).rev() {
+                forward.swap(i, rng.below(i + 1));
+            }
+            let mut inverse = alloc::vec![0; size];
+            for (i, &p) in forward.iter().enumerate() {
+                inverse[p as usize] = i as u32;
+            }
+            (forward, inverse)
+        };
+        let mut lower = Vec::with_capacity(positions);
+        let mut leading = Vec::with_capacity(positions);
+        for _ in 0..positions {
+            lower.push(permutation(base));
+            leading.push(permutation(base - 1));
+        }
+        DigitPermutation {
+            base,
+            lower,
+            leading,
+        }
+    }
+
+    /// Maps the digits of `n`, `powers` are the powers of the base which
+    /// fit into `T`.
+    pub(crate) fn forward<T: UnsignedInteger>(&self, n: T, powers: &[T]) -> T {
+        self.map(n, powers, true)
+    }
+
+    /// Reverses [`forward`](DigitPermutation::forward).
+    pub(crate) fn backward<T: UnsignedInteger>(&self, n: T, powers: &[T]) -> T {
+        self.map(n, powers, false)
+    }
+
+    fn map<T: UnsignedInteger>(&self, n: T, powers: &[T], forward: bool) -> T {
+        if n.is_zero() {
+            return n;
+        }
+        let base = T::from_usize(self.base).expect("the base fits into T");
+        let mut digits = [0; MAX_DIGITS];
+        let mut len = 0;
+        let mut rest = n;
+        while !rest.is_zero() {
+            digits[len] = (rest % base).to_usize().expect("digits fit into usize");
+            rest = rest / base;
+            len += 1;
+        }
+
+        let longest = len == powers.len();
+        if longest {
+            let top = T::max_value() / powers[len - 1];
+            if T::from_usize(digits[len - 1]) == Some(top) {
+                return n;
+            }
+        }
+
+        let (b, lower_len) = (self.base, len - 1);
+        let mut carry = 0;
+        for (i, d) in digits[..lower_len].iter_mut().enumerate() {
+            let (perm, inverse) = &self.lower[i];
+            let mapped = if forward {
+                perm[(*d + carry) % b] as usize
+            } else {
+                (inverse[*d] as usize + b - carry) % b
+            };
+            carry = if forward { mapped } else { *d };
+            *d = mapped;
+        }
+        if !longest && b > 2 {
+            let (perm, inverse) = &self.leading[lower_len];
+            let d = &mut digits[lower_len];
+            let carry = carry % (b - 1);
+            *d = if forward {
+                perm[(*d - 1 + carry) % (b - 1)] as usize + 1
+            } else {
+                (inverse[*d - 1] as usize + (b - 1) - carry) % (b - 1) + 1
+            };
+        }
+
+        digits[..len]
+            .iter()
+            .zip(powers)
+            .fold(T::zero(), |n, (&d, &pow)| {
+                n + pow * T::from_usize(d).expect("digits fit into T")
+            })
+    }
+}
@@ -0,0 +1,137 @@
+use alloc::string::String;
+use sha2::{Digest, Sha256};
+
+use crate::AlphaIdError;
+
+/// The alphabet of the PHP function.
+const INDEX: &[u8; 62] = b"abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Reproduces the classic PHP `alphaID($in, $to_num, $pad_up, $pass_key)`
+/// function, to decode and issue IDs compatible with it.
+///
+/// The output is identical to PHP for numbers below 2^53, above that PHP
+/// loses precision. The number of digits is computed with a floating point
+/// logarithm like PHP does, which emits an extra `a` for a few numbers just
+/// below powers of 62.
+///
+/// Without a pass key and with the same alphabet, the results equal those
+/// of an [`AlphaId`](crate::AlphaId) with `$pad_up` as its
+/// [`pad`](crate::Builder::pad).
+///
+/// Unlike PHP, which decodes unknown characters as `a`, decoding returns
+/// `AlphaIdError::UnexpectedChar` for them.
+///
+/// Needs the `php` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::PhpAlphaId;
+///
+/// let php = PhpAlphaId::new().pad_up(8);
+/// assert_eq!(php.encode(2188847690240), Ok("SpQXn7Cb".to_string()));
+/// assert_eq!(php.decode("SpQXn7Cb"), Ok(2188847690240));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhpAlphaId {
+    index: [u8; 62],
+    pad_up: u32,
+}
+
+impl PhpAlphaId {
+    /// Creates a codec without `$pad_up` and `$pass_key`.
+    pub fn new() -> Self {
+        Self {
+            index: *INDEX,
+            pad_up: 0,
+        }
+    }
+
+    /// Shuffles the alphabet like `$pass_key` does.
+    ///
+    /// ```rust
+    /// use alphaid::PhpAlphaId;
+    ///
+    /// let php = PhpAlphaId::new().pass_key("secret").pad_up(4);
+    /// assert_eq!(php.encode(123456789), Ok("ShIYo".to_string()));
+    /// assert_eq!(php.decode("ShIYo"), Ok(123456789));
+    /// ```
+    pub fn pass_key(mut self, key: &str) -> Self {
+        // The first 62 hex digits of the SHA-256, sorted descending, with
+        // the alphabet sorted ascending for equal digits.
+        let hash = Sha256::digest(key.as_bytes());
+        let mut pairs = [(0, 0); 62];
+        for (n, pair) in pairs.iter_mut().enumerate() {
+            let nibble = (hash[n / 2] >> (4 * (1 - n % 2))) & 0xF;
+            *pair = (b"0123456789abcdef"[nibble as usize], INDEX[n]);
+        }
+        pairs.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        for (c, (_, i)) in self.index.iter_mut().zip(&pairs) {
+            *c = *i;
+        }
+        self
+    }
+
+    /// Pads like `$pad_up`: `pad_up - 1` is the minimum number of digits
+    /// after the first one.
+    pub fn pad_up(mut self, pad_up: u32) -> Self {
+        self.pad_up = pad_up;
+        self
+    }
+
+    /// Returns the amount added by `$pad_up`.
+    fn offset(&self) -> Result<u64, AlphaIdError> {
+        match self.pad_up {
+            0..=1 => Ok(0),
+            pad_up => 62u64.checked_pow(pad_up - 1).ok_or(AlphaIdError::Overflow),
+        }
+    }
+
+    /// Encodes like `alphaID($in, false, $pad_up, $pass_key)`.
+    pub fn encode(&self, n: u64) -> Result<String, AlphaIdError> {
+        let mut n = n
+            .checked_add(self.offset()?)
+            .ok_or(AlphaIdError::Overflow)?;
+        let t = if n == 0 {
+            0
+        } else {
+            ((n as f64).ln() / 62f64.ln()).floor() as u32
+        };
+
+        let mut out = String::new();
+        for t in (0..=t).rev() {
+            let bcp = 62u128.pow(t);
+            let a = (u128::from(n) / bcp % 62) as u64;
+            out.push(char::from(self.index[a as usize]));
+            n -= (u128::from(a) * bcp) as u64;
+        }
+        // PHP reverses the digits, least significant first.
+        Ok(out.chars().rev().collect())
+    }
+
+    /// Decodes like `alphaID($in, true, $pad_up, $pass_key)`.
+    ///
+    /// Returns `AlphaIdError::PadMissed` if the input is smaller than the
+    /// padding, where PHP would return a negative number.
+    pub fn decode(&self, s: &str) -> Result<u64, AlphaIdError> {
+        let mut n: u64 = 0;
+        for b in s.bytes().rev() {
+            let d = self
+                .index
+                .iter()
+                .position(|&c| c == b)
+                .ok_or(AlphaIdError::UnexpectedChar)?;
+            n = n
+                .checked_mul(62)
+                .and_then(|n| n.checked_add(d as u64))
+                .ok_or(AlphaIdError::Overflow)?;
+        }
+        n.checked_sub(self.offset()?).ok_or(AlphaIdError::PadMissed)
+    }
+}
+
+impl Default for PhpAlphaId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,65 @@
+//! The numeric stages between the numbers and their digits.
+//!
+//! Encoding runs the stages in order and decoding in reverse:
+//!
+//! 1. the bound of [`Builder::max_value`](crate::Builder::max_value),
+//! 2. the offset of [`Builder::offset`](crate::Builder::offset),
+//! 3. the obfuscators of [`Builder::obfuscator`](crate::Builder::obfuscator),
+//!    in the order they were added,
+//! 4. the digit permutation of
+//!    [`Builder::permute_digits`](crate::Builder::permute_digits),
+//! 5. the tag of [`Builder::tag`](crate::Builder::tag).
+//!
+//! The result is converted to digits, which are then padded, ordered,
+//! grouped and framed by the affixes. Encoders and decoders only call
+//! [`forward`](AlphaId::forward) and [`backward`](AlphaId::backward), so a
+//! new stage is added here instead of to each of them.
+
+use crate::{from_u128, to_u128, AlphaId, AlphaIdError, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Runs the stages on a number before it is converted to digits.
+    pub(crate) fn forward(&self, n: T) -> Result<T, AlphaIdError> {
+        self.check_max_value(n)?;
+        let n = self.offset(n, u128::checked_add)?;
+        let n = self.obfuscators.iter().fold(n, |n, o| o.forward(n));
+        let n = match &self.digit_permutation {
+            Some(p) => p.forward(n, &self.powers),
+            None => n,
+        };
+        self.tagged(n)
+    }
+
+    /// Reverses the stages on the number the digits were converted to.
+    pub(crate) fn backward(&self, n: T) -> Result<T, AlphaIdError> {
+        let n = self.untagged(n)?;
+        let n = match &self.digit_permutation {
+            Some(p) => p.backward(n, &self.powers),
+            None => n,
+        };
+        let n = self.obfuscators.iter().rev().fold(n, |n, o| o.backward(n));
+        let n = self.offset(n, u128::checked_sub)?;
+        self.check_max_value(n)?;
+        Ok(n)
+    }
+
+    /// Returns `AlphaIdError::Overflow` if `n` exceeds
+    /// [`Builder::max_value`](crate::Builder::max_value).
+    fn check_max_value(&self, n: T) -> Result<(), AlphaIdError> {
+        match self.max_value {
+            Some(max) if to_u128(n) > max => Err(AlphaIdError::Overflow),
+            _ => Ok(()),
+        }
+    }
+
+    /// Applies [`Builder::offset`](crate::Builder::offset) to `n` with `op`,
+    /// returning `AlphaIdError::Overflow` if the result is out of `T`.
+    fn offset(&self, n: T, op: fn(u128, u128) -> Option<u128>) -> Result<T, AlphaIdError> {
+        match self.offset {
+            Some(offset) => op(to_u128(n), offset)
+                .and_then(from_u128)
+                .ok_or(AlphaIdError::Overflow),
+            None => Ok(n),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Strategies for property tests with `proptest`, e.g. checking that IDs
+//! survive a round trip through an API layer wrapping this crate.
+//!
+//! # Example
+//!
+//! ```rust
+//! use alphaid::proptest::{any_alphaid, valid_encoding_for};
+//! use alphaid::AlphaId;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(alphaid in any_alphaid::<u64>(), n: u64)| {
+//!     if let Ok(v) = alphaid.encode(n) {
+//!         prop_assert_eq!(alphaid.decode(&v), Ok(n));
+//!     }
+//! });
+//!
+//! let alphaid = AlphaId::<u64>::new();
+//! proptest!(|(v in valid_encoding_for(&alphaid))| {
+//!     prop_assert!(alphaid.decode_strict(&v).is_ok());
+//! });
+//! ```
+use alloc::vec::Vec;
+
+use ::proptest::arbitrary::{any, Arbitrary};
+use ::proptest::collection::vec;
+use ::proptest::option;
+use ::proptest::sample::{select, subsequence};
+use ::proptest::strategy::Strategy;
+
+use crate::{AlphaId, Builder, Config, DigitOrder, PadMode, UnsignedInteger};
+
+/// Generates settings with an alphabet of 17 to 64 distinct printable
+/// characters, padding, digit orders, separators, affixes, signs, `None`
+/// symbols, tags and digit permutations.
+///
+/// Some settings do not build, e.g. when a tag does not fit into the
+/// number type; [`any_alphaid`] skips them.
+pub fn any_config() -> impl Strategy<Value = Config> {
+    let pool: Vec<u8> = (b'!'..=b'~').collect();
+    let pad_modes = alloc::vec![
+        PadMode::Shift,
+        PadMode::Fill,
+        #[cfg(feature = "rand")]
+        PadMode::Random,
+    ];
+
+    // Three characters more than the alphabet for the other symbols.
+    let symbols = subsequence(pool.clone(), 20..=67).prop_shuffle();
+    let layout = (
+        1..=12u32,
+        select(pad_modes),
+        select(alloc::vec![DigitOrder::BigEndian, DigitOrder::LittleEndian]),
+        option::of(1..=8usize),
+        vec(select(pool.clone()), 0..=4),
+        vec(select(pool), 0..=4),
+    );
+    let extras = (
+        any::<(bool, bool)>(),
+        option::of((any::<[u8; 16]>(), 1..=3u32)),
+        option::of(any::<u64>()),
+        any::<bool>(),
+    );
+    (symbols, layout, extras).prop_map(
+        |(mut chars, (pad, pad_mode, order, group, prefix, suffix), extras)| {
+            let ((sign, none), tag, digit_seed, reject_empty) = extras;
+            let none_symbol = chars.pop().filter(|_| none);
+            let sign = chars.pop().filter(|_| sign);
+            let group = chars.pop().and_then(|sep| group.map(|size| (size, sep)));
+            Config {
+                chars,
+                pad,
+                pad_mode,
+                order,
+                group,
+                prefix,
+                suffix,
+                tag,
+                sign,
+                none_symbol,
+                digit_seed,
+                reject_empty,
+                ..Config::default()
+            }
+        },
+    )
+}
+
+/// Generates an `AlphaId` from the settings of [`any_config`] which build
+/// for `T`.
+pub fn any_alphaid<T: UnsignedInteger>() -> impl Strategy<Value = AlphaId<T>> {
+    any_config().prop_filter_map("the settings do not build", |config| {
+        Builder::from_config(config).try_build_marked().ok()
+    })
+}
+
+/// Generates the encoded results of `alphaid`, shrinking towards the
+/// result of the smallest number.
+pub fn valid_encoding_for<T, M>(alphaid: &AlphaId<T, M>) -> impl Strategy<Value = Vec<u8>>
+where
+    T: UnsignedInteger + Arbitrary,
+    M: 'static,
+{
+    let alphaid = alphaid.clone();
+    any::<T>().prop_filter_map("the number can not be encoded", move |n| {
+        alphaid.encode(n).ok()
+    })
+}
@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+const CONSONANTS: &[u8; 16] = b"bdfghjklmnprstvz";
+const VOWELS: &[u8; 4] = b"aiou";
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode the numbers as a proquint: every 16 bits, most significant
+    /// first, become a pronounceable quintuplet of alternating consonants
+    /// and vowels, joined by `-`.
+    ///
+    /// As few quintuplets as needed are used, so a `u64` below 2^32 reads
+    /// like the proquint of a `u32`. Only the affixes of the configuration
+    /// are applied.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// // 127.0.0.1
+    /// let v = alphaid.encode_proquint(0x7F00_0001).unwrap();
+    /// assert_eq!(v, b"lusab-babad");
+    /// assert_eq!(alphaid.decode_proquint(&v), Ok(0x7F00_0001));
+    /// assert_eq!(alphaid.encode_proquint(0), Ok(b"babab".to_vec()));
+    /// ```
+    pub fn encode_proquint(&self, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        let mut groups = Vec::new();
+        match T::from_usize(1 << 16) {
+            Some(size) => {
+                let mut rest = n;
+                loop {
+                    groups.push((rest % size).to_usize().ok_or(AlphaIdError::Overflow)?);
+                    rest = rest / size;
+                    if rest.is_zero() {
+                        break;
+                    }
+                }
+            }
+            None => groups.push(n.to_usize().ok_or(AlphaIdError::Overflow)?),
+        }
+
+        let mut out = self.prefix.to_vec();
+        for (i, g) in groups.iter().rev().enumerate() {
+            if i > 0 {
+                out.push(b'-');
+            }
+            out.extend_from_slice(&[
+                CONSONANTS[g >> 12 & 0xF],
+                VOWELS[g >> 10 & 0x3],
+                CONSONANTS[g >> 6 & 0xF],
+                VOWELS[g >> 4 & 0x3],
+                CONSONANTS[g & 0xF],
+            ]);
+        }
+        out.extend_from_slice(&self.suffix);
+        Ok(out)
+    }
+
+    /// Decode the result of [`encode_proquint`](AlphaId::encode_proquint).
+    ///
+    /// Returns `AlphaIdError::InvalidLength` if a quintuplet does not have
+    /// five characters.
+    pub fn decode_proquint<V: AsRef<[u8]>>(&self, v: V) -> Result<T, AlphaIdError> {
+        let v = v.as_ref();
+        self.check_input_len(v.len())?;
+        let v = v
+            .strip_prefix(&self.prefix[..])
+            .ok_or(AlphaIdError::PrefixMissed)?;
+        let v = v
+            .strip_suffix(&self.suffix[..])
+            .ok_or(AlphaIdError::SuffixMissed)?;
+
+        let size = T::from_usize(1 << 16);
+        let mut n = T::zero();
+        for (i, quint) in v.split(|&b| b == b'-').enumerate() {
+            if quint.len() != 5 {
+                return Err(AlphaIdError::InvalidLength);
+            }
+            let mut g = 0;
+            for (j, &b) in quint.iter().enumerate() {
+                let (table, bits): (&[u8], _) = if j % 2 == 0 {
+                    (CONSONANTS, 4)
+                } else {
+                    (VOWELS, 2)
+                };
+                let d = table
+                    .iter()
+                    .position(|&c| c == b)
+                    .ok_or(AlphaIdError::UnexpectedChar)?;
+                g = g << bits | d;
+            }
+            let g = T::from_usize(g).ok_or(AlphaIdError::Overflow)?;
+            n = if i == 0 {
+                g
+            } else {
+                size.and_then(|size| n.checked_mul(&size))
+                    .and_then(|n| n.checked_add(&g))
+                    .ok_or(AlphaIdError::Overflow)?
+            };
+        }
+        Ok(n)
+    }
+}
@@ -0,0 +1,33 @@
+/// The characters of the QR code alphanumeric mode without the space, in
+/// the order of their QR values.
+pub const QR_ALPHANUMERIC: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ$%*+-./:";
+
+/// Returns the number of bits saved by storing `v` in a QR code in
+/// alphanumeric mode instead of byte mode, or `None` if `v` contains
+/// characters outside of the alphanumeric mode.
+///
+/// Alphanumeric mode takes 11 bits per pair of characters, byte mode 8 bits
+/// per character. The mode and length headers are not counted.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{qr_savings, AlphaId};
+///
+/// let alphaid = AlphaId::<u64>::builder().qr_alphanumeric().build();
+/// let v = alphaid.encode(u64::MAX).unwrap();
+/// assert_eq!(v.len(), 12);
+/// assert_eq!(qr_savings(&v), Some(96 - 66));
+///
+/// assert_eq!(qr_savings(b"lowercase"), None);
+/// ```
+pub fn qr_savings(v: &[u8]) -> Option<usize> {
+    if !v
+        .iter()
+        .all(|&b| b == b' ' || QR_ALPHANUMERIC.as_bytes().contains(&b))
+    {
+        return None;
+    }
+    let alphanumeric = v.len() / 2 * 11 + v.len() % 2 * 6;
+    Some(v.len() * 8 - alphanumeric)
+}
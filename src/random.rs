@@ -0,0 +1,234 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use rand_core::{CryptoRng, OsRng, RngCore};
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Draws a uniformly random value which is encoded to exactly `len`
+    /// symbols, returning it with its encoded result.
+    ///
+    /// The value is drawn from the operating system's random number
+    /// generator.
+    ///
+    /// Returns `AlphaIdError::InvalidLength` if no value is encoded to
+    /// `len` symbols, and with an [`obfuscator`](crate::Builder::obfuscator),
+    /// see [`max_value_for_len`](AlphaId::max_value_for_len).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u64>::new();
+    /// let (n, v) = alphaid.random(8).unwrap();
+    /// assert_eq!(v.len(), 8);
+    /// assert_eq!(alphaid.decode(&v), Ok(n));
+    /// ```
+    pub fn random(&self, len: usize) -> Result<(T, Vec<u8>), AlphaIdError> {
+        self.random_with(len, &mut OsRng)
+    }
+
+    /// Like [`random`](AlphaId::random), drawing the value from `rng`, e.g.
+    /// a seeded generator in tests or a hardware generator on an embedded
+    /// target.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    /// use rand_chacha::rand_core::SeedableRng;
+    /// use rand_chacha::ChaCha20Rng;
+    ///
+    /// let alphaid = AlphaId::<u64>::new();
+    /// let a = alphaid.random_with(8, &mut ChaCha20Rng::seed_from_u64(42)).unwrap();
+    /// let b = alphaid.random_with(8, &mut ChaCha20Rng::seed_from_u64(42)).unwrap();
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn random_with<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        len: usize,
+        rng: &mut R,
+    ) -> Result<(T, Vec<u8>), AlphaIdError> {
+        let hi = self
+            .max_value_for_len(len)
+            .ok_or(AlphaIdError::InvalidLength)?;
+        let lo = match len
+            .checked_sub(1)
+            .and_then(|len| self.max_value_for_len(len))
+        {
+            Some(n) if n == hi => return Err(AlphaIdError::InvalidLength),
+            Some(n) => n + T::one(),
+            None => T::zero(),
+        };
+
+        loop {
+            let n = uniform(rng, lo, hi);
+            // Blocklist replacements are longer.
+            let mut count = 0;
+            self.encode_digits(n, |_| count += 1)?;
+            if count == len {
+                return Ok((n, self.encode(n)?));
+            }
+        }
+    }
+
+    /// Draws a uniformly random value of `range`, returning it with its
+    /// encoded result.
+    ///
+    /// The value is drawn from the operating system's random number
+    /// generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let (n, v) = alphaid.random_in(1000..=2000).unwrap();
+    /// assert!((1000..=2000).contains(&n));
+    /// assert_eq!(alphaid.decode(&v), Ok(n));
+    /// ```
+    pub fn random_in(&self, range: RangeInclusive<T>) -> Result<(T, Vec<u8>), AlphaIdError> {
+        self.random_in_with(range, &mut OsRng)
+    }
+
+    /// Like [`random_in`](AlphaId::random_in), drawing the value from
+    /// `rng`.
+    pub fn random_in_with<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        range: RangeInclusive<T>,
+        rng: &mut R,
+    ) -> Result<(T, Vec<u8>), AlphaIdError> {
+        let (lo, hi) = range.into_inner();
+        if lo > hi {
+            return Err(AlphaIdError::InvalidNumber);
+        }
+        let n = uniform(rng, lo, hi);
+        Ok((n, self.encode(n)?))
+    }
+}
+
+/// Returns a uniformly random value of `lo..=hi`.
+fn uniform<T: UnsignedInteger, R: RngCore + CryptoRng + ?Sized>(rng: &mut R, lo: T, hi: T) -> T {
+    let span = hi - lo;
+    let mut bits = 0;
+    let mut rest = span;
+    while !rest.is_zero() {
+        rest = rest >> 1;
+        bits += 1;
+    }
+
+    // Rejection sampling of `bits` random bits.
+    loop {
+        let mut n = T::zero();
+        let mut left = bits;
+        while left > 0 {
+            let take = left.min(16);
+            let chunk = T::from_usize((rng.next_u64() & ((1 << take) - 1)) as usize)
+                .expect("primitive number types");
+            n = if left == bits {
+                chunk
+            } else {
+                (n << take) | chunk
+            };
+            left -= take;
+        }
+        if n <= span {
+            return lo + n;
+        }
+    }
+}
+
+/// Generates random IDs which are not taken yet, e.g. not in a database.
+///
+/// A random ID of the current length is drawn until `taken` returns
+/// `false` for it. After [`grow_after`](UniqueGen::grow_after) collisions
+/// in a row the length increases by one symbol, like nanoid does.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, UniqueGen};
+/// use std::cell::RefCell;
+/// use std::collections::HashSet;
+///
+/// let db = RefCell::new(HashSet::new());
+/// let gen = UniqueGen::new(AlphaId::<u64>::new(), 2, |v: &[u8]| db.borrow().contains(v));
+/// for _ in 0..100 {
+///     let (_, v) = gen.next_id().unwrap();
+///     assert!(db.borrow_mut().insert(v));
+/// }
+/// ```
+pub struct UniqueGen<F, T: UnsignedInteger = u64, M = ()> {
+    alphaid: AlphaId<T, M>,
+    taken: F,
+    len: usize,
+    grow_after: Option<u32>,
+    max_attempts: u32,
+}
+
+impl<F, T, M> UniqueGen<F, T, M>
+where
+    F: Fn(&[u8]) -> bool,
+    T: UnsignedInteger,
+{
+    /// Creates a generator of IDs of `len` symbols encoded with `alphaid`,
+    /// `taken` returns whether an encoded result is in use.
+    pub fn new(alphaid: AlphaId<T, M>, len: usize, taken: F) -> Self {
+        Self {
+            alphaid,
+            taken,
+            len,
+            grow_after: None,
+            max_attempts: 100,
+        }
+    }
+
+    /// Increases the length after `collisions` collisions in a row. Off by
+    /// default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if collisions is 0.
+    pub fn grow_after(mut self, collisions: u32) -> Self {
+        assert!(collisions > 0, "collisions must large than 0");
+        self.grow_after = Some(collisions);
+        self
+    }
+
+    /// Sets the number of IDs drawn before giving up. Default to 100.
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Returns a random value and its encoded result which is not taken.
+    ///
+    /// Returns `AlphaIdError::Exhausted` if every drawn ID was taken, and
+    /// `AlphaIdError::InvalidLength` if the length grows beyond `T`.
+    pub fn next_id(&self) -> Result<(T, Vec<u8>), AlphaIdError> {
+        self.next_id_with(&mut OsRng)
+    }
+
+    /// Like [`next_id`](UniqueGen::next_id), drawing the IDs from `rng`.
+    pub fn next_id_with<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<(T, Vec<u8>), AlphaIdError> {
+        let mut len = self.len;
+        let mut collisions = 0;
+        for _ in 0..self.max_attempts {
+            let (n, v) = self.alphaid.random_with(len, rng)?;
+            if !(self.taken)(&v) {
+                return Ok((n, v));
+            }
+            collisions += 1;
+            if self.grow_after == Some(collisions) {
+                len += 1;
+                collisions = 0;
+            }
+        }
+        Err(AlphaIdError::Exhausted)
+    }
+}
@@ -0,0 +1,291 @@
+use crate::{AlphaId, AlphaIdError, Config, UnsignedInteger};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Dispatches encoded IDs to the kind of entity registered for their prefix.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, PrefixRegistry};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum EntityKind {
+///     User,
+///     Order,
+/// }
+///
+/// let registry = PrefixRegistry::new(AlphaId::<u64>::new())
+///     .register(b"usr_", EntityKind::User)
+///     .register(b"ord_", EntityKind::Order);
+///
+/// assert_eq!(registry.encode(&EntityKind::Order, 12345), Ok(b"ord_Vad".to_vec()));
+/// assert_eq!(registry.decode(b"ord_Vad"), Ok((EntityKind::Order, 12345)));
+/// ```
+pub struct PrefixRegistry<K, T: UnsignedInteger = u128> {
+    alphaid: AlphaId<T>,
+    entries: Vec<(Vec<u8>, K)>,
+}
+
+impl<K: Clone + PartialEq, T: UnsignedInteger> PrefixRegistry<K, T> {
+    /// Creates an empty registry encoding and decoding with `alphaid`.
+    pub fn new(alphaid: AlphaId<T>) -> Self {
+        Self {
+            alphaid,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Registers the prefix of IDs for a kind of entity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix or the kind is already registered.
+    pub fn register(mut self, prefix: &[u8], kind: K) -> Self {
+        assert!(
+            self.entries.iter().all(|(p, k)| p != prefix && *k != kind),
+            "prefix and kind must be registered once"
+        );
+        self.entries.push((prefix.to_vec(), kind));
+        // Longer prefixes are matched first.
+        self.entries
+            .sort_by_key(|(p, _)| core::cmp::Reverse(p.len()));
+        self
+    }
+
+    /// Encodes the number with the prefix registered for `kind`.
+    ///
+    /// Returns `AlphaIdError::UnknownName` if the kind is not registered.
+    pub fn encode(&self, kind: &K, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        let (prefix, _) = self
+            .entries
+            .iter()
+            .find(|(_, k)| k == kind)
+            .ok_or(AlphaIdError::UnknownName)?;
+        let mut out = prefix.clone();
+        out.extend(self.alphaid.encode(n)?);
+        Ok(out)
+    }
+
+    /// Decodes into the kind registered for the prefix and the number.
+    ///
+    /// Returns `AlphaIdError::PrefixMissed` if no registered prefix matches.
+    pub fn decode<V: AsRef<[u8]>>(&self, v: V) -> Result<(K, T), AlphaIdError> {
+        let v = v.as_ref();
+        let (rest, kind) = self
+            .entries
+            .iter()
+            .find_map(|(prefix, kind)| v.strip_prefix(&prefix[..]).map(|rest| (rest, kind)))
+            .ok_or(AlphaIdError::PrefixMissed)?;
+        Ok((kind.clone(), self.alphaid.decode(rest)?))
+    }
+}
+
+/// Encodes and decodes with the `AlphaId` registered under a name, e.g.
+/// one configuration per tenant.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, AlphaIdError, AlphaIdRegistry};
+///
+/// let registry = AlphaIdRegistry::new()
+///     .register("tenant-a", AlphaId::<u64>::new())
+///     .register("tenant-b", AlphaId::<u64>::builder().pad(4).build());
+///
+/// assert_eq!(registry.encode("tenant-b", 1), Ok(b"baab".to_vec()));
+/// assert_eq!(registry.decode("tenant-b", b"baab"), Ok(1));
+/// assert_eq!(registry.encode("tenant-c", 1), Err(AlphaIdError::UnknownName));
+/// ```
+pub struct AlphaIdRegistry<T: UnsignedInteger = u128> {
+    entries: BTreeMap<String, AlphaId<T>>,
+}
+
+impl<T: UnsignedInteger> AlphaIdRegistry<T> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a registry from the settings of every name, e.g. loaded
+    /// from a configuration file.
+    ///
+    /// Returns the first name which is repeated or whose settings are
+    /// invalid, see [`AlphaId::try_from_config`].
+    pub fn from_configs<S, I>(configs: I) -> Result<Self, RegistryError>
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = (S, Config)>,
+    {
+        let mut registry = Self::new();
+        for (name, config) in configs {
+            let name = name.into();
+            if registry.entries.contains_key(&name) {
+                return Err(RegistryError::Duplicate(name));
+            }
+            match AlphaId::try_from_config(config) {
+                Ok(alphaid) => registry.entries.insert(name, alphaid),
+                Err(_) => return Err(RegistryError::InvalidConfig(name)),
+            };
+        }
+        Ok(registry)
+    }
+
+    /// Registers `alphaid` under `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name is already registered.
+    pub fn register<S: Into<String>>(mut self, name: S, alphaid: AlphaId<T>) -> Self {
+        let name = name.into();
+        assert!(
+            !self.entries.contains_key(&name),
+            "name must be registered once"
+        );
+        self.entries.insert(name, alphaid);
+        self
+    }
+
+    /// Returns the `AlphaId` registered under `name`.
+    pub fn get(&self, name: &str) -> Option<&AlphaId<T>> {
+        self.entries.get(name)
+    }
+
+    /// Returns the registered names in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Encodes the number with the `AlphaId` registered under `name`.
+    ///
+    /// Returns `AlphaIdError::UnknownName` if the name is not registered.
+    pub fn encode(&self, name: &str, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        self.get(name).ok_or(AlphaIdError::UnknownName)?.encode(n)
+    }
+
+    /// Decodes with the `AlphaId` registered under `name`.
+    ///
+    /// Returns `AlphaIdError::UnknownName` if the name is not registered.
+    pub fn decode<V: AsRef<[u8]>>(&self, name: &str, v: V) -> Result<T, AlphaIdError> {
+        self.get(name).ok_or(AlphaIdError::UnknownName)?.decode(v)
+    }
+}
+
+impl<T: UnsignedInteger> Default for AlphaIdRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The error of [`AlphaIdRegistry::from_configs`], naming the entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    /// The name is repeated.
+    Duplicate(String),
+    /// The settings of the name are invalid.
+    InvalidConfig(String),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::Duplicate(name) => write!(f, "name {:?} is repeated", name),
+            RegistryError::InvalidConfig(name) => {
+                write!(f, "invalid configuration of name {:?}", name)
+            }
+        }
+    }
+}
+
+impl core::error::Error for RegistryError {}
+
+/// Encodes with the current version of the settings and decodes IDs of any
+/// registered version, so that alphabets can be rotated without breaking
+/// old IDs.
+///
+/// Encoded results start with the version symbol, followed by the result
+/// of the `AlphaId` registered for that version.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, VersionedDecoder};
+///
+/// let ids = VersionedDecoder::new()
+///     .register(b'1', AlphaId::<u64>::new())
+///     .register(b'2', AlphaId::<u64>::builder().pad(4).build());
+///
+/// assert_eq!(ids.encode(1), Ok(b"2baab".to_vec()));
+/// assert_eq!(ids.decode(b"2baab"), Ok((b'2', 1)));
+/// assert_eq!(ids.decode(b"1b"), Ok((b'1', 1)));
+/// ```
+pub struct VersionedDecoder<T: UnsignedInteger = u128> {
+    /// The versions in registration order, the last one is current.
+    versions: Vec<(u8, AlphaId<T>)>,
+}
+
+impl<T: UnsignedInteger> VersionedDecoder<T> {
+    /// Creates a decoder without any version.
+    pub fn new() -> Self {
+        Self {
+            versions: Vec::new(),
+        }
+    }
+
+    /// Registers `alphaid` for the IDs starting with `version`, which
+    /// becomes the current version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the version is already registered.
+    pub fn register(mut self, version: u8, alphaid: AlphaId<T>) -> Self {
+        assert!(
+            self.versions.iter().all(|(v, _)| *v != version),
+            "version must be registered once"
+        );
+        self.versions.push((version, alphaid));
+        self
+    }
+
+    /// Returns the current version.
+    pub fn current(&self) -> Option<u8> {
+        self.versions.last().map(|(v, _)| *v)
+    }
+
+    /// Encodes the number with the current version.
+    ///
+    /// Returns `AlphaIdError::UnknownVersion` if no version is registered.
+    pub fn encode(&self, n: T) -> Result<Vec<u8>, AlphaIdError> {
+        let (version, alphaid) = self.versions.last().ok_or(AlphaIdError::UnknownVersion)?;
+        let mut out = alloc::vec![*version];
+        alphaid.encode_into(n, &mut out)?;
+        Ok(out)
+    }
+
+    /// Decodes into the version and the number.
+    ///
+    /// Returns `AlphaIdError::UnknownVersion` if the version is not
+    /// registered.
+    pub fn decode<V: AsRef<[u8]>>(&self, v: V) -> Result<(u8, T), AlphaIdError> {
+        let (&version, rest) = v
+            .as_ref()
+            .split_first()
+            .ok_or(AlphaIdError::UnknownVersion)?;
+        let (_, alphaid) = self
+            .versions
+            .iter()
+            .find(|(v, _)| *v == version)
+            .ok_or(AlphaIdError::UnknownVersion)?;
+        Ok((version, alphaid.decode(rest)?))
+    }
+}
+
+impl<T: UnsignedInteger> Default for VersionedDecoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(all(feature = "std", not(feature = "rand")))]
+use std::collections::hash_map::RandomState;
+#[cfg(all(feature = "std", not(feature = "rand")))]
+use std::hash::{BuildHasher, Hasher};
+#[cfg(all(feature = "std", not(feature = "rand")))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A small, fast, non-cryptographic pseudo random number generator.
+///
+/// Only used where the numbers need not be unpredictable, e.g. the seeded
+/// shuffles of the alphabet and the digit permutations. Its output is easy
+/// to predict from a few samples, never draw tokens from it; `random` and
+/// `UniqueGen` use the operating system's generator instead.
+#[derive(Debug, Clone)]
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Creates a generator starting at `seed`.
+    pub(crate) fn new(seed: u64) -> Self {
+        SplitMix64(seed)
+    }
+
+    /// Seeds a new generator from the process' hash keys and the clock.
+    #[cfg(all(feature = "std", not(feature = "rand")))]
+    pub(crate) fn from_entropy() -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        hasher.write_u128(nanos);
+        SplitMix64(hasher.finish())
+    }
+
+    /// Returns the next number.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly distributed number in `0..n`.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        let n = n as u64;
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let v = self.next_u64();
+            if v < zone {
+                return (v % n) as usize;
+            }
+        }
+    }
+}
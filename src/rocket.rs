@@ -0,0 +1,56 @@
+//! Decodes an [`EncodedId`] in the guards of Rocket routes, so that handlers
+//! take the typed ID instead of decoding a string.
+use ::rocket::form::{self, FromFormField, ValueField};
+use ::rocket::request::FromParam;
+
+use crate::{AlphaIdError, EncodedId, Encoding};
+
+/// Decodes a dynamic path segment.
+///
+/// Rocket forwards an invalid ID to the next route, responding with
+/// `422 Unprocessable Entity` if none matches. Take a
+/// `Result<EncodedId<E>, AlphaIdError>` to handle it in the route instead.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::OnceLock;
+/// # use alphaid::{AlphaId, Encoding};
+/// # struct Users;
+/// # impl Encoding for Users {
+/// #     type Int = u64;
+/// #     fn alphaid() -> &'static AlphaId<u64> {
+/// #         static ALPHAID: OnceLock<AlphaId<u64>> = OnceLock::new();
+/// #         ALPHAID.get_or_init(AlphaId::new)
+/// #     }
+/// # }
+/// use alphaid::EncodedId;
+/// use rocket::get;
+///
+/// #[get("/users/<id>")]
+/// fn user(id: EncodedId<Users>) -> String {
+///     format!("user {}", id.value())
+/// }
+/// # fn main() {}
+/// ```
+impl<E: Encoding> FromParam<'_> for EncodedId<E> {
+    type Error = AlphaIdError;
+
+    fn from_param(param: &str) -> Result<Self, Self::Error> {
+        param.parse()
+    }
+}
+
+/// Decodes a form field or a query parameter, failing the form with the
+/// `AlphaIdError` as a validation error.
+impl<'v, E: Encoding> FromFormField<'v> for EncodedId<E>
+where
+    E::Int: Send,
+{
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        field
+            .value
+            .parse()
+            .map_err(|e: AlphaIdError| form::Error::validation(e.to_string()).into())
+    }
+}
@@ -0,0 +1,90 @@
+use ::rug::{Assign, Integer};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// The largest power of `base` which fits into a `u64` and its exponent,
+/// so that GMP divides out that many digits at once.
+fn chunk(base: u64) -> (u64, usize) {
+    let (mut power, mut width) = (base, 1);
+    while let Some(p) = power.checked_mul(base) {
+        power = p;
+        width += 1;
+    }
+    (power, width)
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode a non-negative `rug::Integer`, e.g. of thousands of bits.
+    ///
+    /// Like [`encode_dec_str`](AlphaId::encode_dec_str) only the alphabet,
+    /// the digit order, the group separator and the affixes of the
+    /// configuration are applied.
+    ///
+    /// Returns `AlphaIdError::InvalidNumber` for negative numbers.
+    ///
+    /// Needs the `rug` feature, which links the GMP library installed on
+    /// the system.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    /// use rug::Integer;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let n = Integer::from(1) << 128;
+    /// let v = alphaid.encode_integer(&n).unwrap();
+    /// assert_eq!(v, "aaaaaaaaaaaaaaaaaaaaae");
+    /// assert_eq!(alphaid.decode_integer(&v), Ok(n));
+    /// ```
+    pub fn encode_integer(&self, n: &Integer) -> Result<String, AlphaIdError> {
+        if n.cmp0() == Ordering::Less {
+            return Err(AlphaIdError::InvalidNumber);
+        }
+        let base = self.base() as u64;
+        let (power, width) = chunk(base);
+
+        // The digits, least significant first.
+        let mut digits = Vec::new();
+        let mut rest = n.clone();
+        let mut rem = Integer::new();
+        loop {
+            rem.assign(power);
+            rest.div_rem_mut(&mut rem);
+            let mut r = rem.to_u64_wrapping();
+            if rest.cmp0() == Ordering::Equal {
+                // The most significant chunk, without leading zeros.
+                loop {
+                    digits.push((r % base) as usize);
+                    r /= base;
+                    if r == 0 {
+                        break;
+                    }
+                }
+                break;
+            }
+            for _ in 0..width {
+                digits.push((r % base) as usize);
+                r /= base;
+            }
+        }
+        self.wide_to_string(digits)
+    }
+
+    /// Decode the result of [`encode_integer`](AlphaId::encode_integer).
+    pub fn decode_integer(&self, s: &str) -> Result<Integer, AlphaIdError> {
+        let base = self.base() as u64;
+        let (_, width) = chunk(base);
+
+        let mut n = Integer::new();
+        for digits in self.wide_digits(s)?.chunks(width) {
+            let value = digits.iter().fold(0, |v, &d| v * base + d as u64);
+            n *= base.pow(digits.len() as u32);
+            n += value;
+        }
+        Ok(n)
+    }
+}
@@ -0,0 +1,128 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError};
+
+/// Packs named fields of a fixed number of bits into one number, e.g. a
+/// shard, a kind of entity and a sequence.
+///
+/// The first field takes the most significant bits.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, Schema};
+///
+/// let schema = Schema::new().field("shard", 8).field("kind", 4).field("seq", 52);
+/// let alphaid = AlphaId::<u128>::new();
+///
+/// let v = schema
+///     .encode(&alphaid, &[("shard", 3), ("kind", 2), ("seq", 1000)])
+///     .unwrap();
+/// let fields = schema.decode(&alphaid, &v).unwrap();
+/// assert_eq!(fields["shard"], 3);
+/// assert_eq!(fields["kind"], 2);
+/// assert_eq!(fields["seq"], 1000);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    /// The names and bits of the fields, most significant first.
+    fields: Vec<(String, u32)>,
+    bits: u32,
+}
+
+impl Schema {
+    /// Creates a schema without fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a field of `bits` bits below the previous fields.
+    ///
+    /// # Panics
+    ///
+    /// Panics if bits is 0 or more than 64, if the fields take more than
+    /// 128 bits, or if the name is already used.
+    pub fn field(mut self, name: &str, bits: u32) -> Self {
+        assert!(bits > 0 && bits <= 64, "bits must be between 1 and 64");
+        assert!(self.bits + bits <= 128, "fields must fit into 128 bits");
+        assert!(
+            self.fields.iter().all(|(n, _)| n != name),
+            "field names must be unique"
+        );
+        self.fields.push((name.into(), bits));
+        self.bits += bits;
+        self
+    }
+
+    /// Returns the number of bits taken by all fields.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Packs the values of the fields into one number, fields without a
+    /// value are 0.
+    ///
+    /// Returns `AlphaIdError::UnknownName` for values of unknown fields and
+    /// `AlphaIdError::InvalidNumber` for values which do not fit into their
+    /// field.
+    pub fn pack(&self, values: &[(&str, u64)]) -> Result<u128, AlphaIdError> {
+        let mut n = 0u128;
+        for &(ref name, bits) in &self.fields {
+            let value = values
+                .iter()
+                .find(|(n, _)| n == name)
+                .map_or(0, |&(_, value)| value);
+            if bits < 64 && value >> bits != 0 {
+                return Err(AlphaIdError::InvalidNumber);
+            }
+            n = n << bits | u128::from(value);
+        }
+        if values
+            .iter()
+            .any(|(name, _)| self.fields.iter().all(|(n, _)| n != name))
+        {
+            return Err(AlphaIdError::UnknownName);
+        }
+        Ok(n)
+    }
+
+    /// Unpacks a number into the values of the fields.
+    ///
+    /// Returns `AlphaIdError::Overflow` if the number takes more bits than
+    /// the fields.
+    pub fn unpack(&self, n: u128) -> Result<BTreeMap<String, u64>, AlphaIdError> {
+        if self.bits < 128 && n >> self.bits != 0 {
+            return Err(AlphaIdError::Overflow);
+        }
+        let mut shift = self.bits;
+        Ok(self
+            .fields
+            .iter()
+            .map(|(name, bits)| {
+                shift -= bits;
+                let mask = u64::MAX >> (64 - bits);
+                (name.clone(), (n >> shift) as u64 & mask)
+            })
+            .collect())
+    }
+
+    /// Packs the values of the fields and encodes the number.
+    pub fn encode<M>(
+        &self,
+        alphaid: &AlphaId<u128, M>,
+        values: &[(&str, u64)],
+    ) -> Result<Vec<u8>, AlphaIdError> {
+        alphaid.encode(self.pack(values)?)
+    }
+
+    /// Decodes the number and unpacks it into the values of the fields.
+    pub fn decode<M, V: AsRef<[u8]>>(
+        &self,
+        alphaid: &AlphaId<u128, M>,
+        v: V,
+    ) -> Result<BTreeMap<String, u64>, AlphaIdError> {
+        self.unpack(alphaid.decode(v)?)
+    }
+}
@@ -0,0 +1,68 @@
+//! Describes an [`EncodedId`] as a string in JSON Schemas generated by
+//! `schemars`.
+use alloc::borrow::Cow;
+use alloc::format;
+
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::json_schema::StringSchema;
+use crate::{EncodedId, Encoding};
+
+/// A `string` with the pattern of the alphabet and the affixes, and the
+/// lengths of the shortest and the longest encoded result.
+///
+/// The schema is named after the `Encoding`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::OnceLock;
+/// # use alphaid::{AlphaId, Encoding};
+/// # struct Users;
+/// # impl Encoding for Users {
+/// #     type Int = u32;
+/// #     fn alphaid() -> &'static AlphaId<u32> {
+/// #         static ALPHAID: OnceLock<AlphaId<u32>> = OnceLock::new();
+/// #         ALPHAID.get_or_init(|| {
+/// #             AlphaId::builder()
+/// #                 .chars(b"0123456789abcdefghijklmnopqrstuv".to_vec())
+/// #                 .prefix(b"u_")
+/// #                 .build()
+/// #         })
+/// #     }
+/// # }
+/// use alphaid::EncodedId;
+///
+/// let schema = schemars::schema_for!(EncodedId<Users>);
+/// assert_eq!(schema.get("type").unwrap(), "string");
+/// assert_eq!(schema.get("pattern").unwrap(), "^u_[0123456789abcdefghijklmnopqrstuv]+$");
+/// assert_eq!(schema.get("minLength").unwrap(), 3);
+/// assert_eq!(schema.get("maxLength").unwrap(), 9);
+/// ```
+impl<E: Encoding> JsonSchema for EncodedId<E> {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed(StringSchema::of::<E>().name)
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "alphaid::EncodedId<{}>",
+            core::any::type_name::<E>()
+        ))
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let s = StringSchema::of::<E>();
+        let mut schema = json_schema!({
+            "type": "string",
+            "pattern": s.pattern,
+        });
+        if let Some(min) = s.min_length {
+            schema.insert("minLength".into(), min.into());
+        }
+        if let Some(max) = s.max_length {
+            schema.insert("maxLength".into(), max.into());
+        }
+        schema
+    }
+}
@@ -0,0 +1,162 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{from_u64, AlphaId, AlphaIdError, UnsignedInteger};
+
+/// Generates encoded IDs from an in-process counter.
+///
+/// The counter starts at 0 and increases by 1 by default. A
+/// `SequentialGen` is `Send` and `Sync`, it can be shared between threads
+/// and never returns the same number twice.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, SequentialGen};
+///
+/// let gen = SequentialGen::new(AlphaId::<u64>::new()).start(1000).step(10);
+/// assert_eq!(gen.next_raw(), Ok(1000));
+/// assert_eq!(gen.next_id(), Ok(b"Op".to_vec()));
+/// ```
+pub struct SequentialGen<T: UnsignedInteger = u64, M = ()> {
+    alphaid: AlphaId<T, M>,
+    next: AtomicU64,
+    step: u64,
+}
+
+impl<T: UnsignedInteger, M> SequentialGen<T, M> {
+    /// Creates a generator encoding with `alphaid`.
+    pub fn new(alphaid: AlphaId<T, M>) -> Self {
+        Self {
+            alphaid,
+            next: AtomicU64::new(0),
+            step: 1,
+        }
+    }
+
+    /// Sets the first number.
+    pub fn start(self, start: u64) -> Self {
+        self.next.store(start, Ordering::Relaxed);
+        self
+    }
+
+    /// Sets the difference between two numbers, e.g. to interleave the
+    /// numbers of several generators.
+    ///
+    /// # Panics
+    ///
+    /// Panics if step is 0.
+    pub fn step(mut self, step: u64) -> Self {
+        assert!(step > 0, "step must large than 0");
+        self.step = step;
+        self
+    }
+
+    /// Returns the next number.
+    ///
+    /// Returns `AlphaIdError::Overflow` once the numbers exceed `u64` or
+    /// `T`.
+    pub fn next_raw(&self) -> Result<T, AlphaIdError> {
+        let n = self
+            .next
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                n.checked_add(self.step)
+            })
+            .map_err(|_| AlphaIdError::Overflow)?;
+        from_u64(n).ok_or(AlphaIdError::Overflow)
+    }
+
+    /// Returns the next encoded ID.
+    pub fn next_id(&self) -> Result<Vec<u8>, AlphaIdError> {
+        self.alphaid.encode(self.next_raw()?)
+    }
+}
+
+/// A counter which numbers are issued from, e.g. a Redis `INCR`, a
+/// database sequence or a file.
+///
+/// Implementations must never return the same number twice, also across
+/// processes sharing the counter, and may block.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, SequenceSource, SourceGen};
+/// use std::sync::atomic::AtomicU64;
+///
+/// let gen = SourceGen::new(AtomicU64::new(1), AlphaId::<u64>::new());
+/// assert_eq!(gen.next_id(), Ok(b"b".to_vec()));
+/// assert_eq!(gen.source().next(), Ok(2));
+/// ```
+pub trait SequenceSource {
+    /// The error of the counter, e.g. a connection error.
+    type Error;
+
+    /// Returns the next number.
+    fn next(&self) -> Result<u64, Self::Error>;
+}
+
+/// Counts up from the current value, returning `AlphaIdError::Overflow`
+/// after `u64::MAX`.
+impl SequenceSource for AtomicU64 {
+    type Error = AlphaIdError;
+
+    fn next(&self) -> Result<u64, AlphaIdError> {
+        self.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_add(1))
+            .map_err(|_| AlphaIdError::Overflow)
+    }
+}
+
+/// The error of [`SourceGen`].
+#[derive(Debug, PartialEq)]
+pub enum SourceError<E> {
+    /// The counter failed.
+    Source(E),
+    /// The number could not be encoded.
+    Encode(AlphaIdError),
+}
+
+impl<E: fmt::Display> fmt::Display for SourceError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SourceError::Source(e) => write!(f, "sequence source failed: {}", e),
+            SourceError::Encode(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for SourceError<E> {}
+
+/// Generates encoded IDs from the numbers of a [`SequenceSource`].
+pub struct SourceGen<S, T: UnsignedInteger = u64, M = ()> {
+    source: S,
+    alphaid: AlphaId<T, M>,
+}
+
+impl<S: SequenceSource, T: UnsignedInteger, M> SourceGen<S, T, M> {
+    /// Creates a generator encoding the numbers of `source` with `alphaid`.
+    pub fn new(source: S, alphaid: AlphaId<T, M>) -> Self {
+        Self { source, alphaid }
+    }
+
+    /// Returns the source of the numbers.
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Returns the next number.
+    ///
+    /// Returns `SourceError::Encode(AlphaIdError::Overflow)` if the number
+    /// does not fit into `T`.
+    pub fn next_raw(&self) -> Result<T, SourceError<S::Error>> {
+        let n = self.source.next().map_err(SourceError::Source)?;
+        from_u64(n).ok_or(SourceError::Encode(AlphaIdError::Overflow))
+    }
+
+    /// Returns the next encoded ID.
+    pub fn next_id(&self) -> Result<Vec<u8>, SourceError<S::Error>> {
+        let n = self.next_raw()?;
+        self.alphaid.encode(n).map_err(SourceError::Encode)
+    }
+}
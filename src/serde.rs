@@ -0,0 +1,205 @@
+//! Helper modules for `#[serde(with = "...")]`, keeping a number field in
+//! memory but (de)serializing it as its encoded string with the default
+//! settings.
+//!
+//! # Example
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct User {
+//!     #[serde(with = "alphaid::serde::u64")]
+//!     id: u64,
+//! }
+//!
+//! let json = serde_json::to_string(&User { id: 1350997667 }).unwrap();
+//! assert_eq!(json, r#"{"id":"90F7qb"}"#);
+//! let user: User = serde_json::from_str(&json).unwrap();
+//! assert_eq!(user.id, 1350997667);
+//! ```
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::{AlphaId, AlphaIdError, Builder, Config, EncodedId, Encoding, UnsignedInteger};
+
+/// Decodes a string with the default settings into a `T`.
+struct DefaultVisitor<T>(PhantomData<T>);
+
+impl<T: TryFrom<u128>> Visitor<'_> for DefaultVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an encoded ID")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        let n = crate::decode(v).map_err(E::custom)?;
+        T::try_from(n).map_err(|_| E::custom(AlphaIdError::Overflow))
+    }
+}
+
+macro_rules! helpers {
+    ($($t:ident),*) => {$(
+        #[doc = concat!("(De)serializes a `", stringify!($t), "` as its encoded string.")]
+        pub mod $t {
+            use super::*;
+
+            /// Serializes the encoded string of the number.
+            pub fn serialize<S: Serializer>(
+                n: &core::primitive::$t,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&crate::encode(*n))
+            }
+
+            /// Deserializes an encoded string into the number.
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<core::primitive::$t, D::Error> {
+                deserializer.deserialize_str(DefaultVisitor(PhantomData))
+            }
+        }
+    )*};
+}
+
+helpers!(u8, u16, u32, u64, u128);
+
+/// (De)serializes any unsigned number as its encoded string, but also
+/// accepts the number itself, e.g. while clients migrate from numeric IDs.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     #[serde(with = "alphaid::serde::lenient")]
+///     id: u64,
+/// }
+///
+/// let user: User = serde_json::from_str(r#"{"id":1350997667}"#).unwrap();
+/// assert_eq!(user.id, 1350997667);
+/// let user: User = serde_json::from_str(r#"{"id":"90F7qb"}"#).unwrap();
+/// assert_eq!(user.id, 1350997667);
+/// assert_eq!(serde_json::to_string(&user).unwrap(), r#"{"id":"90F7qb"}"#);
+/// ```
+pub mod lenient {
+    use super::*;
+
+    /// Decodes a string with the default settings or takes a non-negative
+    /// number as is.
+    struct LenientVisitor<T>(PhantomData<T>);
+
+    impl<T: TryFrom<u128>> LenientVisitor<T> {
+        fn number<E: de::Error>(n: u128) -> Result<T, E> {
+            T::try_from(n).map_err(|_| E::custom(AlphaIdError::Overflow))
+        }
+    }
+
+    impl<T: TryFrom<u128>> Visitor<'_> for LenientVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an encoded ID or an unsigned number")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+            DefaultVisitor(PhantomData).visit_str(v)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+            Self::number(v.into())
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<T, E> {
+            Self::number(v)
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+            let n =
+                u64::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))?;
+            Self::number(n.into())
+        }
+    }
+
+    /// Serializes the encoded string of the number.
+    pub fn serialize<T, S>(n: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Copy + Into<u128>,
+        S: Serializer,
+    {
+        serializer.serialize_str(&crate::encode(*n))
+    }
+
+    /// Deserializes an encoded string or a number.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<u128>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(LenientVisitor(PhantomData))
+    }
+}
+
+/// Serializes the encoded string.
+impl<E: Encoding> Serialize for EncodedId<E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserializes an encoded string, accepting only the exact result of
+/// [`EncodedId::new`] like its `FromStr` implementation.
+impl<'de, E: Encoding> Deserialize<'de> for EncodedId<E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EncodedVisitor<E>(PhantomData<E>);
+
+        impl<E: Encoding> Visitor<'_> for EncodedVisitor<E> {
+            type Value = EncodedId<E>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an encoded ID")
+            }
+
+            fn visit_str<Err: de::Error>(self, v: &str) -> Result<EncodedId<E>, Err> {
+                v.parse().map_err(Err::custom)
+            }
+        }
+
+        deserializer.deserialize_str(EncodedVisitor(PhantomData))
+    }
+}
+
+/// Serializes the settings, see [`AlphaId::to_config`]. They include the
+/// tag keys, if any.
+impl<T: UnsignedInteger, M> Serialize for AlphaId<T, M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_config().serialize(serializer)
+    }
+}
+
+/// Deserializes the settings, see [`AlphaId::try_from_config`]. Invalid
+/// settings are an error instead of a panic.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::AlphaId;
+///
+/// let alphaid: AlphaId<u32> = serde_json::from_str(r#"{"pad":5}"#).unwrap();
+/// assert_eq!(alphaid.encode(0), Ok(b"aaaab".to_vec()));
+/// assert!(serde_json::from_str::<AlphaId<u32>>(r#"{"pad":0}"#).is_err());
+/// ```
+impl<'de, T: UnsignedInteger> Deserialize<'de> for AlphaId<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Builder::from_config(Config::deserialize(deserializer)?)
+            .try_build_marked()
+            .map_err(de::Error::custom)
+    }
+}
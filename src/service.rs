@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+use core::fmt;
+use tokio::sync::{mpsc, oneshot};
+
+/// The number of requests which can wait for the task of an [`IdService`].
+const CAPACITY: usize = 1024;
+
+type Request<E> = oneshot::Sender<Result<Vec<u8>, E>>;
+
+/// Hands out encoded IDs from a generator owned by a background task.
+///
+/// Cloning is cheap, all clones share the same task. The task stops once
+/// every clone is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use alphaid::{AlphaId, IdService, SequentialGen};
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let gen = SequentialGen::new(AlphaId::<u64>::new()).start(1000);
+/// let service = IdService::spawn(move || gen.next_id());
+///
+/// let handle = service.clone();
+/// assert_eq!(handle.next().await, Ok(b"Ep".to_vec()));
+/// assert_eq!(service.next().await, Ok(b"Fp".to_vec()));
+/// # });
+/// ```
+pub struct IdService<E> {
+    requests: mpsc::Sender<Request<E>>,
+}
+
+impl<E: Send + 'static> IdService<E> {
+    /// Spawns a task calling `next` for every requested ID.
+    ///
+    /// `next` runs on the task and must not block, see
+    /// [`spawn_blocking`](IdService::spawn_blocking).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a Tokio runtime.
+    pub fn spawn<F>(mut next: F) -> Self
+    where
+        F: FnMut() -> Result<Vec<u8>, E> + Send + 'static,
+    {
+        let (requests, mut rx) = mpsc::channel::<Request<E>>(CAPACITY);
+        tokio::spawn(async move {
+            while let Some(reply) = rx.recv().await {
+                // The caller may have stopped waiting.
+                let _ = reply.send(next());
+            }
+        });
+        Self { requests }
+    }
+
+    /// Like [`spawn`](IdService::spawn), calling `next` on a thread where
+    /// blocking is acceptable, e.g. for a [`SourceGen`](crate::SourceGen)
+    /// counting in a database.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a Tokio runtime.
+    pub fn spawn_blocking<F>(mut next: F) -> Self
+    where
+        F: FnMut() -> Result<Vec<u8>, E> + Send + 'static,
+    {
+        let (requests, mut rx) = mpsc::channel::<Request<E>>(CAPACITY);
+        tokio::task::spawn_blocking(move || {
+            while let Some(reply) = rx.blocking_recv() {
+                let _ = reply.send(next());
+            }
+        });
+        Self { requests }
+    }
+
+    /// Returns the next encoded ID.
+    ///
+    /// Returns `ServiceError::Stopped` if the task has stopped, e.g.
+    /// because the generator panicked.
+    pub async fn next(&self) -> Result<Vec<u8>, ServiceError<E>> {
+        let (reply, rx) = oneshot::channel();
+        self.requests
+            .send(reply)
+            .await
+            .map_err(|_| ServiceError::Stopped)?;
+        rx.await
+            .map_err(|_| ServiceError::Stopped)?
+            .map_err(ServiceError::Generator)
+    }
+}
+
+impl<E> Clone for IdService<E> {
+    fn clone(&self) -> Self {
+        Self {
+            requests: self.requests.clone(),
+        }
+    }
+}
+
+impl<E> fmt::Debug for IdService<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IdService").finish_non_exhaustive()
+    }
+}
+
+/// The error of [`IdService::next`].
+#[derive(Debug, PartialEq)]
+pub enum ServiceError<E> {
+    /// The task of the service has stopped.
+    Stopped,
+    /// The generator failed.
+    Generator(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ServiceError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServiceError::Stopped => f.write_str("ID service stopped"),
+            ServiceError::Generator(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for ServiceError<E> {}
@@ -0,0 +1,137 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode a set of numbers into one compact result, e.g. a selection of
+    /// IDs passed through a URL.
+    ///
+    /// `items` is sorted in place and duplicates are skipped. The first
+    /// number and the gaps between the following ones are written as
+    /// varints: the lower half of the alphabet ends a number and the upper
+    /// half continues it, so dense sets take a symbol or two per number.
+    /// Only the alphabet, the separators and the affixes of this `AlphaId`
+    /// are applied, the padding, digit order, blocklist and the stages of
+    /// [`Builder::obfuscator`](crate::Builder::obfuscator) and alike are
+    /// not, so the numbers are not hidden.
+    ///
+    /// Returns `AlphaIdError::InvalidConfig` if the alphabet has less than
+    /// 4 symbols.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let mut ids = [1350997667, 1350997670, 1350997668, 1350997668];
+    /// let v = alphaid.encode_set(&mut ids).unwrap();
+    /// assert_eq!(v, b"9RBJEEbab".to_vec());
+    /// assert_eq!(alphaid.decode_set(&v), Ok(vec![1350997667, 1350997668, 1350997670]));
+    /// ```
+    pub fn encode_set(&self, items: &mut [T]) -> Result<Vec<u8>, AlphaIdError> {
+        let radix = self.set_radix()?;
+        items.sort_unstable();
+
+        let mut digits = Vec::new();
+        let mut prev: Option<T> = None;
+        for &n in items.iter() {
+            let mut gap = match prev {
+                Some(p) if p == n => continue,
+                Some(p) => n - p - T::one(),
+                None => n,
+            };
+            prev = Some(n);
+            while gap >= radix {
+                digits.push(self.set_digit(gap % radix) + self.set_digit(radix));
+                gap = gap / radix;
+            }
+            digits.push(self.set_digit(gap));
+        }
+
+        let mut out = self.prefix.to_vec();
+        for (count, &d) in digits.iter().enumerate() {
+            if let Some(sep) = self.separator_before(count) {
+                out.push(sep);
+            }
+            if self.symbols.is_empty() {
+                out.push(self.chars[d]);
+            } else {
+                out.extend_from_slice(self.symbols[d].as_bytes());
+            }
+        }
+        out.extend_from_slice(&self.suffix);
+        Ok(out)
+    }
+
+    /// Decode the result of [`encode_set`](AlphaId::encode_set) into the
+    /// sorted numbers.
+    ///
+    /// Returns `AlphaIdError::InvalidLength` if the input ends within a
+    /// number, and `AlphaIdError::UnexpectedChar` for a symbol outside of
+    /// the alphabet or a number with needless continuation symbols, so
+    /// that every set has a single encoding.
+    pub fn decode_set<V: AsRef<[u8]>>(&self, v: V) -> Result<Vec<T>, AlphaIdError> {
+        let radix = self.set_radix()?;
+        let half = self.set_digit(radix);
+        let v = v
+            .as_ref()
+            .strip_prefix(&self.prefix[..])
+            .ok_or(AlphaIdError::PrefixMissed)?;
+        let v = v
+            .strip_suffix(&self.suffix[..])
+            .ok_or(AlphaIdError::SuffixMissed)?;
+        let digits = self.tokenize(v).ok_or(AlphaIdError::UnexpectedChar)?;
+
+        let mut items = Vec::new();
+        let mut gap = T::zero();
+        let mut scale = Some(T::one());
+        for &d in &digits {
+            if d >= 2 * half {
+                return Err(AlphaIdError::UnexpectedChar);
+            }
+            let last = d < half;
+            let digit = T::from_usize(d % half).ok_or(AlphaIdError::Overflow)?;
+            if last && digit.is_zero() && scale != Some(T::one()) {
+                return Err(AlphaIdError::UnexpectedChar);
+            }
+            if !digit.is_zero() {
+                gap = scale
+                    .and_then(|scale| scale.checked_mul(&digit))
+                    .and_then(|add| gap.checked_add(&add))
+                    .ok_or(AlphaIdError::Overflow)?;
+            }
+            if !last {
+                scale = scale.and_then(|scale| scale.checked_mul(&radix));
+                continue;
+            }
+
+            let n = match items.last() {
+                Some(&prev) => T::checked_add(&prev, &T::one())
+                    .and_then(|next| next.checked_add(&gap))
+                    .ok_or(AlphaIdError::Overflow)?,
+                None => gap,
+            };
+            items.push(n);
+            gap = T::zero();
+            scale = Some(T::one());
+        }
+        if scale != Some(T::one()) {
+            return Err(AlphaIdError::InvalidLength);
+        }
+        Ok(items)
+    }
+
+    /// Returns the radix of the varints of `encode_set`, half of the base.
+    fn set_radix(&self) -> Result<T, AlphaIdError> {
+        let half = self.base / (T::one() + T::one());
+        if half < T::one() + T::one() {
+            return Err(AlphaIdError::InvalidConfig);
+        }
+        Ok(half)
+    }
+
+    fn set_digit(&self, d: T) -> usize {
+        d.to_usize().expect("digits are less than the base")
+    }
+}
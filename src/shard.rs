@@ -0,0 +1,56 @@
+use crate::{to_u128, AlphaId, AlphaIdError, UnsignedInteger};
+
+/// The jump consistent hash of Lamping and Veach, mapping `key` to one of
+/// `buckets` buckets.
+fn jump_hash(mut key: u64, buckets: u32) -> u32 {
+    let (mut b, mut j) = (-1i64, 0i64);
+    while j < i64::from(buckets) {
+        b = j;
+        key = key.wrapping_mul(2_862_933_555_777_941_757).wrapping_add(1);
+        j = ((b + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+    }
+    b as u32
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Returns the shard of `n` out of `shards` shards.
+    ///
+    /// The shard is the jump consistent hash of Lamping and Veach of the
+    /// number, folded to 64 bits by XOR of its halves. It does not depend
+    /// on the settings, and adding a shard only moves numbers to the new
+    /// shard.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u64>::new();
+    /// let v = alphaid.encode(1350997667).unwrap();
+    /// assert_eq!(alphaid.shard_for_encoded(&v, 16), Ok(alphaid.shard_for(1350997667, 16)));
+    /// assert_eq!(alphaid.shard_for(1350997667, 1), 0);
+    /// ```
+    pub fn shard_for(&self, n: T, shards: u32) -> u32 {
+        assert!(shards > 0, "shards must not be zero");
+        let n = to_u128(n);
+        jump_hash((n ^ n >> 64) as u64, shards)
+    }
+
+    /// Decodes `v` and returns the shard of the number, see
+    /// [`shard_for`](AlphaId::shard_for).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards` is zero.
+    pub fn shard_for_encoded<V: AsRef<[u8]>>(
+        &self,
+        v: V,
+        shards: u32,
+    ) -> Result<u32, AlphaIdError> {
+        Ok(self.shard_for(self.decode(v)?, shards))
+    }
+}
@@ -0,0 +1,56 @@
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use crate::{from_u128, to_u128, AlphaId, AlphaIdError, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode a signed number as the sign symbol of
+    /// [`Builder::sign`](crate::Builder::sign) followed by the encoded
+    /// magnitude. Positive numbers are encoded like
+    /// [`encode`](AlphaId::encode).
+    ///
+    /// Returns `AlphaIdError::InvalidNumber` for negative numbers if no
+    /// sign is set, and `AlphaIdError::Overflow` if the magnitude does not
+    /// fit into `T`.
+    pub fn encode_signed<N: Into<i128>>(&self, n: N) -> Result<Vec<u8>, AlphaIdError> {
+        let n = n.into();
+        let magnitude = from_u128(n.unsigned_abs()).ok_or(AlphaIdError::Overflow)?;
+        let mut v = self.encode(magnitude)?;
+        if n < 0 {
+            let sign = self.sign.ok_or(AlphaIdError::InvalidNumber)?;
+            v.insert(self.prefix.len(), sign);
+            self.check_len(v.len())?;
+        }
+        Ok(v)
+    }
+
+    /// Decode the result of [`encode_signed`](AlphaId::encode_signed).
+    ///
+    /// The sign is only accepted right after the prefix. Returns
+    /// `AlphaIdError::NonCanonical` for a negative zero and
+    /// `AlphaIdError::Overflow` if the number does not fit into `N`.
+    pub fn decode_signed<N: TryFrom<i128>>(&self, v: &[u8]) -> Result<N, AlphaIdError> {
+        let negative = match self.sign {
+            Some(sign) => v.get(self.prefix.len()) == Some(&sign) && v.starts_with(&self.prefix),
+            None => false,
+        };
+        let magnitude = if negative {
+            let mut unsigned = v.to_vec();
+            unsigned.remove(self.prefix.len());
+            to_u128(self.decode(unsigned)?)
+        } else {
+            to_u128(self.decode(v)?)
+        };
+        if negative && magnitude == 0 {
+            return Err(AlphaIdError::NonCanonical);
+        }
+
+        let n = if negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+        n.and_then(|n| N::try_from(n).ok())
+            .ok_or(AlphaIdError::Overflow)
+    }
+}
@@ -0,0 +1,254 @@
+use alloc::vec::Vec;
+
+/// The alphabet as runs of consecutive bytes with consecutive digit values,
+/// which lets whole chunks of input be translated with SIMD instructions:
+/// AVX2 when the CPU supports it and SSE2 otherwise on x86_64, NEON on
+/// aarch64. Other targets use the scalar lookup.
+pub(crate) struct Ranges {
+    /// `(first byte, length, first digit)` of every run.
+    runs: Vec<(u8, u8, u8)>,
+}
+
+/// More runs make the SIMD path slower than the scalar lookup.
+const MAX_RUNS: usize = 8;
+
+/// Inputs shorter than a SIMD register are translated by the scalar path.
+pub(crate) const CHUNK: usize = 16;
+
+/// Returns whether the AVX2 path can run. Without `std` the CPU cannot be
+/// queried, so only a build with AVX2 enabled uses it.
+#[cfg(target_arch = "x86_64")]
+fn has_avx2() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+impl Ranges {
+    /// Returns `None` if the alphabet is not made of a few runs.
+    pub(crate) fn new(chars: &[u8]) -> Option<Self> {
+        if chars.is_empty() || chars.len() > 256 {
+            return None;
+        }
+
+        let mut runs: Vec<(u8, u8, u8)> = Vec::new();
+        for (digit, &b) in chars.iter().enumerate() {
+            match runs.last_mut() {
+                Some((first, len, first_digit))
+                    if *len < u8::MAX
+                        && first.checked_add(*len) == Some(b)
+                        && first_digit.checked_add(*len).map(usize::from) == Some(digit) =>
+                {
+                    *len += 1
+                }
+                _ => runs.push((b, 1, digit as u8)),
+            }
+            if runs.len() > MAX_RUNS {
+                return None;
+            }
+        }
+        Some(Ranges { runs })
+    }
+
+    /// Translates every byte of `input` to its digit value, appending them
+    /// to `out`. Returns `false` if a byte is not in the alphabet.
+    pub(crate) fn translate(&self, input: &[u8], out: &mut Vec<u8>) -> bool {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if has_avx2() {
+                // SAFETY: AVX2 is supported by this CPU.
+                return unsafe { self.translate_avx2(input, out) };
+            }
+        }
+        self.translate_baseline(input, out)
+    }
+
+    /// The path which every CPU of the target supports.
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    fn translate_baseline(&self, input: &[u8], out: &mut Vec<u8>) -> bool {
+        // SAFETY: SSE2 is enabled at compile time, as it is by default.
+        unsafe { self.translate_sse2(input, out) }
+    }
+
+    /// The path which every CPU of the target supports.
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    fn translate_baseline(&self, input: &[u8], out: &mut Vec<u8>) -> bool {
+        // SAFETY: NEON is enabled at compile time, as it is by default.
+        unsafe { self.translate_neon(input, out) }
+    }
+
+    /// The path which every CPU of the target supports.
+    #[cfg(not(any(
+        all(target_arch = "x86_64", target_feature = "sse2"),
+        all(target_arch = "aarch64", target_feature = "neon")
+    )))]
+    fn translate_baseline(&self, input: &[u8], out: &mut Vec<u8>) -> bool {
+        self.translate_scalar(input, out)
+    }
+
+    fn translate_scalar(&self, input: &[u8], out: &mut Vec<u8>) -> bool {
+        for &b in input {
+            let digit = self.runs.iter().find_map(|&(first, len, digit)| {
+                let t = b.wrapping_sub(first);
+                if t < len {
+                    Some(digit + t)
+                } else {
+                    None
+                }
+            });
+            match digit {
+                Some(digit) => out.push(digit),
+                None => return false,
+            }
+        }
+        true
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    unsafe fn translate_avx2(&self, input: &[u8], out: &mut Vec<u8>) -> bool {
+        use core::arch::x86_64::*;
+
+        let mut chunks = input.chunks_exact(2 * CHUNK);
+        for chunk in &mut chunks {
+            let x = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+            let mut matched = _mm256_setzero_si256();
+            let mut digits = _mm256_setzero_si256();
+            for &(first, len, digit) in &self.runs {
+                // Offsets into the run, the bytes before it wrap around.
+                let t = _mm256_sub_epi8(x, _mm256_set1_epi8(first as i8));
+                let last = _mm256_set1_epi8((len - 1) as i8);
+                let hit = _mm256_cmpeq_epi8(_mm256_min_epu8(t, last), t);
+                let value = _mm256_add_epi8(t, _mm256_set1_epi8(digit as i8));
+                matched = _mm256_or_si256(matched, hit);
+                digits = _mm256_or_si256(digits, _mm256_and_si256(hit, value));
+            }
+            if _mm256_movemask_epi8(matched) != -1 {
+                return false;
+            }
+
+            let mut buf = [0u8; 2 * CHUNK];
+            _mm256_storeu_si256(buf.as_mut_ptr() as *mut __m256i, digits);
+            out.extend_from_slice(&buf);
+        }
+        self.translate_scalar(chunks.remainder(), out)
+    }
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    #[target_feature(enable = "sse2")]
+    unsafe fn translate_sse2(&self, input: &[u8], out: &mut Vec<u8>) -> bool {
+        use core::arch::x86_64::*;
+
+        let mut chunks = input.chunks_exact(CHUNK);
+        for chunk in &mut chunks {
+            let x = _mm_loadu_si128(chunk.as_ptr() as *const __m128i);
+            let mut matched = _mm_setzero_si128();
+            let mut digits = _mm_setzero_si128();
+            for &(first, len, digit) in &self.runs {
+                // Offsets into the run, the bytes before it wrap around.
+                let t = _mm_sub_epi8(x, _mm_set1_epi8(first as i8));
+                let last = _mm_set1_epi8((len - 1) as i8);
+                let hit = _mm_cmpeq_epi8(_mm_min_epu8(t, last), t);
+                let value = _mm_add_epi8(t, _mm_set1_epi8(digit as i8));
+                matched = _mm_or_si128(matched, hit);
+                digits = _mm_or_si128(digits, _mm_and_si128(hit, value));
+            }
+            if _mm_movemask_epi8(matched) != 0xFFFF {
+                return false;
+            }
+
+            let mut buf = [0u8; CHUNK];
+            _mm_storeu_si128(buf.as_mut_ptr() as *mut __m128i, digits);
+            out.extend_from_slice(&buf);
+        }
+        self.translate_scalar(chunks.remainder(), out)
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    #[target_feature(enable = "neon")]
+    unsafe fn translate_neon(&self, input: &[u8], out: &mut Vec<u8>) -> bool {
+        use core::arch::aarch64::*;
+
+        let mut chunks = input.chunks_exact(CHUNK);
+        for chunk in &mut chunks {
+            let x = vld1q_u8(chunk.as_ptr());
+            let mut matched = vdupq_n_u8(0);
+            let mut digits = vdupq_n_u8(0);
+            for &(first, len, digit) in &self.runs {
+                // Offsets into the run, the bytes before it wrap around.
+                let t = vsubq_u8(x, vdupq_n_u8(first));
+                let hit = vcleq_u8(t, vdupq_n_u8(len - 1));
+                let value = vaddq_u8(t, vdupq_n_u8(digit));
+                matched = vorrq_u8(matched, hit);
+                digits = vorrq_u8(digits, vandq_u8(hit, value));
+            }
+            if vminvq_u8(matched) != 0xFF {
+                return false;
+            }
+
+            let mut buf = [0u8; CHUNK];
+            vst1q_u8(buf.as_mut_ptr(), digits);
+            out.extend_from_slice(&buf);
+        }
+        self.translate_scalar(chunks.remainder(), out)
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use alloc::vec::Vec;
+
+    use super::Ranges;
+
+    /// Translates `input` with every path this CPU supports, the scalar one
+    /// first, for testing them against each other. `None` is returned for
+    /// an alphabet which is not made of a few runs, and in place of the
+    /// digits of an input with a byte not in the alphabet.
+    #[allow(clippy::type_complexity)]
+    pub fn translate_paths(
+        chars: &[u8],
+        input: &[u8],
+    ) -> Option<Vec<(&'static str, Option<Vec<u8>>)>> {
+        let ranges = Ranges::new(chars)?;
+        let run = |f: &dyn Fn(&mut Vec<u8>) -> bool| {
+            let mut out = Vec::new();
+            if f(&mut out) {
+                Some(out)
+            } else {
+                None
+            }
+        };
+
+        let mut paths = Vec::new();
+        paths.push(("scalar", run(&|out| ranges.translate_scalar(input, out))));
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        paths.push((
+            "sse2",
+            // SAFETY: SSE2 is enabled at compile time.
+            run(&|out| unsafe { ranges.translate_sse2(input, out) }),
+        ));
+        #[cfg(target_arch = "x86_64")]
+        {
+            if super::has_avx2() {
+                paths.push((
+                    "avx2",
+                    // SAFETY: AVX2 is supported by this CPU.
+                    run(&|out| unsafe { ranges.translate_avx2(input, out) }),
+                ));
+            }
+        }
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        paths.push((
+            "neon",
+            // SAFETY: NEON is enabled at compile time.
+            run(&|out| unsafe { ranges.translate_neon(input, out) }),
+        ));
+        paths.push(("dispatch", run(&|out| ranges.translate(input, out))));
+        Some(paths)
+    }
+}
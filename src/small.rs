@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// The number of bytes stored without allocating.
+const INLINE: usize = 24;
+
+/// The bytes of an encoded result, stored inline up to 24 bytes.
+///
+/// Returned by [`AlphaId::encode_small`], derefs to `[u8]`.
+#[derive(Clone)]
+pub struct SmallBytes(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Inline { buf: [u8; INLINE], len: u8 },
+    Heap(Vec<u8>),
+}
+
+impl SmallBytes {
+    /// Returns `true` if the bytes are stored on the heap.
+    pub fn spilled(&self) -> bool {
+        matches!(self.0, Repr::Heap(_))
+    }
+
+    /// Converts into a `Vec`.
+    pub fn into_vec(self) -> Vec<u8> {
+        match self.0 {
+            Repr::Inline { .. } => self.to_vec(),
+            Repr::Heap(v) => v,
+        }
+    }
+}
+
+impl Deref for SmallBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match &self.0 {
+            Repr::Inline { buf, len } => &buf[..*len as usize],
+            Repr::Heap(v) => v,
+        }
+    }
+}
+
+impl AsRef<[u8]> for SmallBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl PartialEq for SmallBytes {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for SmallBytes {}
+
+impl fmt::Debug for SmallBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode the numbers without allocating if the result is at most 24
+    /// bytes long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u128>::new();
+    /// let v = alphaid.encode_small(1350997667).unwrap();
+    /// assert_eq!(&v[..], b"90F7qb");
+    /// assert!(!v.spilled());
+    /// ```
+    pub fn encode_small(&self, n: T) -> Result<SmallBytes, AlphaIdError> {
+        let mut buf = [0; INLINE];
+        match self.encode_to_slice(n, &mut buf) {
+            Ok(len) => Ok(SmallBytes(Repr::Inline {
+                buf,
+                len: len as u8,
+            })),
+            Err(AlphaIdError::BufferTooSmall) => self.encode(n).map(|v| SmallBytes(Repr::Heap(v))),
+            Err(e) => Err(e),
+        }
+    }
+}
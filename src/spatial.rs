@@ -0,0 +1,54 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError};
+
+impl<M> AlphaId<u64, M> {
+    /// Encode a pair of coordinates as their Morton code, which interleaves
+    /// the bits of `x` and `y`.
+    ///
+    /// Nearby coordinates get close numbers, so with
+    /// `DigitOrder::BigEndian` their encoded results share prefixes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, DigitOrder};
+    ///
+    /// let alphaid = AlphaId::<u64>::builder().order(DigitOrder::BigEndian).build();
+    /// let v = alphaid.encode_morton(1234, 5678).unwrap();
+    /// assert_eq!(alphaid.decode_morton(&v), Ok((1234, 5678)));
+    ///
+    /// let near = alphaid.encode_morton(1235, 5678).unwrap();
+    /// assert_eq!(v[..v.len() - 1], near[..near.len() - 1]);
+    /// ```
+    pub fn encode_morton(&self, x: u32, y: u32) -> Result<Vec<u8>, AlphaIdError> {
+        self.encode(spread(x) | spread(y) << 1)
+    }
+
+    /// Decode the result of [`encode_morton`](AlphaId::encode_morton) into
+    /// the coordinates.
+    pub fn decode_morton<V: AsRef<[u8]>>(&self, v: V) -> Result<(u32, u32), AlphaIdError> {
+        let n = self.decode(v)?;
+        Ok((compact(n), compact(n >> 1)))
+    }
+}
+
+/// Moves the bits of `n` to the even bits.
+fn spread(n: u32) -> u64 {
+    let mut n = u64::from(n);
+    n = (n | n << 16) & 0x0000_FFFF_0000_FFFF;
+    n = (n | n << 8) & 0x00FF_00FF_00FF_00FF;
+    n = (n | n << 4) & 0x0F0F_0F0F_0F0F_0F0F;
+    n = (n | n << 2) & 0x3333_3333_3333_3333;
+    (n | n << 1) & 0x5555_5555_5555_5555
+}
+
+/// Gathers the even bits of `n`, the inverse of `spread`.
+fn compact(n: u64) -> u32 {
+    let mut n = n & 0x5555_5555_5555_5555;
+    n = (n | n >> 1) & 0x3333_3333_3333_3333;
+    n = (n | n >> 2) & 0x0F0F_0F0F_0F0F_0F0F;
+    n = (n | n >> 4) & 0x00FF_00FF_00FF_00FF;
+    n = (n | n >> 8) & 0x0000_FFFF_0000_FFFF;
+    (n | n >> 16) as u32
+}
@@ -0,0 +1,55 @@
+//! Stores an [`EncodedId`] as its number in a `BIGINT` column of any sqlx
+//! database, e.g. Postgres, MySQL or SQLite.
+use core::convert::TryFrom;
+
+use ::sqlx::decode::Decode;
+use ::sqlx::encode::{Encode, IsNull};
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Type};
+
+use crate::{from_u128, to_u128, AlphaIdError, EncodedId, Encoding};
+
+/// An `EncodedId` has the type of an `i64`.
+impl<DB: Database, E: Encoding> Type<DB> for EncodedId<E>
+where
+    i64: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <i64 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <i64 as Type<DB>>::compatible(ty)
+    }
+}
+
+/// Binds the number, failing with `AlphaIdError::Overflow` beyond
+/// `i64::MAX`.
+impl<'q, DB: Database, E: Encoding> Encode<'q, DB> for EncodedId<E>
+where
+    i64: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let n = i64::try_from(to_u128(self.value())).map_err(|_| AlphaIdError::Overflow)?;
+        Encode::<DB>::encode_by_ref(&n, buf)
+    }
+}
+
+/// Encodes the number, failing with `AlphaIdError::Overflow` for negative
+/// numbers and numbers which do not fit into the `Encoding`.
+impl<'r, DB: Database, E: Encoding> Decode<'r, DB> for EncodedId<E>
+where
+    i64: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let n = <i64 as Decode<DB>>::decode(value)?;
+        let n = u128::try_from(n)
+            .ok()
+            .and_then(from_u128)
+            .ok_or(AlphaIdError::Overflow)?;
+        Ok(EncodedId::new(n)?)
+    }
+}
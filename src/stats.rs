@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use crate::{to_u128, AlphaId, UnsignedInteger};
+
+/// The size of the ID space of an `AlphaId`, see [`AlphaId::stats`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Stats {
+    /// The bits of information per symbol, the binary logarithm of the
+    /// base.
+    pub bits_per_symbol: f64,
+    /// Every length in symbols which values are encoded to, shortest first.
+    pub lengths: Vec<LengthStats>,
+}
+
+/// The values encoded to one length in symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LengthStats {
+    pub len: usize,
+    /// The number of values of this length, saturating at `u128::MAX`.
+    pub ids: u128,
+    /// The number of values of this length in the range passed to
+    /// [`AlphaId::stats`], saturating at `u128::MAX`.
+    pub in_range: u128,
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Returns the bits per symbol, the number of values per length and how
+    /// the values of `range` are distributed over the lengths.
+    ///
+    /// Lengths count symbols like [`len_for`](AlphaId::len_for), without
+    /// affixes, separators and blocklist replacements. There are none with
+    /// an [`obfuscator`](crate::Builder::obfuscator), see
+    /// [`max_value_for_len`](AlphaId::max_value_for_len).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let stats = alphaid.stats(0..=9999);
+    /// assert_eq!(stats.bits_per_symbol, 6.0);
+    /// assert_eq!(stats.lengths[0].ids, 64);
+    /// assert_eq!(stats.lengths[0].in_range, 64);
+    /// assert_eq!(stats.lengths[1].ids, 64 * 64 - 64);
+    /// assert_eq!(stats.lengths[2].in_range, 10000 - 64 * 64);
+    /// assert_eq!(stats.lengths.len(), 6);
+    /// ```
+    pub fn stats(&self, range: RangeInclusive<T>) -> Stats {
+        let (lo, hi) = range.into_inner();
+        let count = |first: T, last: T| to_u128(last - first).saturating_add(1);
+
+        let mut lengths = Vec::new();
+        // The largest value of the shorter lengths.
+        let mut prev: Option<T> = None;
+        for len in 1..=self.max_encoded_len() {
+            let top = match self.max_value_for_len(len) {
+                Some(top) if Some(top) != prev => top,
+                _ => continue,
+            };
+            let first = prev.map_or_else(T::zero, |p| p + T::one());
+            let (start, end) = (first.max(lo), top.min(hi));
+            lengths.push(LengthStats {
+                len,
+                ids: count(first, top),
+                in_range: if start <= end { count(start, end) } else { 0 },
+            });
+            if top == T::max_value() {
+                break;
+            }
+            prev = Some(top);
+        }
+
+        Stats {
+            bits_per_symbol: (self.base() as f64).log2(),
+            lengths,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::io;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+impl From<AlphaIdError> for io::Error {
+    fn from(e: AlphaIdError) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, e)
+    }
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode the numbers into an [`io::Write`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let mut out = Vec::new();
+    /// alphaid.encode_writer(1350997667, &mut out).unwrap();
+    /// assert_eq!(out, b"90F7qb");
+    /// ```
+    pub fn encode_writer<W: io::Write + ?Sized>(&self, n: T, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.encode(n)?)
+    }
+
+    /// Read and decode one encoded result terminated by `delimiter` or the
+    /// end of `r`.
+    ///
+    /// The delimiter is consumed but nothing after it is read, so `r` can
+    /// be used for the next result. Returns `Ok(None)` at the end of `r`.
+    /// Decoding errors and results longer than any encoded result are
+    /// reported as `io::ErrorKind::InvalidData`.
+    ///
+    /// `r` is read byte by byte, wrap it in a `BufReader` if reads are
+    /// expensive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let mut r = &b"b 90F7qb"[..];
+    /// assert_eq!(alphaid.decode_reader(&mut r, b' ').unwrap(), Some(1));
+    /// assert_eq!(alphaid.decode_reader(&mut r, b' ').unwrap(), Some(1350997667));
+    /// assert_eq!(alphaid.decode_reader(&mut r, b' ').unwrap(), None);
+    /// ```
+    pub fn decode_reader<R: io::Read + ?Sized>(
+        &self,
+        r: &mut R,
+        delimiter: u8,
+    ) -> io::Result<Option<T>> {
+        let confusable_len = self.confusables.iter().map(|(from, _)| from.len()).max();
+        let limit = self.max_encoded_len() * confusable_len.unwrap_or(1).max(1);
+
+        let mut v = Vec::new();
+        let mut byte = [0];
+        loop {
+            match r.read(&mut byte) {
+                Ok(0) if v.is_empty() => return Ok(None),
+                Ok(0) => break,
+                Ok(_) if byte[0] == delimiter => break,
+                Ok(_) if v.len() == limit => return Err(AlphaIdError::InvalidLength.into()),
+                Ok(_) => v.push(byte[0]),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(Some(self.decode(v)?))
+    }
+}
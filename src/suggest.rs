@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// Groups of characters which are commonly typed for each other.
+const LOOK_ALIKES: &[&[u8]] = &[
+    b"0Oo", b"1lIi|", b"2Zz", b"5Ss", b"6G", b"8B", b"9gq", b"uvUV", b"-_",
+];
+
+/// The most unexpected characters which are substituted.
+const MAX_SUBSTITUTIONS: usize = 4;
+
+/// An error of [`AlphaId::decode_with_suggestions`].
+#[derive(Debug, PartialEq)]
+pub struct SuggestedError<T> {
+    pub error: AlphaIdError,
+    /// The inputs which decode successfully, with their numbers.
+    pub suggestions: Vec<(Vec<u8>, T)>,
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Decode into numbers, proposing likely intended inputs if decoding
+    /// fails.
+    ///
+    /// Unexpected characters are replaced by the look-alikes in the
+    /// alphabet, e.g. `O` by `0`, and every combination which decodes,
+    /// including the verification of tags, is suggested. Without
+    /// unexpected characters, every single character is replaced instead.
+    /// Symbol alphabets get no suggestions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder()
+    ///     .chars(b"0123456789abcdefghjkmnpqrstuvwxyz".to_vec())
+    ///     .build();
+    /// let err = alphaid.decode_with_suggestions(b"1O").unwrap_err();
+    /// assert_eq!(err.suggestions, [(b"10".to_vec(), alphaid.decode(b"10").unwrap())]);
+    /// ```
+    pub fn decode_with_suggestions<V: AsRef<[u8]>>(&self, v: V) -> Result<T, SuggestedError<T>> {
+        let v = v.as_ref();
+        let error = match self.decode(v) {
+            Ok(n) => return Ok(n),
+            Err(error) => error,
+        };
+        let mut suggestions = Vec::new();
+        if !self.symbols.is_empty() || v.len() < self.prefix.len() + self.suffix.len() {
+            return Err(SuggestedError { error, suggestions });
+        }
+
+        let body = self.prefix.len()..v.len() - self.suffix.len();
+        let sep = self.group.map(|(_, sep)| sep);
+        let unexpected: Vec<usize> = body
+            .clone()
+            .filter(|&i| self.digit(v[i]).is_none() && Some(v[i]) != sep)
+            .collect();
+
+        let mut input = v.to_vec();
+        if unexpected.is_empty() {
+            for i in body {
+                for c in self.look_alikes(v[i]) {
+                    input[i] = c;
+                    self.suggest(&input, &mut suggestions);
+                }
+                input[i] = v[i];
+            }
+        } else if unexpected.len() <= MAX_SUBSTITUTIONS {
+            self.substitute(&mut input, &unexpected, &mut suggestions);
+        }
+        Err(SuggestedError { error, suggestions })
+    }
+
+    /// Suggests every combination of look-alikes at `positions`.
+    fn substitute(
+        &self,
+        input: &mut Vec<u8>,
+        positions: &[usize],
+        suggestions: &mut Vec<(Vec<u8>, T)>,
+    ) {
+        let (&i, rest) = match positions.split_first() {
+            Some(split) => split,
+            None => return self.suggest(input, suggestions),
+        };
+        let original = input[i];
+        for c in self.look_alikes(original) {
+            input[i] = c;
+            self.substitute(input, rest, suggestions);
+        }
+        input[i] = original;
+    }
+
+    fn suggest(&self, input: &[u8], suggestions: &mut Vec<(Vec<u8>, T)>) {
+        if let Ok(n) = self.decode(input) {
+            suggestions.push((input.to_vec(), n));
+        }
+    }
+
+    /// Returns the characters of the alphabet which look like `b`.
+    fn look_alikes(&self, b: u8) -> Vec<u8> {
+        LOOK_ALIKES
+            .iter()
+            .filter(|group| group.contains(&b))
+            .flat_map(|group| group.iter().copied())
+            .filter(|&c| c != b && self.digit(c).is_some())
+            .collect()
+    }
+}
@@ -0,0 +1,88 @@
+use alloc::vec::Vec;
+use siphasher::sip::SipHasher24;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// Returns the SipHash-2-4 of `msg` under `key`.
+pub(crate) fn siphash24(key: &[u8; 16], msg: &[u8]) -> u64 {
+    SipHasher24::new_with_key(key).hash(msg)
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Appends the tag of [`Builder::tag`](crate::Builder::tag) to `n` as
+    /// its least significant digits.
+    pub(crate) fn tagged(&self, n: T) -> Result<T, AlphaIdError> {
+        let (key, len) = match &self.tag {
+            Some((key, len)) => (key, *len as usize),
+            None => return Ok(n),
+        };
+        let mut tag = self.tag_of(n, key, len);
+        let mut len = len;
+        if self.tag_key_id {
+            tag = tag + self.key_id(key) * self.powers[len];
+            len += 1;
+        }
+        n.checked_mul(&self.powers[len])
+            .and_then(|t| t.checked_add(&tag))
+            .ok_or(AlphaIdError::Overflow)
+    }
+
+    /// Removes and verifies the tag of [`Builder::tag`](crate::Builder::tag).
+    pub(crate) fn untagged(&self, t: T) -> Result<T, AlphaIdError> {
+        let (key, len) = match &self.tag {
+            Some((key, len)) => (key, *len as usize),
+            None => return Ok(t),
+        };
+        let pow = self.powers[len];
+        let (mut n, tag) = (t / pow, t % pow);
+        let mut id = None;
+        if self.tag_key_id {
+            id = Some(n % self.base);
+            n = n / self.base;
+        }
+        let mut keys = core::iter::once(key)
+            .chain(self.old_tag_keys.iter())
+            .filter(|key| id.is_none_or(|id| id == self.key_id(key)));
+        if !keys.any(|key| tag == self.tag_of(n, key, len)) {
+            return Err(AlphaIdError::TagMismatch);
+        }
+        Ok(n)
+    }
+
+    /// Returns the digit of [`Builder::tag_key_id`](crate::Builder::tag_key_id)
+    /// identifying `key`.
+    fn key_id(&self, key: &[u8; 16]) -> T {
+        let base = self.base.to_usize().unwrap_or(usize::MAX) as u64;
+        T::from_usize((siphash24(key, b"key id") % base) as usize).unwrap_or_else(T::zero)
+    }
+
+    /// Returns the `len` digit tag of `n`, taken from the hash of its
+    /// little-endian bytes.
+    fn tag_of(&self, n: T, key: &[u8; 16], len: usize) -> T {
+        let byte = T::from_usize(256);
+        let mut bytes = Vec::new();
+        let mut rest = n;
+        while !rest.is_zero() {
+            match byte {
+                Some(byte) => {
+                    bytes.push((rest % byte).to_usize().unwrap_or(0) as u8);
+                    rest = rest / byte;
+                }
+                None => {
+                    bytes.push(rest.to_usize().unwrap_or(0) as u8);
+                    rest = T::zero();
+                }
+            }
+        }
+
+        let mut h = siphash24(key, &bytes);
+        let base = self.base.to_usize().unwrap_or(usize::MAX) as u64;
+        let mut tag = T::zero();
+        for _ in 0..len {
+            let digit = T::from_usize((h % base) as usize).unwrap_or_else(T::zero);
+            tag = tag * self.base + digit;
+            h /= base;
+        }
+        tag
+    }
+}
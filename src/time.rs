@@ -0,0 +1,86 @@
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use std::time::{Duration, SystemTime};
+
+use crate::{from_u64, AlphaId, AlphaIdError, UnsignedInteger};
+
+/// The resolution timestamps are encoded with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Seconds,
+    Millis,
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode the time elapsed from `epoch` to `t` in `unit`s, rounded
+    /// down.
+    ///
+    /// Returns `AlphaIdError::InvalidNumber` if `t` is before `epoch`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, TimeUnit};
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let alphaid = AlphaId::<u64>::new();
+    /// let epoch = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+    /// let t = epoch + Duration::from_secs(86_400);
+    /// let v = alphaid.encode_timestamp(t, epoch, TimeUnit::Seconds).unwrap();
+    /// assert_eq!(v, b"agv".to_vec());
+    /// assert_eq!(alphaid.decode_timestamp(&v, epoch, TimeUnit::Seconds), Ok(t));
+    /// ```
+    pub fn encode_timestamp(
+        &self,
+        t: SystemTime,
+        epoch: SystemTime,
+        unit: TimeUnit,
+    ) -> Result<Vec<u8>, AlphaIdError> {
+        let elapsed = t
+            .duration_since(epoch)
+            .map_err(|_| AlphaIdError::InvalidNumber)?;
+        let n = match unit {
+            TimeUnit::Seconds => elapsed.as_secs(),
+            TimeUnit::Millis => {
+                u64::try_from(elapsed.as_millis()).map_err(|_| AlphaIdError::Overflow)?
+            }
+        };
+        self.encode(from_u64(n).ok_or(AlphaIdError::Overflow)?)
+    }
+
+    /// Decode the result of
+    /// [`encode_timestamp`](AlphaId::encode_timestamp) into the time.
+    pub fn decode_timestamp<V: AsRef<[u8]>>(
+        &self,
+        v: V,
+        epoch: SystemTime,
+        unit: TimeUnit,
+    ) -> Result<SystemTime, AlphaIdError> {
+        let n = self.decode(v)?;
+        let n = to_u64(n).ok_or(AlphaIdError::Overflow)?;
+        let elapsed = match unit {
+            TimeUnit::Seconds => Duration::from_secs(n),
+            TimeUnit::Millis => Duration::from_millis(n),
+        };
+        epoch.checked_add(elapsed).ok_or(AlphaIdError::Overflow)
+    }
+}
+
+/// Converts `n` into a `u64`, returning `None` if it does not fit.
+fn to_u64<T: UnsignedInteger>(n: T) -> Option<u64> {
+    let chunk = match T::from_usize(1 << 16) {
+        Some(chunk) => chunk,
+        None => return n.to_usize().map(|n| n as u64),
+    };
+    let mut rest = n;
+    let mut out = 0u64;
+    for i in 0..4 {
+        out |= ((rest % chunk).to_usize()? as u64) << (16 * i);
+        rest = rest / chunk;
+    }
+    if rest.is_zero() {
+        Some(out)
+    } else {
+        None
+    }
+}
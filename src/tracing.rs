@@ -0,0 +1,41 @@
+//! Events and spans for the `tracing` crate, all at the `DEBUG` level
+//! under the `alphaid` target.
+//!
+//! A failed [`AlphaId::decode`] or [`AlphaId::decode_strict`], and the
+//! methods built on them, emits an `alphaid::decode_failed` event with the
+//! fields
+//!
+//! - `error`, the snake case name of the [`AlphaIdError`], e.g.
+//!   `unexpected_char` or `tag_mismatch`,
+//! - `input_len`, the length of the input in bytes,
+//! - `offset`, for `unexpected_char`, the index of the first `char` which
+//!   can not be decoded, see [`AlphaId::decode_str_with_position`].
+//!
+//! The input itself is not recorded. [`AlphaId::encode_batch`],
+//! [`AlphaId::decode_batch`] and their parallel versions run in a span of
+//! the same name with the field `len`, the size of the batch.
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+pub(crate) fn decode_failed<T: UnsignedInteger, M>(
+    alphaid: &AlphaId<T, M>,
+    v: &[u8],
+    e: &AlphaIdError,
+) {
+    if !::tracing::enabled!(target: "alphaid", ::tracing::Level::DEBUG) {
+        return;
+    }
+    let offset = match e {
+        AlphaIdError::UnexpectedChar => core::str::from_utf8(v)
+            .ok()
+            .and_then(|s| alphaid.unexpected_char_position(s)),
+        _ => None,
+    };
+    ::tracing::debug!(
+        name: "alphaid::decode_failed",
+        target: "alphaid",
+        error = e.name(),
+        input_len = v.len(),
+        offset,
+        "failed to decode an ID",
+    );
+}
@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+
+use crate::digits::Digits;
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Encode two numbers into one result, e.g. a parent and a child ID.
+    ///
+    /// Every number but the last is preceded by its number of digits. Only
+    /// the alphabet, the separators and the affixes of this `AlphaId` are
+    /// applied, the padding, digit order and blocklist are not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let v = alphaid.encode_pair(1350997667, 7).unwrap();
+    /// assert_eq!(v, b"g90F7qbh".to_vec());
+    /// assert_eq!(alphaid.decode_pair(&v), Ok((1350997667, 7)));
+    /// ```
+    pub fn encode_pair(&self, a: T, b: T) -> Result<Vec<u8>, AlphaIdError> {
+        self.encode_tuple(&[a, b])
+    }
+
+    /// Encode three numbers into one result, see
+    /// [`encode_pair`](AlphaId::encode_pair).
+    pub fn encode_triple(&self, a: T, b: T, c: T) -> Result<Vec<u8>, AlphaIdError> {
+        self.encode_tuple(&[a, b, c])
+    }
+
+    /// Decode the result of [`encode_pair`](AlphaId::encode_pair).
+    pub fn decode_pair<V: AsRef<[u8]>>(&self, v: V) -> Result<(T, T), AlphaIdError> {
+        let mut values = [T::zero(); 2];
+        self.decode_tuple(v.as_ref(), &mut values)?;
+        Ok((values[0], values[1]))
+    }
+
+    /// Decode the result of [`encode_triple`](AlphaId::encode_triple).
+    pub fn decode_triple<V: AsRef<[u8]>>(&self, v: V) -> Result<(T, T, T), AlphaIdError> {
+        let mut values = [T::zero(); 3];
+        self.decode_tuple(v.as_ref(), &mut values)?;
+        Ok((values[0], values[1], values[2]))
+    }
+
+    fn encode_tuple(&self, values: &[T]) -> Result<Vec<u8>, AlphaIdError> {
+        let base = self.base.to_usize().ok_or(AlphaIdError::Overflow)?;
+        let width = self.tuple_len_width();
+        let mut digits = Vec::new();
+        for (i, &n) in values.iter().enumerate() {
+            let start = digits.len();
+            digits.extend(Digits::filled(n, self.base, 1).with_bits(self.bits));
+            if i + 1 < values.len() {
+                let mut len = digits.len() - start;
+                let prefix: Vec<usize> = (0..width)
+                    .map(|_| {
+                        let d = len % base;
+                        len /= base;
+                        d
+                    })
+                    .collect();
+                digits.splice(start..start, prefix);
+            }
+        }
+
+        let mut out = self.prefix.to_vec();
+        for (count, &d) in digits.iter().enumerate() {
+            if let Some(sep) = self.separator_before(count) {
+                out.push(sep);
+            }
+            if self.symbols.is_empty() {
+                out.push(self.chars[d]);
+            } else {
+                out.extend_from_slice(self.symbols[d].as_bytes());
+            }
+        }
+        out.extend_from_slice(&self.suffix);
+        Ok(out)
+    }
+
+    fn decode_tuple(&self, v: &[u8], values: &mut [T]) -> Result<(), AlphaIdError> {
+        let v = v
+            .strip_prefix(&self.prefix[..])
+            .ok_or(AlphaIdError::PrefixMissed)?;
+        let v = v
+            .strip_suffix(&self.suffix[..])
+            .ok_or(AlphaIdError::SuffixMissed)?;
+        let digits = self.tokenize(v).ok_or(AlphaIdError::UnexpectedChar)?;
+        let base = self.base.to_usize().ok_or(AlphaIdError::Overflow)?;
+        if digits.iter().any(|&d| d >= base) {
+            return Err(AlphaIdError::UnexpectedChar);
+        }
+
+        let width = self.tuple_len_width();
+        let count = values.len();
+        let mut rest = &digits[..];
+        for (i, value) in values.iter_mut().enumerate() {
+            let len = if i + 1 < count {
+                let (prefix, tail) = split(rest, width)?;
+                rest = tail;
+                prefix.iter().rev().fold(0, |len, &d| len * base + d)
+            } else {
+                rest.len()
+            };
+            let (number, tail) = split(rest, len)?;
+            if number.is_empty() {
+                return Err(AlphaIdError::InvalidLength);
+            }
+            rest = tail;
+            *value = number
+                .iter()
+                .rev()
+                .try_fold(T::zero(), |n, &d| {
+                    n.checked_mul(&self.base)?.checked_add(&T::from_usize(d)?)
+                })
+                .ok_or(AlphaIdError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of digits the lengths of `encode_tuple` take.
+    fn tuple_len_width(&self) -> usize {
+        let base = self.base.to_usize().unwrap_or(usize::MAX);
+        let mut len = Digits::filled(T::max_value(), self.base, 1).count();
+        let mut width = 1;
+        while len >= base {
+            len /= base;
+            width += 1;
+        }
+        width
+    }
+}
+
+fn split(digits: &[usize], len: usize) -> Result<(&[usize], &[usize]), AlphaIdError> {
+    if len > digits.len() {
+        return Err(AlphaIdError::InvalidLength);
+    }
+    Ok(digits.split_at(len))
+}
@@ -0,0 +1,73 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use primitive_types::{U256, U512};
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+macro_rules! impl_wide {
+    ($t:ident, $encode:ident, $decode:ident, $shift:literal, $example:literal) => {
+        impl<T: UnsignedInteger, M> AlphaId<T, M> {
+            #[doc = concat!("Encode a `primitive_types::", stringify!($t), "`, e.g. a hash or a token ID.")]
+            ///
+            /// Like [`encode_dec_str`](AlphaId::encode_dec_str) only the
+            /// alphabet, the digit order, the group separator and the affixes
+            /// of the configuration are applied.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use alphaid::AlphaId;\nuse primitive_types::", stringify!($t), ";")]
+            ///
+            /// let alphaid = AlphaId::<u32>::new();
+            #[doc = concat!("let n = ", stringify!($t), "::one() << ", $shift, ";")]
+            #[doc = concat!("let v = alphaid.", stringify!($encode), "(n).unwrap();")]
+            #[doc = concat!("assert_eq!(v, \"", $example, "\");")]
+            #[doc = concat!("assert_eq!(alphaid.", stringify!($decode), "(&v), Ok(n));")]
+            /// ```
+            pub fn $encode(&self, n: $t) -> Result<String, AlphaIdError> {
+                let base = $t::from(self.base());
+                let mut digits = Vec::new();
+                let mut rest = n;
+                loop {
+                    let (q, r) = rest.div_mod(base);
+                    digits.push(r.low_u64() as usize);
+                    rest = q;
+                    if rest.is_zero() {
+                        break;
+                    }
+                }
+                self.wide_to_string(digits)
+            }
+
+            #[doc = concat!("Decode the result of [`", stringify!($encode), "`](AlphaId::", stringify!($encode), ").")]
+            ///
+            #[doc = concat!("Returns `AlphaIdError::Overflow` if the number does not fit into a `", stringify!($t), "`.")]
+            pub fn $decode(&self, s: &str) -> Result<$t, AlphaIdError> {
+                let base = $t::from(self.base());
+                let mut n = $t::zero();
+                for d in self.wide_digits(s)? {
+                    n = n
+                        .checked_mul(base)
+                        .and_then(|n| n.checked_add($t::from(d)))
+                        .ok_or(AlphaIdError::Overflow)?;
+                }
+                Ok(n)
+            }
+        }
+    };
+}
+
+impl_wide!(
+    U256,
+    encode_u256,
+    decode_u256,
+    128,
+    "aaaaaaaaaaaaaaaaaaaaae"
+);
+impl_wide!(
+    U512,
+    encode_u512,
+    decode_u512,
+    256,
+    "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaq"
+);
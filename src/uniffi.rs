@@ -0,0 +1,186 @@
+//! Swift and Kotlin interfaces through UniFFI, generating the same IDs in
+//! iOS and Android apps as on the backend.
+//!
+//! Build the library with `cargo build --release --features uniffi`, after
+//! adding `cdylib` (Android) or `staticlib` (iOS) to the crate types, and
+//! generate the bindings from it:
+//!
+//! ```text
+//! cargo run --features uniffi-bindgen --bin uniffi-bindgen -- generate \
+//!     --library target/release/libalphaid.so --language swift --out-dir out
+//! ```
+//!
+//! ```swift
+//! let alphaid = try AlphaIdBuilder().pad(pad: 5).prefix(prefix: "usr_").build()
+//! let id = try alphaid.encode(n: 1350997667)
+//! try alphaid.decode(s: id) // 1350997667
+//! ```
+//!
+//! Numbers are `u64`, `UInt64` in Swift and `ULong` in Kotlin. Errors are
+//! thrown as [`AlphaIdError`], with `InvalidConfig` for settings which
+//! [`Builder::build`] would panic on.
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use std::sync::Mutex;
+
+use crate::{grapheme, AlphaIdError, Builder, Config, DigitOrder, PadMode};
+
+/// An `AlphaId<u64>`.
+#[derive(Debug, ::uniffi::Object)]
+pub struct AlphaId(crate::AlphaId<u64>);
+
+#[::uniffi::export]
+impl AlphaId {
+    /// Constructs an `AlphaId` with the default settings.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        AlphaId(crate::AlphaId::new())
+    }
+
+    /// Constructs an `AlphaId` from the settings of [`Builder::parse`].
+    #[uniffi::constructor]
+    pub fn parse(settings: &str) -> Result<Self, AlphaIdError> {
+        Builder::parse(settings)?
+            .try_build_marked()
+            .map(AlphaId)
+            .map_err(|_| AlphaIdError::InvalidConfig)
+    }
+
+    /// Encodes `n` into a string.
+    pub fn encode(&self, n: u64) -> Result<String, AlphaIdError> {
+        self.0.encode_string(n)
+    }
+
+    /// Decodes a string into the number.
+    pub fn decode(&self, s: &str) -> Result<u64, AlphaIdError> {
+        self.0.decode_str(s)
+    }
+
+    /// Decodes a string into the number, accepting only the exact result of
+    /// [`encode`](AlphaId::encode).
+    pub fn decode_strict(&self, s: &str) -> Result<u64, AlphaIdError> {
+        self.0.decode_strict(s)
+    }
+}
+
+impl Default for AlphaId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The settings of an [`AlphaId`], like [`Builder`].
+///
+/// The setters modify the builder in place and return it for chaining.
+/// Invalid settings are an error of [`build`](AlphaIdBuilder::build)
+/// instead of a panic.
+#[derive(Debug, ::uniffi::Object)]
+pub struct AlphaIdBuilder(Mutex<Config>);
+
+impl AlphaIdBuilder {
+    fn update(self: Arc<Self>, f: impl FnOnce(&mut Config)) -> Arc<Self> {
+        f(&mut self.0.lock().unwrap());
+        self
+    }
+}
+
+#[::uniffi::export]
+impl AlphaIdBuilder {
+    /// Constructs a builder with the default settings.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        AlphaIdBuilder(Mutex::new(Config::default()))
+    }
+
+    /// Sets the characters set, see [`Builder::chars_str`].
+    pub fn chars(self: Arc<Self>, chars: &str) -> Arc<Self> {
+        self.update(|config| {
+            if chars.is_ascii() {
+                config.chars = chars.as_bytes().to_vec();
+                config.symbols = Vec::new();
+            } else {
+                config.chars = Vec::new();
+                config.symbols = grapheme::split(chars)
+                    .into_iter()
+                    .map(String::from)
+                    .collect();
+            }
+        })
+    }
+
+    /// Sets the minimum length, see [`Builder::pad`].
+    pub fn pad(self: Arc<Self>, pad: u32) -> Arc<Self> {
+        self.update(|config| config.pad = pad)
+    }
+
+    /// See [`Builder::pad_mode`].
+    pub fn pad_mode(self: Arc<Self>, mode: PadMode) -> Arc<Self> {
+        self.update(|config| config.pad_mode = mode)
+    }
+
+    /// See [`Builder::order`].
+    pub fn order(self: Arc<Self>, order: DigitOrder) -> Arc<Self> {
+        self.update(|config| config.order = order)
+    }
+
+    /// See [`Builder::group`].
+    pub fn group(self: Arc<Self>, size: u32, sep: u8) -> Arc<Self> {
+        self.update(|config| config.group = Some((size as usize, sep)))
+    }
+
+    /// See [`Builder::prefix`].
+    pub fn prefix(self: Arc<Self>, prefix: &str) -> Arc<Self> {
+        self.update(|config| config.prefix = prefix.as_bytes().to_vec())
+    }
+
+    /// See [`Builder::suffix`].
+    pub fn suffix(self: Arc<Self>, suffix: &str) -> Arc<Self> {
+        self.update(|config| config.suffix = suffix.as_bytes().to_vec())
+    }
+
+    /// See [`Builder::reject_empty`].
+    pub fn reject_empty(self: Arc<Self>, reject: bool) -> Arc<Self> {
+        self.update(|config| config.reject_empty = reject)
+    }
+
+    /// Appends a tag of `len` symbols, see [`Builder::tag`]. The key must
+    /// be 16 bytes.
+    pub fn tag(self: Arc<Self>, key: Vec<u8>, len: u32) -> Result<Arc<Self>, AlphaIdError> {
+        let key = <[u8; 16]>::try_from(key).map_err(|_| AlphaIdError::InvalidConfig)?;
+        Ok(self.update(|config| config.tag = Some((key, len))))
+    }
+
+    /// See [`Builder::sign`].
+    pub fn sign(self: Arc<Self>, sign: u8) -> Arc<Self> {
+        self.update(|config| config.sign = Some(sign))
+    }
+
+    /// See [`Builder::none_symbol`].
+    pub fn none_symbol(self: Arc<Self>, symbol: u8) -> Arc<Self> {
+        self.update(|config| config.none_symbol = Some(symbol))
+    }
+
+    /// See [`Builder::permute_digits`].
+    pub fn permute_digits(self: Arc<Self>, seed: u64) -> Arc<Self> {
+        self.update(|config| config.digit_seed = Some(seed))
+    }
+
+    /// See [`Builder::block_words`].
+    pub fn block_words(self: Arc<Self>, words: Vec<String>) -> Arc<Self> {
+        self.update(|config| config.blocked_words.extend(words))
+    }
+
+    /// Builds the `AlphaId`.
+    pub fn build(&self) -> Result<AlphaId, AlphaIdError> {
+        let config = self.0.lock().unwrap().clone();
+        crate::AlphaId::try_from_config(config).map(AlphaId)
+    }
+}
+
+impl Default for AlphaIdBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
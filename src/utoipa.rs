@@ -0,0 +1,57 @@
+//! Describes an [`EncodedId`] as a string in OpenAPI documents generated by
+//! `utoipa`.
+use alloc::borrow::Cow;
+
+use ::utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use ::utoipa::openapi::RefOr;
+use ::utoipa::{PartialSchema, ToSchema};
+
+use crate::json_schema::StringSchema;
+use crate::{EncodedId, Encoding};
+
+/// A `string` with the pattern of the alphabet and the affixes, and the
+/// lengths of the shortest and the longest encoded result.
+impl<E: Encoding> PartialSchema for EncodedId<E> {
+    fn schema() -> RefOr<Schema> {
+        let s = StringSchema::of::<E>();
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .pattern(Some(s.pattern))
+            .min_length(s.min_length)
+            .max_length(s.max_length)
+            .into()
+    }
+}
+
+/// The component is named after the `Encoding`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::OnceLock;
+/// # use alphaid::{AlphaId, Encoding};
+/// # struct Users;
+/// # impl Encoding for Users {
+/// #     type Int = u32;
+/// #     fn alphaid() -> &'static AlphaId<u32> {
+/// #         static ALPHAID: OnceLock<AlphaId<u32>> = OnceLock::new();
+/// #         ALPHAID.get_or_init(|| AlphaId::builder().prefix(b"u_").build())
+/// #     }
+/// # }
+/// use alphaid::EncodedId;
+/// use utoipa::openapi::schema::{Schema, Type};
+/// use utoipa::openapi::RefOr;
+/// use utoipa::{PartialSchema, ToSchema};
+///
+/// assert_eq!(EncodedId::<Users>::name(), "Users");
+/// let RefOr::T(Schema::Object(schema)) = EncodedId::<Users>::schema() else {
+///     unreachable!()
+/// };
+/// assert!(schema.schema_type == Type::String.into());
+/// assert_eq!(schema.min_length, Some(3));
+/// ```
+impl<E: Encoding> ToSchema for EncodedId<E> {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed(StringSchema::of::<E>().name)
+    }
+}
@@ -0,0 +1,114 @@
+use alloc::vec::Vec;
+
+use crate::{AlphaId, AlphaIdError, UnsignedInteger};
+
+/// A problem found by [`AlphaId::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// A character outside of the alphabet at this byte offset.
+    UnexpectedChar(usize),
+    /// Any other error of [`AlphaId::decode_strict`].
+    Error(AlphaIdError),
+}
+
+/// Every problem of an input, see [`AlphaId::validate`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ValidationReport {
+    problems: Vec<Problem>,
+}
+
+impl ValidationReport {
+    /// Returns `true` if no problem was found.
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// Returns the problems in the order of the input.
+    pub fn problems(&self) -> &[Problem] {
+        &self.problems
+    }
+}
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Checks `v` like [`decode_strict`](AlphaId::decode_strict), reporting
+    /// every problem instead of the first one.
+    ///
+    /// Missing affixes and every unexpected character are reported. The
+    /// numbers are only checked for overflow, length and canonical form if
+    /// all characters are part of the alphabet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::{AlphaId, AlphaIdError, Problem};
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().prefix(b"id_").build();
+    /// assert!(alphaid.validate(b"id_90F7qb").is_valid());
+    ///
+    /// let report = alphaid.validate(b"9!F7q?");
+    /// assert_eq!(
+    ///     report.problems(),
+    ///     &[
+    ///         Problem::Error(AlphaIdError::PrefixMissed),
+    ///         Problem::UnexpectedChar(1),
+    ///         Problem::UnexpectedChar(5),
+    ///     ]
+    /// );
+    /// ```
+    pub fn validate<V: AsRef<[u8]>>(&self, v: V) -> ValidationReport {
+        let v = v.as_ref();
+        let mut problems = Vec::new();
+        if self.check_input_len(v.len()).is_err() {
+            problems.push(Problem::Error(AlphaIdError::InputTooLong));
+        }
+        let (offset, body) = match v.strip_prefix(&self.prefix[..]) {
+            Some(body) => (self.prefix.len(), body),
+            None => {
+                problems.push(Problem::Error(AlphaIdError::PrefixMissed));
+                (0, v)
+            }
+        };
+        let body = match body.strip_suffix(&self.suffix[..]) {
+            Some(body) => body,
+            None => {
+                problems.push(Problem::Error(AlphaIdError::SuffixMissed));
+                body
+            }
+        };
+
+        let mut unexpected = false;
+        let mut i = 0;
+        while i < body.len() {
+            let rest = &body[i..];
+            let len = if self.group.is_some_and(|(_, sep)| sep == rest[0]) {
+                1
+            } else if let Some((from, _)) = self
+                .confusables
+                .iter()
+                .find(|(from, _)| rest.starts_with(from))
+            {
+                from.len()
+            } else if let Some(len) = self.symbol_len(rest) {
+                len
+            } else {
+                unexpected = true;
+                problems.push(Problem::UnexpectedChar(offset + i));
+                1 + rest[1..].iter().take_while(|&&b| b & 0xC0 == 0x80).count()
+            };
+            i += len;
+        }
+
+        if !unexpected {
+            let mut whole = self.prefix.to_vec();
+            whole.extend_from_slice(body);
+            whole.extend_from_slice(&self.suffix);
+            match self.decode_strict(&whole) {
+                Err(e) if !problems.contains(&Problem::Error(e)) => {
+                    problems.push(Problem::Error(e))
+                }
+                _ => {}
+            }
+        }
+        ValidationReport { problems }
+    }
+}
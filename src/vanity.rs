@@ -0,0 +1,61 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use crate::{AlphaId, UnsignedInteger};
+
+impl<T: UnsignedInteger, M> AlphaId<T, M> {
+    /// Returns the first number of `range` whose encoded result contains
+    /// `needle`, together with the result, e.g. for memorable short links.
+    ///
+    /// Searching around a target value covers `target - k..=target + k`.
+    /// Every number is encoded, so the search takes time linear in the
+    /// size of the range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::new();
+    /// let (n, v) = alphaid.find_vanity(1_000_000..=20_000_000, b"cafe").unwrap();
+    /// assert_eq!(v, b"cafe");
+    /// assert_eq!(alphaid.decode(&v), Ok(n));
+    /// ```
+    pub fn find_vanity(&self, range: RangeInclusive<T>, needle: &[u8]) -> Option<(T, Vec<u8>)> {
+        self.find_vanity_by(range, |v| v.windows(needle.len()).any(|w| w == needle))
+    }
+
+    /// Returns the first number of `range` whose encoded result matches
+    /// `pattern`, together with the result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use alphaid::AlphaId;
+    ///
+    /// let alphaid = AlphaId::<u32>::builder().pad(4).build();
+    /// let (_, v) = alphaid
+    ///     .find_vanity_by(0..=u32::MAX, |v| v.iter().all(u8::is_ascii_uppercase))
+    ///     .unwrap();
+    /// assert_eq!(v, b"AAAA");
+    /// ```
+    pub fn find_vanity_by<F>(
+        &self,
+        range: RangeInclusive<T>,
+        mut pattern: F,
+    ) -> Option<(T, Vec<u8>)>
+    where
+        F: FnMut(&[u8]) -> bool,
+    {
+        let (mut n, hi) = range.into_inner();
+        while n <= hi {
+            if let Ok(v) = self.encode(n) {
+                if pattern(&v) {
+                    return Some((n, v));
+                }
+            }
+            n = n.checked_add(&T::one())?;
+        }
+        None
+    }
+}
@@ -0,0 +1,139 @@
+//! Golden test vectors for keeping ports to other languages compatible.
+//!
+//! Every vector is a configuration in the settings syntax of
+//! [`Builder::parse`](crate::Builder::parse), a number and its encoded
+//! result with `u128` as the number type. [`to_json`] renders them as a
+//! JSON array:
+//!
+//! ```json
+//! [
+//!   {"config": "pad=3", "number": "0", "encoded": "aab"},
+//!   ...
+//! ]
+//! ```
+//!
+//! Numbers are JSON strings, since most JSON parsers lose the precision
+//! of large integers.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{AlphaId, Builder};
+
+/// The configurations covered by [`vectors`].
+pub const CONFIGS: &[&str] = &[
+    "",
+    "pad=2",
+    "pad=5",
+    "pad=4;pad_mode=fill",
+    "pad_char=z;pad=3",
+    "chars=0123456789abcdefghij",
+    "chars=0123456789ABCDEFGHJKMNPQRSTVWXYZ;pad=2",
+    "order=big_endian",
+    "order=big_endian;pad=4",
+    "sortable=26",
+    "group=4:.",
+    "prefix=usr_;suffix=.v1",
+];
+
+/// A configuration, a number and its encoded result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vector {
+    /// The settings of [`Builder::parse`](crate::Builder::parse).
+    pub config: &'static str,
+    pub number: u128,
+    pub encoded: String,
+}
+
+/// The numbers encoded with a configuration of `base` symbols: the
+/// boundaries around every power of the base and of the integer types.
+fn numbers(base: u128) -> Vec<u128> {
+    let mut ns = alloc::vec![
+        1350997667,
+        u8::MAX.into(),
+        u16::MAX.into(),
+        u32::MAX.into(),
+        u64::MAX.into(),
+        u128::MAX,
+    ];
+    let mut pow = 1u128;
+    loop {
+        ns.extend([pow - 1, pow, pow + 1]);
+        match pow.checked_mul(base) {
+            Some(next) => pow = next,
+            None => break,
+        }
+    }
+    ns.sort_unstable();
+    ns.dedup();
+    ns
+}
+
+/// Returns the vectors of every configuration in [`CONFIGS`].
+///
+/// Numbers which a configuration can not encode, e.g. those exceeding
+/// the width of the sortable mode, are left out.
+///
+/// # Example
+///
+/// ```rust
+/// let vectors = alphaid::vectors::vectors();
+/// let v = vectors.iter().find(|v| v.config == "pad=2" && v.number == 0).unwrap();
+/// assert_eq!(v.encoded, "ab");
+/// ```
+pub fn vectors() -> Vec<Vector> {
+    let mut out = Vec::new();
+    for &config in CONFIGS {
+        let alphaid: AlphaId<u128> = Builder::parse(config).expect("configs are valid").build();
+        for n in numbers(alphaid.base() as u128) {
+            if let Ok(encoded) = alphaid.encode_string(n) {
+                out.push(Vector {
+                    config,
+                    number: n,
+                    encoded,
+                });
+            }
+        }
+    }
+    out
+}
+
+/// Renders [`vectors`] as a JSON array with one vector per line.
+///
+/// # Example
+///
+/// ```rust
+/// let json = alphaid::vectors::to_json();
+/// assert!(json.starts_with("[\n  {\"config\": \"\", \"number\": \"0\", \"encoded\": \"a\"},\n"));
+/// ```
+pub fn to_json() -> String {
+    let lines: Vec<_> = vectors()
+        .iter()
+        .map(|v| {
+            format!(
+                "  {{\"config\": {}, \"number\": \"{}\", \"encoded\": {}}}",
+                json_string(v.config),
+                v.number,
+                json_string(&v.encoded)
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", lines.join(",\n"))
+}
+
+/// Quotes `s` as a JSON string.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,57 @@
+//! A JavaScript interface through `wasm-bindgen`, generating the same IDs
+//! in the browser as on the backend.
+//!
+//! Build a package with `wasm-pack build --features wasm`, after adding
+//! `cdylib` to the crate types.
+//!
+//! ```js
+//! import { AlphaId } from "alphaid";
+//!
+//! const alphaid = new AlphaId("pad=5;prefix=usr_");
+//! const id = alphaid.encode(1350997667n);
+//! alphaid.decode(id); // 1350997667n
+//! ```
+//!
+//! Numbers are `BigInt`s, so that all `u64` survive the round trip, and
+//! errors are thrown as `Error`s with the message of the [`AlphaIdError`].
+
+use wasm_bindgen::prelude::*;
+
+use crate::{AlphaId, AlphaIdError, Builder};
+
+/// An `AlphaId<u64>`, exported to JavaScript as `AlphaId`.
+#[wasm_bindgen(js_name = AlphaId)]
+pub struct WasmAlphaId(AlphaId<u64>);
+
+#[wasm_bindgen(js_class = AlphaId)]
+impl WasmAlphaId {
+    /// Constructs an `AlphaId` from the settings of [`Builder::parse`], or
+    /// the default settings if `settings` is omitted.
+    ///
+    /// Invalid settings are an error instead of a panic.
+    #[wasm_bindgen(constructor)]
+    pub fn new(settings: Option<String>) -> Result<WasmAlphaId, JsError> {
+        let builder = match settings {
+            Some(s) => Builder::parse(&s).map_err(js_error)?,
+            None => Builder::new(),
+        };
+        builder
+            .try_build_marked()
+            .map(WasmAlphaId)
+            .map_err(JsError::new)
+    }
+
+    /// Encodes `n` into a string.
+    pub fn encode(&self, n: u64) -> Result<String, JsError> {
+        self.0.encode_string(n).map_err(js_error)
+    }
+
+    /// Decodes a string into the number.
+    pub fn decode(&self, s: &str) -> Result<u64, JsError> {
+        self.0.decode_str(s).map_err(js_error)
+    }
+}
+
+fn js_error(e: AlphaIdError) -> JsError {
+    JsError::new(&e.to_string())
+}
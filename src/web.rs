@@ -0,0 +1,78 @@
+//! Extracts an [`EncodedId`] from the path of an axum or actix-web request,
+//! so that handlers take the typed ID instead of decoding a string.
+//!
+//! An invalid ID is rejected with `400 Bad Request` and a message like
+//! `invalid ID: number overflow`. The route must have exactly one path
+//! parameter; use `Path<(EncodedId<A>, EncodedId<B>)>` through the `serde`
+//! implementations for more.
+use alloc::format;
+use alloc::string::String;
+
+use crate::{AlphaIdError, EncodedId, Encoding};
+
+fn message(e: AlphaIdError) -> String {
+    format!("invalid ID: {}", e)
+}
+
+/// Extracts the only path parameter of an axum route.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::OnceLock;
+/// # use alphaid::{AlphaId, Encoding};
+/// # struct Users;
+/// # impl Encoding for Users {
+/// #     type Int = u64;
+/// #     fn alphaid() -> &'static AlphaId<u64> {
+/// #         static ALPHAID: OnceLock<AlphaId<u64>> = OnceLock::new();
+/// #         ALPHAID.get_or_init(AlphaId::new)
+/// #     }
+/// # }
+/// use alphaid::EncodedId;
+/// use axum::{routing::get, Router};
+///
+/// async fn user(id: EncodedId<Users>) -> String {
+///     format!("user {}", id.value())
+/// }
+///
+/// let app: Router = Router::new().route("/users/{id}", get(user));
+/// ```
+impl<S: Send + Sync, E: Encoding> axum::extract::FromRequestParts<S> for EncodedId<E> {
+    type Rejection = axum::response::Response;
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        use axum::extract::Path;
+        use axum::http::StatusCode;
+        use axum::response::IntoResponse;
+
+        let Path(s) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        s.parse()
+            .map_err(|e| (StatusCode::BAD_REQUEST, message(e)).into_response())
+    }
+}
+
+/// Extracts the only path parameter of an actix-web route.
+///
+/// Prefer this over `web::Path<EncodedId<E>>`, which actix-web rejects
+/// with `404 Not Found`.
+impl<E: Encoding> actix_web::FromRequest for EncodedId<E> {
+    type Error = actix_web::Error;
+    type Future = core::future::Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &actix_web::HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
+        use actix_web::error::ErrorBadRequest;
+        use actix_web::web::Path;
+
+        core::future::ready(
+            Path::<String>::extract(req)
+                .into_inner()
+                .and_then(|s| s.parse().map_err(|e| ErrorBadRequest(message(e)))),
+        )
+    }
+}
@@ -1,4 +1,9 @@
-use alphaid::{AlphaId, AlphaIdError};
+#![allow(clippy::legacy_numeric_constants)]
+
+use alphaid::{
+    AlphaId, AlphaIdError, AlphaIdRegistry, Builder, CharClass, Config, DigitOrder, Id, Lint,
+    Migrator, Obfuscator, PadMode, PrefixRegistry, RegistryError, VersionedDecoder,
+};
 #[test]
 fn test_encode_basic() {
     let alphaid = AlphaId::new();
@@ -140,3 +145,3381 @@ fn test_duplicated_chars() {
 
     x.encode(20191226).unwrap();
 }
+
+#[test]
+fn test_decode_confusables() {
+    let alphaid = AlphaId::<u32>::builder()
+        .chars(b"abcdefghijklmnopqstuvwxyz23456789".to_vec())
+        .map_confusables(vec![
+            (b"0".to_vec(), b'o'),
+            (b"O".to_vec(), b'o'),
+            (b"1".to_vec(), b'l'),
+            (b"I".to_vec(), b'l'),
+            (b"rn".to_vec(), b'm'),
+        ])
+        .build();
+    let v = alphaid.encode(20191226).unwrap();
+    assert_eq!(alphaid.decode(&v), Ok(20191226));
+    assert_eq!(alphaid.decode(b"mz"), alphaid.decode(b"rnz"));
+    assert_eq!(alphaid.decode(b"Iz"), alphaid.decode(b"lz"));
+    // `n` alone is still a digit of its own.
+    let v = (0..)
+        .map(|n| alphaid.encode(n).unwrap())
+        .find(|v| v.windows(2).any(|w| w == b"nm"))
+        .unwrap();
+    assert_eq!(alphaid.encode(alphaid.decode(&v).unwrap()), Ok(v));
+
+    let mut config = Config::default();
+    config.confusables = vec![(b"rn".to_vec(), b'm')];
+    assert_eq!(
+        alphaid::DynAlphaId::from_config(config).err(),
+        Some(AlphaIdError::InvalidConfig)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_confusables_shadowing_chars() {
+    AlphaId::<u32>::builder()
+        .map_confusables(vec![(b"0".to_vec(), b'o')])
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "confusable must contain a byte which is not in the encoded results")]
+fn test_confusables_in_output() {
+    let alphaid = AlphaId::<u32>::new();
+    assert_eq!(alphaid.encode(849), Ok(b"rn".to_vec()));
+    assert_eq!(alphaid.decode(b"rn"), Ok(849));
+    AlphaId::<u32>::builder()
+        .map_confusables(vec![(b"rn".to_vec(), b'm')])
+        .build();
+}
+
+#[test]
+fn test_unicode_chars() {
+    let alphaid = AlphaId::<u64>::builder()
+        .chars_str("αβγδεζηθικλμνξοπρστυφχψω")
+        .pad(3)
+        .build();
+    assert_eq!(alphaid.encode_chars(0), Ok("ααβ".to_string()));
+    for n in &[0, 1, 23, 24, 20191226, u64::MAX] {
+        let s = alphaid.encode_chars(*n).unwrap();
+        assert_eq!(alphaid.decode_str(&s), Ok(*n));
+        assert_eq!(alphaid.encode(*n), Ok(s.clone().into_bytes()));
+        assert_eq!(alphaid.decode(s.as_bytes()), Ok(*n));
+    }
+    assert_eq!(alphaid.decode_str("αa"), Err(AlphaIdError::UnexpectedChar));
+    assert_eq!(alphaid.decode(b"\xce"), Err(AlphaIdError::UnexpectedChar));
+}
+
+#[test]
+fn test_ascii_chars_str() {
+    let alphaid = AlphaId::<u32>::new();
+    assert_eq!(alphaid.encode_chars(1350997667), Ok("90F7qb".to_string()));
+    assert_eq!(alphaid.decode_str("90F7qb"), Ok(1350997667));
+    assert_eq!(
+        alphaid.decode_str("90F7qé"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+}
+
+#[test]
+fn test_emoji_chars() {
+    let alphaid = AlphaId::<u64>::builder().emoji().build();
+    for n in &[0, 1, 63, 64, 20191226, u64::MAX] {
+        let s = alphaid.encode_chars(*n).unwrap();
+        assert_eq!(alphaid.decode_str(&s), Ok(*n));
+    }
+}
+
+#[cfg(feature = "grapheme")]
+#[test]
+fn test_grapheme_chars() {
+    let family = "👨\u{200D}👩\u{200D}👧";
+    let chars = format!("🐶{}👍🏽🇯🇵1️⃣🐱🐭🐹🐰🦊🐻🐼🐨🐯🦁🐮🐷🐸", family);
+    let alphaid = AlphaId::<u64>::builder().chars_str(&chars).build();
+    assert_eq!(alphaid.encode_chars(1), Ok(family.to_string()));
+    assert_eq!(alphaid.encode_chars(2), Ok("👍🏽".to_string()));
+    assert_eq!(alphaid.encode_chars(3), Ok("🇯🇵".to_string()));
+    assert_eq!(alphaid.decode_str("1️⃣"), Ok(4));
+    assert_eq!(alphaid.decode_str("👍"), Err(AlphaIdError::UnexpectedChar));
+    for n in 0..1000 {
+        let s = alphaid.encode_chars(n).unwrap();
+        assert_eq!(alphaid.decode_str(&s), Ok(n));
+    }
+
+    // A joiner only joins emoji, and decomposed Hangul syllables are one
+    // cluster each.
+    let hangul = "\u{1100}\u{1161}\u{11A8}";
+    let chars = format!(
+        "🏳\u{FE0F}\u{200D}🌈x\u{200D}y{}🐱🐭🐹🐰🦊🐻🐼🐨🐯🦁🐮🐷🐸",
+        hangul
+    );
+    let alphaid = AlphaId::<u64>::builder().chars_str(&chars).build();
+    let symbols = alphaid.to_config().symbols;
+    assert_eq!(symbols[0], "🏳\u{FE0F}\u{200D}🌈");
+    assert_eq!(symbols[1], "x\u{200D}");
+    assert_eq!(symbols[2], "y");
+    assert_eq!(symbols[3], hangul);
+}
+
+#[test]
+fn test_block_words() {
+    let alphaid = AlphaId::<u32>::builder().block_words(&["cat", "B"]).build();
+    for n in 0..100_000 {
+        let v = alphaid.encode(n).unwrap();
+        let lower = v.to_ascii_lowercase();
+        assert!(!lower.windows(3).any(|w| w == b"cat"));
+        assert!(!lower.contains(&b'b'));
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+    assert_eq!(alphaid.decode(b"xaa"), Err(AlphaIdError::UnexpectedChar));
+}
+
+#[test]
+fn test_block_words_non_ascii() {
+    let alphaid = AlphaId::<u32>::builder()
+        .chars_str("АБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ")
+        .block_words(&["да"])
+        .reserved(&["Нет"])
+        .build();
+    assert!(alphaid.is_reserved("НЕТ".as_bytes()));
+    for n in 0..100_000 {
+        let s = alphaid.encode_string(n).unwrap();
+        assert!(!s.contains("ДА"), "{}", s);
+        assert_ne!(s, "НЕТ");
+        assert_eq!(alphaid.decode_str(&s), Ok(n));
+    }
+}
+
+#[test]
+fn test_reserved_words() {
+    let alphaid = AlphaId::<u32>::builder()
+        .reserved(&["new", "edit", "b"])
+        .build();
+    assert!(alphaid.is_reserved(b"EDIT"));
+    assert!(!alphaid.is_reserved(b"edits"));
+    for n in 0..200_000 {
+        let v = alphaid.encode(n).unwrap();
+        assert!(!alphaid.is_reserved(&v));
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+    assert_eq!(alphaid.encode(1), Ok(b"cba".to_vec()));
+}
+
+#[test]
+fn test_big_endian() {
+    let alphaid = AlphaId::<u128>::builder()
+        .order(DigitOrder::BigEndian)
+        .build();
+    assert_eq!(alphaid.encode(64), Ok(b"ba".to_vec()));
+    assert_eq!(alphaid.encode(20191226), Ok(b"bnb5W".to_vec()));
+    assert_eq!(alphaid.decode(b"bnb5W"), Ok(20191226));
+    assert_eq!(alphaid.decode(b"d_____________________"), Ok(u128::MAX));
+
+    let alphaid = AlphaId::<u32>::builder()
+        .order(DigitOrder::BigEndian)
+        .pad(5)
+        .build();
+    assert_eq!(alphaid.encode(0), Ok(b"baaaa".to_vec()));
+    assert_eq!(alphaid.decode(b"baaaa"), Ok(0));
+    for n in (0..u32::MAX).step_by(9973) {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+}
+
+#[test]
+fn test_sortable() {
+    let alphaid = AlphaId::<u64>::builder().sortable(11).build();
+    let mut prev = alphaid.encode(0).unwrap();
+    for n in (1..u64::MAX).step_by(1_000_000_000_000_007) {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(v.len(), 11);
+        assert!(prev < v);
+        assert_eq!(alphaid.decode(&v), Ok(n));
+        prev = v;
+    }
+    assert_eq!(alphaid.decode(b"-"), Err(AlphaIdError::InvalidLength));
+
+    let alphaid = AlphaId::<u64>::builder().sortable(2).build();
+    assert_eq!(alphaid.encode(4095), Ok(b"zz".to_vec()));
+    assert_eq!(alphaid.encode(4096), Err(AlphaIdError::Overflow));
+}
+
+#[test]
+fn test_pad_char() {
+    let alphaid = AlphaId::<u32>::builder().pad(4).pad_char(b'Z').build();
+    assert_eq!(alphaid.encode(0), Ok(b"ZZZb".to_vec()));
+    assert_eq!(alphaid.encode(61), Ok(b"aZZb".to_vec()));
+    for n in (0..u32::MAX).step_by(99991) {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_pad_char_not_in_chars() {
+    AlphaId::<u32>::builder().pad_char(b'!').build();
+}
+
+#[cfg(not(feature = "rand"))]
+#[test]
+#[should_panic(expected = "PadMode::Random requires the rand feature")]
+fn test_random_pad_without_rand() {
+    assert!(matches!(
+        Builder::<u64>::parse("pad=10;pad_mode=random"),
+        Err(AlphaIdError::InvalidConfig)
+    ));
+    AlphaId::<u64>::builder()
+        .pad(10)
+        .pad_mode(PadMode::Random)
+        .build();
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_random_pad() {
+    let alphaid = AlphaId::<u64>::builder()
+        .pad(10)
+        .pad_mode(PadMode::Random)
+        .build();
+    assert_eq!(alphaid.base(), 63);
+    assert_ne!(alphaid.encode(0), alphaid.encode(0));
+    for n in (0..u64::MAX).step_by(1_000_000_000_000_007).chain(0..1000) {
+        let v = alphaid.encode(n).unwrap();
+        assert!(v.len() >= 10);
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+    assert_eq!(alphaid.decode(b"b_ab"), Ok(1));
+    assert_eq!(alphaid.decode(b"b_a!"), Err(AlphaIdError::UnexpectedChar));
+}
+
+#[test]
+fn test_group() {
+    let alphaid = AlphaId::<u128>::builder()
+        .chars(b"abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ".to_vec())
+        .group(4, b'-')
+        .build();
+    assert_eq!(alphaid.encode(20191226), Ok(b"WEIw-b".to_vec()));
+    for n in (0..=255u128).map(|i| i << 120 | i).chain(0..1000) {
+        let v = alphaid.encode(n).unwrap();
+        assert!(v.split(|&b| b == b'-').all(|g| g.len() <= 4));
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+    assert_eq!(alphaid.decode(b"W-EIwb"), Ok(20191226));
+}
+
+#[test]
+#[should_panic]
+fn test_group_separator_in_chars() {
+    AlphaId::<u32>::builder().group(4, b'-').build();
+}
+
+#[test]
+fn test_affixes() {
+    let alphaid = AlphaId::<u64>::builder()
+        .prefix(b"usr_")
+        .suffix(b".v1")
+        .pad(3)
+        .build();
+    assert_eq!(alphaid.encode(0), Ok(b"usr_aab.v1".to_vec()));
+    assert_eq!(alphaid.encode_chars(0), Ok("usr_aab.v1".to_string()));
+    assert_eq!(alphaid.decode(b"usr_aab.v1"), Ok(0));
+    assert_eq!(alphaid.decode_str("usr_aab.v1"), Ok(0));
+    assert_eq!(alphaid.decode(b"aab.v1"), Err(AlphaIdError::PrefixMissed));
+    assert_eq!(alphaid.decode(b"usr_aab"), Err(AlphaIdError::SuffixMissed));
+    assert_eq!(
+        alphaid.decode(b"usr_a.b.v1"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+
+    let alphaid = AlphaId::<u64>::builder()
+        .emoji()
+        .prefix("👤".as_bytes())
+        .build();
+    let s = alphaid.encode_chars(20191226).unwrap();
+    assert!(s.starts_with('👤'));
+    assert_eq!(alphaid.decode_str(&s), Ok(20191226));
+}
+
+#[test]
+fn test_prefix_registry() {
+    #[derive(Debug, Clone, PartialEq)]
+    enum Kind {
+        User,
+        Admin,
+        Guest,
+    }
+
+    let registry = PrefixRegistry::new(AlphaId::<u32>::builder().pad(3).build())
+        .register(b"u", Kind::User)
+        .register(b"u_admin", Kind::Admin);
+    let v = registry.encode(&Kind::Admin, 7).unwrap();
+    assert_eq!(v, b"u_adminhab".to_vec());
+    assert_eq!(registry.decode(&v), Ok((Kind::Admin, 7)));
+    assert_eq!(registry.decode(b"uhab"), Ok((Kind::User, 7)));
+    assert_eq!(registry.decode(b"xhab"), Err(AlphaIdError::PrefixMissed));
+    assert_eq!(
+        registry.encode(&Kind::Guest, 7),
+        Err(AlphaIdError::UnknownName)
+    );
+}
+
+#[test]
+fn test_typed_ids() {
+    struct User;
+    struct Order;
+
+    let users: AlphaId<u64, User> = AlphaId::builder().pad(4).build_marked();
+    let orders = AlphaId::<u64, Order>::default();
+
+    let user: Id<u64, User> = users.decode_id(b"baab").unwrap();
+    assert_eq!(user, Id::new(1));
+    assert_eq!(users.encode_id(user), Ok(b"baab".to_vec()));
+
+    let order: Id<u64, Order> = user.cast();
+    assert_eq!(orders.encode_id(order), Ok(b"b".to_vec()));
+    assert_eq!(order.into_inner(), 1);
+}
+
+#[test]
+fn test_encoded_id() {
+    use alphaid::{EncodedId, Encoding};
+    use std::collections::HashSet;
+    use std::sync::OnceLock;
+
+    struct Orders;
+
+    impl Encoding for Orders {
+        type Int = u32;
+
+        fn alphaid() -> &'static AlphaId<u32> {
+            static ALPHAID: OnceLock<AlphaId<u32>> = OnceLock::new();
+            ALPHAID.get_or_init(|| AlphaId::builder().prefix(b"ord_").build())
+        }
+    }
+
+    let id = EncodedId::<Orders>::new(12345).unwrap();
+    assert_eq!(&*id, "ord_Vad");
+    assert_eq!(id.value(), 12345);
+    assert_eq!(format!("{}", id), "ord_Vad");
+
+    let parsed: EncodedId<Orders> = "ord_Vad".parse().unwrap();
+    assert_eq!(parsed, id);
+    assert_eq!(
+        "usr_Vad".parse::<EncodedId<Orders>>(),
+        Err(AlphaIdError::PrefixMissed)
+    );
+    // "ord_Vada" decodes to the same value, but is not its encoding.
+    assert_eq!(Orders::alphaid().decode("ord_Vada"), Ok(12345));
+    assert_eq!(
+        "ord_Vada".parse::<EncodedId<Orders>>(),
+        Err(AlphaIdError::NonCanonical)
+    );
+
+    let set: HashSet<_> = vec![id.clone(), parsed].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    // Ordered by the numbers, not the encoded strings "ord_Vad" and "ord_a".
+    assert!(id > EncodedId::<Orders>::new(0).unwrap());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_encoded_id_random_pad() {
+    use alphaid::{EncodedId, Encoding};
+    use std::collections::{BTreeSet, HashSet};
+    use std::sync::OnceLock;
+
+    struct Orders;
+
+    impl Encoding for Orders {
+        type Int = u8;
+
+        fn alphaid() -> &'static AlphaId<u8> {
+            static ALPHAID: OnceLock<AlphaId<u8>> = OnceLock::new();
+            ALPHAID.get_or_init(|| AlphaId::builder().pad(12).pad_mode(PadMode::Random).build())
+        }
+    }
+
+    let ids: Vec<_> = (0..20)
+        .map(|_| EncodedId::<Orders>::new(7).unwrap())
+        .collect();
+    assert!(ids.iter().any(|id| id.as_str() != ids[0].as_str()));
+    assert!(ids.iter().all(|id| *id == ids[0]));
+    assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 1);
+    assert_eq!(ids.iter().collect::<BTreeSet<_>>().len(), 1);
+    let other = EncodedId::<Orders>::new(200).unwrap();
+    assert!(ids[0] < other);
+    assert_eq!(ids.iter().chain([&other]).collect::<HashSet<_>>().len(), 2);
+}
+
+#[test]
+fn test_encode_string() {
+    let alphaid = AlphaId::<u64>::builder().prefix(b"id-").pad(3).build();
+    assert_eq!(alphaid.encode_string(0), Ok("id-aab".to_string()));
+
+    let alphaid = AlphaId::<u64>::builder()
+        .chars((0xa0..0xc0).collect())
+        .allow_raw_bytes()
+        .build();
+    assert_eq!(alphaid.encode_string(0), Ok("\u{a0}".to_string()));
+    assert_eq!(alphaid.encode_chars(0), Ok("\u{a0}".to_string()));
+
+    // Raw bytes map to the code points of the same value both ways.
+    let alphaid = AlphaId::<u64>::builder()
+        .chars((0xa0..0xc0).collect())
+        .prefix("é_".as_bytes())
+        .allow_raw_bytes()
+        .build();
+    for n in [0, 5, u64::MAX] {
+        let s = alphaid.encode_string(n).unwrap();
+        assert_eq!(alphaid.decode_str(&s), Ok(n));
+    }
+
+    let alphaid = AlphaId::<u64>::builder().emoji().build();
+    assert_eq!(alphaid.encode_string(1), Ok("🐱".to_string()));
+
+    let alphaid = AlphaId::<u64>::builder()
+        .chars_str("абвгдежзийклмнопрстуфхцчшщъыьэюя")
+        .prefix("é_".as_bytes())
+        .build();
+    let s = alphaid.encode_string(5).unwrap();
+    assert_eq!(s, "é_е");
+    assert_eq!(alphaid.decode_str(&s), Ok(5));
+}
+
+#[test]
+#[should_panic(expected = "affixes must be ASCII")]
+fn test_non_ascii_prefix_with_byte_chars() {
+    AlphaId::<u64>::builder().prefix("é_".as_bytes()).build();
+}
+
+#[test]
+fn test_config_round_trip() {
+    let alphaid = AlphaId::<u64>::builder()
+        .pad(4)
+        .pad_char(b'x')
+        .group(2, b'.')
+        .reserved(&["new"])
+        .block_words(&["cat"])
+        .order(DigitOrder::BigEndian)
+        .build();
+    let config = alphaid.to_config();
+    assert_eq!(config.chars[0], b'x');
+    assert_eq!(config.pad, 4);
+
+    let copy = AlphaId::<u64>::from_config(config.clone());
+    assert_eq!(copy.to_config(), config);
+    for n in (0..u64::MAX).step_by(1_000_000_000_000_007) {
+        assert_eq!(copy.encode(n), alphaid.encode(n));
+    }
+
+    let alphaid = AlphaId::<u64>::builder().emoji().build();
+    let copy = AlphaId::<u64>::from_config(alphaid.to_config());
+    assert_eq!(copy.encode(20191226), alphaid.encode(20191226));
+}
+
+#[test]
+fn test_try_from_config() {
+    let alphaid = AlphaId::<u64>::builder()
+        .pad(4)
+        .block_words(&["cat"])
+        .build();
+    let copy = AlphaId::<u64>::try_from_config(alphaid.to_config()).unwrap();
+    assert_eq!(copy, alphaid);
+
+    let invalid: [fn(&mut Config); 5] = [
+        |c| c.blocked_words = vec!["cat".to_string(), String::new()],
+        |c| c.symbols = vec!["ka".to_string(), String::new()],
+        |c| c.pad = 0,
+        |c| c.group = Some((0, b'.')),
+        |c| c.chars = b"abc".to_vec(),
+    ];
+    for set in invalid {
+        let mut config = Config::default();
+        set(&mut config);
+        assert_eq!(
+            AlphaId::<u64>::try_from_config(config.clone()).err(),
+            Some(AlphaIdError::InvalidConfig),
+            "{:?}",
+            config
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "blocked word must not be empty")]
+fn test_block_words_empty() {
+    AlphaId::<u64>::builder().block_words(&[""]).build();
+}
+
+#[test]
+fn test_decode_str_with_position() {
+    use alphaid::PositionedError;
+
+    let alphaid = AlphaId::<u64>::builder()
+        .prefix("ключ-".as_bytes())
+        .chars_str("абвгдежзийклмнопрстуфхцчшщъыьэюя")
+        .group(2, b'.')
+        .build();
+    let s = alphaid.encode_chars(20191226).unwrap();
+    assert_eq!(alphaid.decode_str_with_position(&s), Ok(20191226));
+
+    let err = |position| {
+        Err(PositionedError {
+            error: AlphaIdError::UnexpectedChar,
+            position,
+        })
+    };
+    assert_eq!(alphaid.decode_str_with_position("ключ-аб.вz"), err(Some(9)));
+
+    let alphaid = AlphaId::<u64>::new();
+    assert_eq!(alphaid.decode_str_with_position("ab+c"), err(Some(2)));
+    assert_eq!(alphaid.decode_str_with_position("abé"), err(Some(2)));
+}
+
+#[test]
+fn test_encode_into() {
+    let alphaid = AlphaId::<u32>::new();
+    let mut out = Vec::new();
+    for n in &[0, 1, 1350997667] {
+        alphaid.encode_into(*n, &mut out).unwrap();
+        out.push(b',');
+    }
+    assert_eq!(out, b"a,b,90F7qb,");
+
+    let alphaid = AlphaId::<u32>::builder().sortable(2).build();
+    assert_eq!(
+        alphaid.encode_into(u32::MAX, &mut out),
+        Err(AlphaIdError::Overflow)
+    );
+    assert_eq!(out, b"a,b,90F7qb,");
+}
+
+#[test]
+fn test_encode_to_slice() {
+    let configs = vec![
+        AlphaId::<u64>::new(),
+        AlphaId::<u64>::builder().pad(5).prefix(b"id_").build(),
+        AlphaId::<u64>::builder()
+            .group(3, b'~')
+            .suffix(b"!")
+            .build(),
+        AlphaId::<u64>::builder()
+            .sortable(11)
+            .group(4, b'.')
+            .build(),
+        AlphaId::<u64>::builder()
+            .order(DigitOrder::BigEndian)
+            .group(2, b'.')
+            .build(),
+        AlphaId::<u64>::builder().block_words(&["b"]).build(),
+    ];
+    for alphaid in &configs {
+        let mut buf = vec![0; alphaid.max_encoded_len()];
+        for &n in &[0, 1, 63, 64, 4096, 1350997667, u64::MAX] {
+            let v = alphaid.encode(n).unwrap();
+            let len = alphaid.encode_to_slice(n, &mut buf).unwrap();
+            assert_eq!(&buf[..len], &v[..]);
+            assert_eq!(
+                alphaid.encode_to_slice(n, &mut buf[..len - 1]),
+                Err(AlphaIdError::BufferTooSmall)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_encode_fmt() {
+    use std::fmt;
+
+    struct Limited(String, usize);
+
+    impl fmt::Write for Limited {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if self.0.len() + s.len() > self.1 {
+                return Err(fmt::Error);
+            }
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    let alphaid = AlphaId::<u32>::builder()
+        .chars_str("абвгдежзийклмнопрстуфхцчшщъыьэюя")
+        .prefix(b"#")
+        .build();
+    let mut out = Limited(String::new(), 64);
+    alphaid.encode_fmt(33, &mut out).unwrap();
+    assert_eq!(out.0, "#бб");
+
+    let mut out = Limited(String::new(), 2);
+    assert_eq!(
+        alphaid.encode_fmt(33, &mut out),
+        Err(AlphaIdError::WriteFailed)
+    );
+
+    let configs = [
+        AlphaId::<u32>::builder()
+            .chars_str("абвгдежзийклмнопрстуфхцчшщъыьэюя")
+            .prefix("é_".as_bytes())
+            .suffix("·".as_bytes())
+            .build(),
+        AlphaId::<u32>::builder()
+            .chars((0xa0..0xc0).collect())
+            .prefix("é_".as_bytes())
+            .group(2, 0xd7)
+            .allow_raw_bytes()
+            .build(),
+    ];
+    for alphaid in &configs {
+        let mut out = String::new();
+        alphaid.encode_fmt(1350997667, &mut out).unwrap();
+        assert_eq!(Ok(out.clone()), alphaid.encode_string(1350997667));
+        assert_eq!(Ok(out.clone()), alphaid.encode_chars(1350997667));
+        assert_eq!(alphaid.decode_str(&out), Ok(1350997667));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io() {
+    use std::io::{ErrorKind, Read};
+
+    let alphaid = AlphaId::<u64>::builder().group(3, b'.').build();
+    let mut out = Vec::new();
+    for n in &[0, 1350997667, u64::MAX] {
+        alphaid.encode_writer(*n, &mut out).unwrap();
+        out.push(b'\n');
+    }
+
+    let mut r = &out[..];
+    assert_eq!(alphaid.decode_reader(&mut r, b'\n').unwrap(), Some(0));
+    assert_eq!(
+        alphaid.decode_reader(&mut r, b'\n').unwrap(),
+        Some(1350997667)
+    );
+    assert_eq!(
+        alphaid.decode_reader(&mut r, b'\n').unwrap(),
+        Some(u64::MAX)
+    );
+    assert_eq!(alphaid.decode_reader(&mut r, b'\n').unwrap(), None);
+
+    let mut r = &b"b+\nc"[..];
+    let err = alphaid.decode_reader(&mut r, b'\n').unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(alphaid.decode_reader(&mut r, b'\n').unwrap(), Some(2));
+
+    let mut r = std::io::repeat(b'a').take(1 << 20);
+    let err = alphaid.decode_reader(&mut r, b'\n').unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_encode_iter() {
+    let configs = vec![
+        AlphaId::<u64>::builder().pad(5).group(2, b'.').build(),
+        AlphaId::<u64>::builder().sortable(11).build(),
+        AlphaId::<u64>::builder()
+            .block_words(&["b"])
+            .suffix(b"!")
+            .build(),
+        AlphaId::<u64>::builder().emoji().build(),
+    ];
+    for alphaid in &configs {
+        for &n in &[0, 1, 63, 64, 1350997667, u64::MAX] {
+            let v: Vec<u8> = alphaid.encode_iter(n).unwrap().collect();
+            assert_eq!(v, alphaid.encode(n).unwrap());
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_encode_iter_random_pad() {
+    let alphaid = AlphaId::<u32>::builder()
+        .pad(6)
+        .pad_mode(PadMode::Random)
+        .build();
+    for n in 0..100 {
+        let v: Vec<u8> = alphaid.encode_iter(n).unwrap().collect();
+        assert_eq!(v.len(), 6);
+        assert_eq!(alphaid.decode(v), Ok(n));
+    }
+}
+
+#[test]
+fn test_encoded_len() {
+    let configs = vec![
+        AlphaId::<u64>::new(),
+        AlphaId::<u64>::builder().pad(5).group(2, b'.').build(),
+        AlphaId::<u64>::builder().sortable(11).suffix(b"!").build(),
+        AlphaId::<u64>::builder().block_words(&["b"]).build(),
+        AlphaId::<u64>::builder().emoji().build(),
+    ];
+    for alphaid in &configs {
+        for &n in &[0, 1, 63, 64, 4095, 4096, 1350997667, u64::MAX] {
+            let len = alphaid.encode(n).unwrap().len();
+            assert_eq!(alphaid.encoded_len(n), len);
+            assert!(len <= alphaid.max_encoded_len());
+        }
+    }
+}
+
+#[test]
+fn test_capacity_planning() {
+    let alphaid = AlphaId::<u64>::builder().pad(4).build();
+    assert_eq!(alphaid.max_value_for_len(3), None);
+    for len in 4..8 {
+        let max = alphaid.max_value_for_len(len).unwrap();
+        assert_eq!(alphaid.len_for(max), len);
+        assert_eq!(alphaid.len_for(max + 1), len + 1);
+        assert_eq!(alphaid.encode(max).unwrap().len(), len);
+    }
+    assert_eq!(alphaid.max_value_for_len(100), Some(u64::MAX));
+
+    let alphaid = AlphaId::<u64>::builder().sortable(3).build();
+    assert_eq!(alphaid.max_value_for_len(2), None);
+    assert_eq!(alphaid.max_value_for_len(3), Some(64u64.pow(3) - 1));
+    assert_eq!(alphaid.len_for(5), 3);
+}
+
+#[test]
+fn test_batch() {
+    let alphaid = AlphaId::<u64>::builder().pad(3).group(2, b'.').build();
+    let ns: Vec<u64> = (0..1000).map(|n| n * 7919).collect();
+    let vs = alphaid.encode_batch(&ns).unwrap();
+    for (n, v) in ns.iter().zip(&vs) {
+        assert_eq!(alphaid.encode(*n).as_ref(), Ok(v));
+    }
+    assert_eq!(alphaid.decode_batch(&vs), Ok(ns));
+    assert_eq!(
+        alphaid.decode_batch(&["abc", "a+c"]),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+}
+
+#[test]
+fn test_decode_long_input() {
+    let alphaid = AlphaId::<u128>::new();
+    let pad = AlphaId::<u128>::builder().pad(20).build();
+    let sortable = AlphaId::<u128>::builder().sortable(22).build();
+    let scattered = AlphaId::<u128>::builder()
+        .chars(b"qwertyuiopasdfghjklzxcvbnm".to_vec())
+        .build();
+    for alphaid in &[alphaid, pad, sortable, scattered] {
+        for &n in &[0, 1, u64::MAX as u128, u128::MAX / 3, u128::MAX] {
+            let v = alphaid.encode(n).unwrap();
+            assert_eq!(alphaid.decode(&v), Ok(n));
+
+            let mut v = v;
+            let last = v.len() - 1;
+            v[last] = b'+';
+            assert!(alphaid.decode(&v).is_err());
+        }
+    }
+    assert_eq!(
+        AlphaId::<u128>::new().decode(b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaab"),
+        Err(AlphaIdError::Overflow)
+    );
+}
+
+#[test]
+fn test_decode_simd_paths() {
+    let alphabets: &[&[u8]] = &[
+        b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789-_",
+        b"0123456789abcdef",
+        b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+        b"zyxwvutsrqponmlkjihgfedcba",
+    ];
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+    for chars in alphabets {
+        let paths = alphaid::__simd::translate_paths(chars, b"");
+        if chars[0] == b'z' {
+            assert!(paths.is_none());
+            continue;
+        }
+        let names: Vec<_> = paths.unwrap().iter().map(|&(name, _)| name).collect();
+        if cfg!(target_arch = "x86_64") {
+            assert!(names.contains(&"sse2"));
+        }
+        if cfg!(target_arch = "aarch64") {
+            assert!(names.contains(&"neon"));
+        }
+
+        for len in 0..100 {
+            let mut input: Vec<u8> = (0..len)
+                .map(|_| chars[next() as usize % chars.len()])
+                .collect();
+            // Every other input has a byte which is not in the alphabet.
+            if len > 0 && next() % 2 == 0 {
+                let i = next() as usize % len;
+                input[i] = b'+';
+            }
+            let paths = alphaid::__simd::translate_paths(chars, &input).unwrap();
+            let (_, scalar) = &paths[0];
+            for (name, digits) in &paths[1..] {
+                assert_eq!(digits, scalar, "{} path, input {:?}", name, input);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_decode_big_endian_bounds() {
+    let alphaid = AlphaId::<u64>::builder()
+        .chars(b"0123456789abcdefghijklmnopqrstuv".to_vec())
+        .order(DigitOrder::BigEndian)
+        .build();
+    assert_eq!(alphaid.encode(u64::MAX), Ok(b"fvvvvvvvvvvvv".to_vec()));
+    assert_eq!(alphaid.decode(b"fvvvvvvvvvvvv"), Ok(u64::MAX));
+    assert_eq!(alphaid.decode(b"0fvvvvvvvvvvvv"), Ok(u64::MAX));
+    assert_eq!(
+        alphaid.decode(b"g000000000000"),
+        Err(AlphaIdError::Overflow)
+    );
+    assert_eq!(alphaid.decode(b"ff+f"), Err(AlphaIdError::UnexpectedChar));
+}
+
+#[test]
+fn test_alphaid_const() {
+    use alphaid::AlphaIdConst;
+
+    let fixed = AlphaIdConst::<u64, 64>::default();
+    let alphaid = AlphaId::<u64>::new();
+    for &n in &[0, 1, 63, 64, 1350997667, u64::MAX] {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(fixed.encode(n), Ok(v.clone()));
+        assert_eq!(fixed.decode(&v), Ok(n));
+    }
+    assert_eq!(fixed.decode(b"__________q"), Err(AlphaIdError::Overflow));
+    assert_eq!(fixed.decode(b"a+"), Err(AlphaIdError::UnexpectedChar));
+
+    let mut chars = [0; 20];
+    chars.copy_from_slice(b"ABCDEFGHIJKLMNOPQRST");
+    let fixed = AlphaIdConst::<u32, 20>::new(chars);
+    let alphaid = AlphaId::<u32>::builder().chars(chars.to_vec()).build();
+    assert_eq!(fixed.encode(u32::MAX), alphaid.encode(u32::MAX));
+    let mut buf = [0; 4];
+    assert_eq!(
+        fixed.encode_to_slice(u32::MAX, &mut buf),
+        Err(AlphaIdError::BufferTooSmall)
+    );
+}
+
+#[test]
+fn test_alphaid_const_static() {
+    use alphaid::AlphaIdConst;
+
+    static IDS: AlphaIdConst<u32, 64> = AlphaIdConst::DEFAULT;
+    const HEX: AlphaIdConst<u32, 22> = AlphaIdConst::new(*b"0123456789abcdefABCDEF");
+
+    assert_eq!(IDS.decode(IDS.encode(1350997667).unwrap()), Ok(1350997667));
+    assert_eq!(HEX.decode(HEX.encode(u32::MAX).unwrap()), Ok(u32::MAX));
+}
+
+#[test]
+fn test_clone_shares_tables() {
+    let alphaid = AlphaId::<u64>::builder()
+        .pad(4)
+        .block_words(&["abc"])
+        .build();
+    let clones: Vec<_> = (0..8).map(|_| alphaid.clone()).collect();
+    std::thread::scope(|s| {
+        for (i, clone) in clones.into_iter().enumerate() {
+            let expected = alphaid.encode(i as u64).unwrap();
+            s.spawn(move || assert_eq!(clone.encode(i as u64), Ok(expected)));
+        }
+    });
+}
+
+#[test]
+fn test_overflow_boundaries() {
+    fn check<T>(max: T)
+    where
+        T: alphaid::UnsignedInteger + std::fmt::Debug + Into<u128>,
+    {
+        let max_u128: u128 = max.into();
+        for base in 17..=64 {
+            let chars = DEFAULT_CHARS.as_bytes()[..base].to_vec();
+            let alphaid = AlphaId::<T>::builder().chars(chars.clone()).build();
+            let v = alphaid.encode(max).unwrap();
+            assert_eq!(alphaid.decode(&v), Ok(max));
+
+            // The largest value with as many digits.
+            let all_max = vec![chars[base - 1]; v.len()];
+            let value = (base as u128).pow(v.len() as u32) - 1;
+            if value > max_u128 {
+                assert_eq!(alphaid.decode(&all_max), Err(AlphaIdError::Overflow));
+            } else {
+                assert_eq!(alphaid.decode(&all_max), Ok(max));
+            }
+
+            let mut longer = v.clone();
+            longer.push(chars[1]);
+            assert_eq!(alphaid.decode(&longer), Err(AlphaIdError::Overflow));
+        }
+    }
+
+    check(u16::MAX);
+    check(u32::MAX);
+    check(u64::MAX);
+
+    let alphaid = AlphaId::<u64>::builder().pad(3).build();
+    let mut v = vec![b'a'; 30];
+    v.push(b'b');
+    assert_eq!(alphaid.decode(&v), Err(AlphaIdError::Overflow));
+}
+
+const DEFAULT_CHARS: &str = "abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ-_";
+
+#[test]
+fn test_free_functions() {
+    let alphaid = AlphaId::<u128>::new();
+    for &n in &[0, 1, 1350997667, u64::MAX as u128, u128::MAX] {
+        let s = alphaid::encode(n);
+        assert_eq!(s.as_bytes(), &alphaid.encode(n).unwrap()[..]);
+        assert_eq!(alphaid::decode(&s), Ok(n));
+    }
+    assert_eq!(alphaid::decode("+"), Err(AlphaIdError::UnexpectedChar));
+}
+
+#[test]
+fn test_encoder() {
+    let alphaid = AlphaId::<u64>::builder().pad(3).prefix(b"id_").build();
+    let mut encoder = alphaid.encoder();
+    let mut decoder = alphaid.decoder();
+    for n in (0..100_000).step_by(997) {
+        let v = encoder.encode(n).unwrap().to_vec();
+        assert_eq!(alphaid.encode(n), Ok(v.clone()));
+        let s = encoder.encode_str(n).unwrap();
+        assert_eq!(s.as_bytes(), &v[..]);
+        assert_eq!(decoder.decode_str(s), Ok(n));
+        assert_eq!(decoder.decode(&v), Ok(n));
+    }
+    assert_eq!(
+        decoder.decode_str("id_é"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+}
+
+#[test]
+fn test_encode_small() {
+    let alphaid = AlphaId::<u128>::new();
+    let v = alphaid.encode_small(u128::MAX).unwrap();
+    assert!(!v.spilled());
+    assert_eq!(v.into_vec(), alphaid.encode(u128::MAX).unwrap());
+
+    let alphaid = AlphaId::<u128>::builder().prefix(b"customer_").build();
+    let v = alphaid.encode_small(u128::MAX).unwrap();
+    assert!(v.spilled());
+    assert_eq!(alphaid.decode(&v), Ok(u128::MAX));
+
+    let alphaid = AlphaId::<u64>::builder().sortable(2).build();
+    assert_eq!(alphaid.encode_small(u64::MAX), Err(AlphaIdError::Overflow));
+}
+
+#[test]
+fn test_decode_strict() {
+    let alphaid = AlphaId::<u64>::builder()
+        .pad(4)
+        .group(2, b'.')
+        .map_confusables(vec![(b"O".to_vec(), b'o')])
+        .chars(b"abcdefghijklmnopqrstuvwxyz0123456789".to_vec())
+        .build();
+    for n in (0..200_000).step_by(1009) {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(alphaid.decode_strict(&v), Ok(n));
+    }
+    let v = alphaid.encode(123_456).unwrap();
+    assert_eq!(v, b"mj.xd".to_vec());
+    for bad in &[&b"mjxd"[..], b"m.jxd", b"mj.xd.", b"mj..xd"] {
+        assert_eq!(alphaid.decode_strict(bad), Err(AlphaIdError::NonCanonical));
+    }
+    let v = (0..)
+        .map(|n| alphaid.encode(n).unwrap())
+        .find(|v| v.contains(&b'o'))
+        .unwrap();
+    let confused: Vec<u8> = v
+        .iter()
+        .map(|&b| if b == b'o' { b'O' } else { b })
+        .collect();
+    assert_eq!(alphaid.decode(&confused), alphaid.decode(&v));
+    assert_eq!(
+        alphaid.decode_strict(&confused),
+        Err(AlphaIdError::NonCanonical)
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_decode_strict_random_pad() {
+    let alphaid = AlphaId::<u64>::builder()
+        .pad(8)
+        .pad_mode(PadMode::Random)
+        .build();
+    for n in 0..100 {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(alphaid.decode_strict(&v), Ok(n));
+        let mut longer = v.clone();
+        longer.push(b'a');
+        assert_eq!(
+            alphaid.decode_strict(&longer),
+            Err(AlphaIdError::NonCanonical)
+        );
+    }
+}
+
+#[test]
+fn test_reject_empty() {
+    let alphaid = AlphaId::<u32>::builder().prefix(b"id_").build();
+    assert!(alphaid.decode(b"id_").is_ok());
+
+    let alphaid = AlphaId::<u32>::builder()
+        .prefix(b"id_")
+        .group(2, b'~')
+        .reject_empty(true)
+        .build();
+    assert_eq!(alphaid.decode(b"id_"), Err(AlphaIdError::EmptyInput));
+    assert_eq!(alphaid.decode(b"id_~"), Err(AlphaIdError::EmptyInput));
+    assert_eq!(alphaid.decode(b"a"), Err(AlphaIdError::PrefixMissed));
+    assert_eq!(alphaid.decode(b"id_a"), Ok(0));
+}
+
+#[test]
+fn test_max_input_len() {
+    let alphaid = AlphaId::<u64>::builder()
+        .prefix(b"id_")
+        .max_input_len(8)
+        .build();
+    let garbage = "?".repeat(10_000);
+    assert_eq!(alphaid.decode(&garbage), Err(AlphaIdError::InputTooLong));
+    assert_eq!(
+        alphaid.decode_str(&garbage),
+        Err(AlphaIdError::InputTooLong)
+    );
+    assert_eq!(
+        alphaid.decoder().decode_str(&garbage),
+        Err(AlphaIdError::InputTooLong)
+    );
+    assert!(alphaid.decode(b"id_90F7q").is_ok());
+    assert_eq!(
+        alphaid.decode(b"id_90F7qb"),
+        Err(AlphaIdError::InputTooLong)
+    );
+}
+
+#[test]
+fn test_max_len() {
+    let alphaid = AlphaId::<u64>::builder()
+        .prefix(b"id_")
+        .sign(b'~')
+        .max_len::<14>()
+        .build();
+    assert_eq!(alphaid.max_encoded_len(), 14);
+    assert_eq!(alphaid.encode(u64::MAX).map(|v| v.len()), Ok(14));
+    assert_eq!(alphaid.encode_signed(-1), Ok(b"id_~b".to_vec()));
+    assert_eq!(
+        alphaid.encode_signed(-(u64::MAX as i128)),
+        Err(AlphaIdError::InvalidLength)
+    );
+}
+
+#[test]
+#[should_panic(expected = "encoded results can be longer than max_len")]
+fn test_max_len_too_small() {
+    AlphaId::<u128>::builder().max_len::<12>().build();
+}
+
+#[test]
+fn test_pad_at_max() {
+    fn check<T>(max: T)
+    where
+        T: alphaid::UnsignedInteger + std::fmt::Debug + Into<u128>,
+    {
+        for &base in &[17, 36, 62, 64] {
+            let chars = DEFAULT_CHARS.as_bytes()[..base].to_vec();
+            let width = AlphaId::<T>::builder()
+                .chars(chars.clone())
+                .build()
+                .encoded_len(max) as u32;
+            for pad in 1..=width + 2 {
+                for &mode in &[
+                    PadMode::Shift,
+                    #[cfg(feature = "rand")]
+                    PadMode::Random,
+                ] {
+                    for &order in &[DigitOrder::LittleEndian, DigitOrder::BigEndian] {
+                        let alphaid = AlphaId::<T>::builder()
+                            .chars(chars.clone())
+                            .pad(pad)
+                            .pad_mode(mode)
+                            .order(order)
+                            .build();
+                        let v = alphaid.encode(max).unwrap();
+                        assert!(v.len() >= pad as usize);
+                        assert_eq!(alphaid.decode(&v), Ok(max), "base {} pad {}", base, pad);
+                    }
+                }
+            }
+        }
+    }
+
+    check(u8::MAX);
+    check(u16::MAX);
+    check(u32::MAX);
+    check(u64::MAX);
+    check(u128::MAX);
+}
+
+#[test]
+fn test_pad_mode_fill() {
+    let alphaid = AlphaId::<u64>::builder()
+        .pad(6)
+        .pad_mode(PadMode::Fill)
+        .order(DigitOrder::BigEndian)
+        .chars(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz".to_vec())
+        .build();
+    assert_eq!(alphaid.encode(0), Ok(b"000000".to_vec()));
+    assert_eq!(alphaid.encode(61), Ok(b"00000z".to_vec()));
+    assert_eq!(alphaid.encode(62), Ok(b"000010".to_vec()));
+    assert_eq!(alphaid.decode(b"00000z"), Ok(61));
+    assert_eq!(alphaid.decode(b"z"), Ok(61));
+    assert_eq!(alphaid.decode_strict(b"z"), Err(AlphaIdError::NonCanonical));
+    for n in (0..u64::MAX).step_by(u64::MAX as usize / 997) {
+        let v = alphaid.encode(n).unwrap();
+        assert!(v.len() >= 6);
+        assert_eq!(alphaid.decode_strict(&v), Ok(n));
+    }
+
+    let alphaid = AlphaId::<u32>::builder()
+        .pad(4)
+        .pad_mode(PadMode::Fill)
+        .build();
+    assert_eq!(alphaid.encode(1), Ok(b"baaa".to_vec()));
+    assert_eq!(alphaid.decode(b"baaa"), Ok(1));
+    assert_eq!(alphaid.decode(b"b"), Ok(1));
+}
+
+#[test]
+fn test_config_debug_redacts_keys() {
+    let alphaid = AlphaId::<u64>::builder()
+        .tag([0xab; 16], 2)
+        .old_tag_keys(&[[0xcd; 16]])
+        .build();
+    let config = alphaid.to_config();
+    let debug = format!("{:?}", config);
+    assert!(debug.contains("tag: Some((<redacted>, 2))"), "{}", debug);
+    assert!(debug.contains("old_tag_keys: [<redacted>]"), "{}", debug);
+    assert!(
+        !debug.contains("171") && !debug.contains("205"),
+        "{}",
+        debug
+    );
+    assert!(debug.contains("pad: 1"));
+}
+
+#[test]
+fn test_debug_eq_getters() {
+    let alphaid = AlphaId::<u32>::builder().pad(4).prefix(b"id_").build();
+    let debug = format!("{:?}", alphaid);
+    assert!(debug.starts_with("AlphaId { chars: \"abcdefghijklmnopqrstuvwxyz0123456789"));
+    assert!(debug.contains("pad: 4"));
+    assert!(debug.contains("prefix: \"id_\""));
+
+    assert_eq!(alphaid.clone(), alphaid);
+    assert_ne!(AlphaId::<u32>::new(), alphaid);
+    assert_eq!(alphaid.alphabet(), DEFAULT_CHARS.as_bytes());
+    assert_eq!(alphaid.base(), 64);
+    assert_eq!(alphaid.pad(), 4);
+
+    let alphaid = AlphaId::<u32>::builder()
+        .chars_str("абвгдежзийклмнопрстуфхцчшщъыьэюя")
+        .build();
+    assert!(alphaid.alphabet().is_empty());
+    assert_eq!(alphaid.symbols().len(), 32);
+}
+
+#[test]
+fn test_builder_parse() {
+    let alphaid = Builder::<u64>::parse(
+        "chars=0123456789abcdefghijklmnopqrstuvwxyz; pad=4;pad_mode=fill;order=big_endian;\
+         group=2:-;prefix=id_;reject_empty=true;max_input_len=32;",
+    )
+    .unwrap()
+    .build();
+    assert_eq!(alphaid.encode(35), Ok(b"id_00-0z".to_vec()));
+    assert_eq!(alphaid.decode(b"id_"), Err(AlphaIdError::EmptyInput));
+
+    assert_eq!(
+        Builder::<u32>::parse("").unwrap().build(),
+        AlphaId::<u32>::new()
+    );
+
+    for bad in &[
+        "salt=abc",
+        "pad",
+        "pad=-1",
+        "pad=0",
+        "pad_mode=zigzag",
+        "chars=abc",
+        "chars=aabcdefghijklmnopqrstuvwxyz",
+        "group=4:a",
+        "group=0:-",
+        "pad_char=!",
+        "sortable=4;pad_char=a",
+        "sortable=4;block_words=cat",
+        "block_words=cat,,dog",
+        "reject_empty=yes",
+    ] {
+        assert!(
+            matches!(Builder::<u32>::parse(bad), Err(AlphaIdError::InvalidConfig)),
+            "{}",
+            bad
+        );
+    }
+    let chars: String = (0x4e00..0x4e00 + 300).filter_map(char::from_u32).collect();
+    assert!(Builder::<u16>::parse(&format!("chars={}", chars)).is_ok());
+    assert!(matches!(
+        Builder::<u8>::parse(&format!("chars={}", chars)),
+        Err(AlphaIdError::InvalidConfig)
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_builder_from_env() {
+    std::env::set_var("ALPHAID_TEST_PAD", "3");
+    std::env::set_var("ALPHAID_TEST_PREFIX", "usr_");
+    let alphaid = Builder::<u32>::from_env("ALPHAID_TEST_").unwrap().build();
+    assert_eq!(alphaid.encode(0), Ok(b"usr_aab".to_vec()));
+
+    std::env::set_var("ALPHAID_BAD_PAD_MODE", "zigzag");
+    assert!(matches!(
+        Builder::<u32>::from_env("ALPHAID_BAD_"),
+        Err(AlphaIdError::InvalidConfig)
+    ));
+}
+
+#[test]
+fn test_alphaid_registry() {
+    let mut padded = Config::default();
+    padded.pad = 4;
+    let mut prefixed = Config::default();
+    prefixed.prefix = b"id_".to_vec();
+    let registry = AlphaIdRegistry::<u64>::from_configs(vec![
+        ("tenant-a", padded.clone()),
+        ("tenant-b", prefixed.clone()),
+    ])
+    .unwrap();
+    assert_eq!(
+        registry.names().collect::<Vec<_>>(),
+        ["tenant-a", "tenant-b"]
+    );
+
+    assert_eq!(registry.encode("tenant-a", 0), Ok(b"aaab".to_vec()));
+    assert_eq!(registry.encode("tenant-b", 0), Ok(b"id_a".to_vec()));
+    assert_eq!(registry.decode("tenant-a", b"aaab"), Ok(0));
+    assert_eq!(registry.decode("tenant-b", b"id_a"), Ok(0));
+    assert_eq!(
+        registry.decode("tenant-b", b"aaab"),
+        Err(AlphaIdError::PrefixMissed)
+    );
+    assert_eq!(
+        registry.decode("nobody", b"a"),
+        Err(AlphaIdError::UnknownName)
+    );
+    assert!(registry.get("tenant-a").is_some());
+
+    assert_eq!(
+        AlphaIdRegistry::<u64>::from_configs(vec![
+            ("tenant-a", padded.clone()),
+            ("tenant-a", prefixed),
+        ])
+        .err(),
+        Some(RegistryError::Duplicate("tenant-a".into()))
+    );
+    let mut invalid = Config::default();
+    invalid.chars = b"aa".to_vec();
+    assert_eq!(
+        AlphaIdRegistry::<u64>::from_configs(vec![("tenant-a", padded), ("tenant-c", invalid)])
+            .err(),
+        Some(RegistryError::InvalidConfig("tenant-c".into()))
+    );
+}
+
+#[test]
+fn test_versioned_decoder() {
+    let empty = VersionedDecoder::<u64>::new();
+    assert_eq!(empty.encode(1), Err(AlphaIdError::UnknownVersion));
+    assert_eq!(empty.current(), None);
+
+    let v1 = AlphaId::<u64>::new();
+    let v2 = AlphaId::<u64>::builder()
+        .chars(b"0123456789abcdefghijklmnopqrstuvwxyz".to_vec())
+        .build();
+    let old = VersionedDecoder::new().register(b'A', v1.clone());
+    let ids = VersionedDecoder::new()
+        .register(b'A', v1)
+        .register(b'B', v2);
+    assert_eq!(ids.current(), Some(b'B'));
+
+    for n in (0..1_000_000).step_by(9973) {
+        let link = old.encode(n).unwrap();
+        assert_eq!(link[0], b'A');
+        assert_eq!(ids.decode(&link), Ok((b'A', n)));
+        let v = ids.encode(n).unwrap();
+        assert_eq!(v[0], b'B');
+        assert_eq!(ids.decode(&v), Ok((b'B', n)));
+    }
+    assert_eq!(ids.decode(b"Cabc"), Err(AlphaIdError::UnknownVersion));
+    assert_eq!(ids.decode(b""), Err(AlphaIdError::UnknownVersion));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_expiring_ids() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let alphaid = AlphaId::<u64>::builder().prefix(b"dl_").build();
+    let issued = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let v = alphaid.encode_issued_at(123_456, issued).unwrap();
+    assert_eq!(alphaid.decode_issued_at(&v), Ok((123_456, issued)));
+    assert_eq!(
+        alphaid.decode_with_ttl(&v, Duration::from_secs(3600)),
+        Err(AlphaIdError::Expired)
+    );
+
+    let v = alphaid.encode_issued_at(7, SystemTime::now()).unwrap();
+    assert_eq!(
+        alphaid.decode_with_ttl(&v, Duration::from_secs(3600)),
+        Ok(7)
+    );
+    let future = SystemTime::now() + Duration::from_secs(30);
+    let v = alphaid.encode_issued_at(7, future).unwrap();
+    assert_eq!(
+        alphaid.decode_with_ttl(&v, Duration::from_secs(3600)),
+        Ok(7)
+    );
+
+    assert_eq!(
+        alphaid.encode_issued_at(1 << 32, issued),
+        Err(AlphaIdError::Overflow)
+    );
+    assert!(AlphaId::<u128>::new()
+        .encode_issued_at(u64::MAX as u128, issued)
+        .is_ok());
+    assert_eq!(
+        AlphaId::<u32>::new().encode_issued_at(1, issued),
+        Err(AlphaIdError::Overflow)
+    );
+
+    // Moving the time of a tagged ID forward breaks its tag.
+    let tagged = AlphaId::<u128>::builder()
+        .tag(*b"0123456789abcdef", 4)
+        .build();
+    let plain = AlphaId::<u128>::new();
+    let v = tagged.encode_issued_at(7, issued).unwrap();
+    let packed = plain.decode(&v).unwrap();
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let shift = (now.as_secs() as u128 - 1_700_000_000) << 24;
+    let forged = plain.encode(packed + shift).unwrap();
+    // The 4 tag symbols take the low 24 bits.
+    assert_eq!((packed + shift) >> 24 & 0xffff_ffff, now.as_secs() as u128);
+    assert_eq!(
+        tagged.decode_with_ttl(&forged, Duration::from_secs(3600)),
+        Err(AlphaIdError::TagMismatch)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sortable_gen() {
+    use alphaid::SortableGen;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let gen = SortableGen::new(
+        AlphaId::<u128>::builder()
+            .chars(b"0123456789ABCDEFGHJKMNPQRSTVWXYZ".to_vec())
+            .sortable(26)
+            .build(),
+    );
+    let ids: Vec<Vec<u8>> = (0..10_000).map(|_| gen.next_id().unwrap()).collect();
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    assert!(ids.iter().all(|v| v.len() == 26));
+
+    let n = gen.next_raw();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    assert!(now - SortableGen::timestamp_ms(n) < 60_000);
+}
+
+#[cfg(all(feature = "std", feature = "rand"))]
+#[test]
+fn test_sortable_gen_with_rng() {
+    use alphaid::SortableGen;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    let gen = SortableGen::new(AlphaId::<u128>::new()).with_rng(ChaCha20Rng::seed_from_u64(1));
+    let same = SortableGen::new(AlphaId::<u128>::new()).with_rng(ChaCha20Rng::seed_from_u64(1));
+    let (a, b) = (gen.next_raw(), same.next_raw());
+    assert_eq!(a & ((1 << 80) - 1), b & ((1 << 80) - 1));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_snowflake_gen() {
+    use alphaid::SnowflakeGen;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let gen = SnowflakeGen::new(1023, AlphaId::<u64>::builder().sortable(11).build());
+    let ids: Vec<(u64, Vec<u8>)> = (0..20_000).map(|_| gen.next_id().unwrap()).collect();
+    assert!(ids.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+    for (n, slug) in &ids {
+        assert_eq!(gen.decode(slug), Ok(*n));
+        assert_eq!(SnowflakeGen::worker_id(*n), 1023);
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    let (n, _) = ids[0];
+    assert!(now - gen.timestamp_ms(n) < 60_000);
+
+    let gen = SnowflakeGen::new(1, AlphaId::<u64>::new()).epoch_ms(now + 3_600_000);
+    let a = gen.next_raw();
+    let b = gen.next_raw();
+    assert!(a < b);
+    assert_eq!(SnowflakeGen::worker_id(b), 1);
+}
+
+#[test]
+fn test_sequential_gen() {
+    use alphaid::SequentialGen;
+
+    fn assert_send_sync<S: Send + Sync>(_: &S) {}
+
+    let gen = SequentialGen::new(AlphaId::<u64>::new());
+    assert_send_sync(&gen);
+    assert_eq!(gen.next_id(), Ok(b"a".to_vec()));
+    assert_eq!(gen.next_id(), Ok(b"b".to_vec()));
+
+    let gen = SequentialGen::new(AlphaId::<u64>::new()).start(1).step(2);
+    let mut seen: Vec<u64> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                s.spawn(|| {
+                    (0..1000)
+                        .map(|_| gen.next_raw().unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect()
+    });
+    seen.sort_unstable();
+    assert_eq!(seen, (0..4000).map(|i| 2 * i + 1).collect::<Vec<_>>());
+
+    let gen = SequentialGen::new(AlphaId::<u16>::new()).start(u64::from(u16::MAX));
+    assert_eq!(gen.next_raw(), Ok(u16::MAX));
+    assert_eq!(gen.next_raw(), Err(AlphaIdError::Overflow));
+
+    let gen = SequentialGen::new(AlphaId::<u64>::new()).start(u64::MAX);
+    assert_eq!(gen.next_raw(), Err(AlphaIdError::Overflow));
+}
+
+#[test]
+fn test_source_gen() {
+    use alphaid::{SequenceSource, SourceError, SourceGen};
+    use std::cell::Cell;
+
+    struct Flaky(Cell<u64>);
+
+    impl SequenceSource for Flaky {
+        type Error = &'static str;
+
+        fn next(&self) -> Result<u64, &'static str> {
+            let n = self.0.get();
+            self.0.set(n + 1);
+            if n % 2 == 1 {
+                Err("connection reset")
+            } else {
+                Ok(n * 100_000)
+            }
+        }
+    }
+
+    let gen = SourceGen::new(Flaky(Cell::new(0)), AlphaId::<u16>::new());
+    assert_eq!(gen.next_id(), Ok(b"a".to_vec()));
+    assert_eq!(gen.next_id(), Err(SourceError::Source("connection reset")));
+    assert_eq!(
+        gen.next_id(),
+        Err(SourceError::Encode(AlphaIdError::Overflow))
+    );
+    assert_eq!(
+        SourceError::<&str>::Source("connection reset").to_string(),
+        "sequence source failed: connection reset"
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_random() {
+    let alphaid = AlphaId::<u64>::builder().prefix(b"tok_").build();
+    for len in 1..=10 {
+        for _ in 0..100 {
+            let (n, v) = alphaid.random(len).unwrap();
+            assert_eq!(v.len(), len + 4);
+            assert_eq!(alphaid.decode(&v), Ok(n));
+        }
+    }
+    assert_eq!(alphaid.random(0), Err(AlphaIdError::InvalidLength));
+    assert_eq!(alphaid.random(12), Err(AlphaIdError::InvalidLength));
+    assert!(alphaid.random(11).is_ok());
+
+    let alphaid = AlphaId::<u8>::builder().pad(3).build();
+    assert_eq!(alphaid.random(1), Err(AlphaIdError::InvalidLength));
+    let (_, v) = alphaid.random(3).unwrap();
+    assert_eq!(v.len(), 3);
+
+    let alphaid = AlphaId::<u32>::new();
+    let mut seen = [false; 11];
+    for _ in 0..1000 {
+        let (n, _) = alphaid.random_in(10..=20).unwrap();
+        seen[n as usize - 10] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+    assert_eq!(alphaid.random_in(5..=5).map(|(n, _)| n), Ok(5));
+    assert_eq!(
+        alphaid.random_in(u32::MAX..=u32::MAX).map(|(n, _)| n),
+        Ok(u32::MAX)
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_random_with() {
+    use alphaid::UniqueGen;
+    use rand_chacha::rand_core::{CryptoRng, Error, RngCore, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    let alphaid = AlphaId::<u64>::new();
+    let mut a = ChaCha20Rng::seed_from_u64(7);
+    let mut b = ChaCha20Rng::seed_from_u64(7);
+    for len in 1..=11 {
+        let (n, v) = alphaid.random_with(len, &mut a).unwrap();
+        assert_eq!(alphaid.random_with(len, &mut b), Ok((n, v.clone())));
+        assert_eq!(v.len(), len);
+    }
+    assert_eq!(
+        alphaid.random_in_with(10..=20, &mut a),
+        alphaid.random_in_with(10..=20, &mut b)
+    );
+
+    // A source which always returns the same number.
+    struct Fixed(u64);
+    impl RngCore for Fixed {
+        fn next_u32(&mut self) -> u32 {
+            self.0 as u32
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(self.0 as u8);
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+    impl CryptoRng for Fixed {}
+    assert_eq!(
+        alphaid
+            .random_in_with(10..=20, &mut Fixed(3))
+            .map(|(n, _)| n),
+        Ok(13)
+    );
+
+    let gen = UniqueGen::new(alphaid, 4, |_: &[u8]| false);
+    assert_eq!(
+        gen.next_id_with(&mut ChaCha20Rng::seed_from_u64(1)),
+        gen.next_id_with(&mut ChaCha20Rng::seed_from_u64(1))
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_unique_gen() {
+    use alphaid::UniqueGen;
+    use std::cell::RefCell;
+    use std::collections::HashSet;
+
+    let alphaid = AlphaId::<u64>::builder().pad(2).build();
+    // Take every ID of two symbols.
+    let gen = UniqueGen::new(alphaid.clone(), 2, |v: &[u8]| v.len() == 2).max_attempts(10);
+    assert_eq!(gen.next_id(), Err(AlphaIdError::Exhausted));
+
+    let gen = UniqueGen::new(alphaid, 2, |v: &[u8]| v.len() == 2).grow_after(3);
+    let (n, v) = gen.next_id().unwrap();
+    assert_eq!(v.len(), 3);
+    assert!(n >= 64 * 64);
+
+    let issued = RefCell::new(HashSet::new());
+    let gen = UniqueGen::new(AlphaId::<u8>::new(), 1, |v: &[u8]| {
+        issued.borrow().contains(v)
+    })
+    .grow_after(20)
+    .max_attempts(1000);
+    let err = loop {
+        match gen.next_id() {
+            Ok((_, v)) => assert!(issued.borrow_mut().insert(v)),
+            Err(e) => break e,
+        }
+    };
+    // The length eventually grows beyond what u8 can encode.
+    assert_eq!(err, AlphaIdError::InvalidLength);
+    assert!(issued.borrow().len() > 64);
+}
+
+#[test]
+fn test_schema() {
+    use alphaid::Schema;
+
+    let schema = Schema::new()
+        .field("shard", 8)
+        .field("kind", 4)
+        .field("seq", 52);
+    assert_eq!(schema.bits(), 64);
+    assert_eq!(
+        schema.pack(&[("seq", 1), ("shard", 0xAB), ("kind", 0xC)]),
+        Ok(0xABC0_0000_0000_0001)
+    );
+    assert_eq!(
+        schema.pack(&[("kind", 16)]),
+        Err(AlphaIdError::InvalidNumber)
+    );
+    assert_eq!(schema.pack(&[("size", 1)]), Err(AlphaIdError::UnknownName));
+    assert_eq!(schema.unpack(1 << 64), Err(AlphaIdError::Overflow));
+
+    let fields = schema.unpack(0xABC0_0000_0000_0001).unwrap();
+    assert_eq!(fields["shard"], 0xAB);
+    assert_eq!(fields["kind"], 0xC);
+    assert_eq!(fields["seq"], 1);
+
+    let wide = Schema::new().field("hi", 64).field("lo", 64);
+    let alphaid = AlphaId::<u128>::new();
+    let v = wide
+        .encode(&alphaid, &[("hi", u64::MAX), ("lo", 7)])
+        .unwrap();
+    let fields = wide.decode(&alphaid, &v).unwrap();
+    assert_eq!(fields["hi"], u64::MAX);
+    assert_eq!(fields["lo"], 7);
+}
+
+#[test]
+fn test_encode_tuple() {
+    let alphaid = AlphaId::<u64>::new();
+    for &(a, b) in &[(0, 0), (1, 0), (0, 1), (12345, 678), (u64::MAX, u64::MAX)] {
+        let v = alphaid.encode_pair(a, b).unwrap();
+        assert_eq!(alphaid.decode_pair(&v), Ok((a, b)));
+    }
+    let v = alphaid.encode_triple(7, u64::MAX, 0).unwrap();
+    assert_eq!(alphaid.decode_triple(&v), Ok((7, u64::MAX, 0)));
+    assert_eq!(alphaid.decode_pair(b"b"), Err(AlphaIdError::InvalidLength));
+    assert_eq!(alphaid.decode_pair(b"cb"), Err(AlphaIdError::InvalidLength));
+    assert_eq!(
+        alphaid.decode_pair(b"b!b"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+
+    // Lengths take two digits when a number can have more digits than the
+    // base.
+    let alphaid = AlphaId::<u128>::builder()
+        .chars(b"0123456789abcdefg".to_vec())
+        .prefix(b"p/")
+        .group(4, b'.')
+        .build();
+    let v = alphaid.encode_pair(u128::MAX, 1).unwrap();
+    assert!(v.starts_with(b"p/"));
+    assert_eq!(alphaid.decode_pair(&v), Ok((u128::MAX, 1)));
+    let v = alphaid.encode_triple(1, 2, 3).unwrap();
+    assert_eq!(alphaid.decode_triple(&v), Ok((1, 2, 3)));
+
+    let alphaid = AlphaId::<u32>::builder().emoji().build();
+    let v = alphaid.encode_pair(42, 4242).unwrap();
+    assert_eq!(alphaid.decode_pair(&v), Ok((42, 4242)));
+}
+
+#[test]
+fn test_morton() {
+    let alphaid = AlphaId::<u64>::builder()
+        .order(DigitOrder::BigEndian)
+        .build();
+    assert_eq!(alphaid.encode_morton(0, 0), alphaid.encode(0));
+    assert_eq!(alphaid.encode_morton(1, 0), alphaid.encode(1));
+    assert_eq!(alphaid.encode_morton(0, 1), alphaid.encode(2));
+    assert_eq!(alphaid.encode_morton(3, 3), alphaid.encode(15));
+    assert_eq!(
+        alphaid.encode_morton(u32::MAX, u32::MAX),
+        alphaid.encode(u64::MAX)
+    );
+    for &(x, y) in &[(0, u32::MAX), (u32::MAX, 0), (123_456, 654_321)] {
+        let v = alphaid.encode_morton(x, y).unwrap();
+        assert_eq!(alphaid.decode_morton(&v), Ok((x, y)));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_timestamp() {
+    use alphaid::TimeUnit;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let alphaid = AlphaId::<u64>::new();
+    let t = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    let v = alphaid
+        .encode_timestamp(t, UNIX_EPOCH, TimeUnit::Millis)
+        .unwrap();
+    assert_eq!(
+        alphaid.decode_timestamp(&v, UNIX_EPOCH, TimeUnit::Millis),
+        Ok(t)
+    );
+
+    let v = alphaid
+        .encode_timestamp(t, UNIX_EPOCH, TimeUnit::Seconds)
+        .unwrap();
+    assert_eq!(
+        alphaid.decode_timestamp(&v, UNIX_EPOCH, TimeUnit::Seconds),
+        Ok(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+    );
+    assert_eq!(
+        alphaid.encode_timestamp(UNIX_EPOCH, t, TimeUnit::Seconds),
+        Err(AlphaIdError::InvalidNumber)
+    );
+
+    // Sixty days fit into a u32 in seconds, not in millis.
+    let alphaid = AlphaId::<u32>::new();
+    let day = UNIX_EPOCH + Duration::from_secs(86_400 * 60);
+    assert!(alphaid
+        .encode_timestamp(day, UNIX_EPOCH, TimeUnit::Seconds)
+        .is_ok());
+    assert_eq!(
+        alphaid.encode_timestamp(day, UNIX_EPOCH, TimeUnit::Millis),
+        Err(AlphaIdError::Overflow)
+    );
+}
+
+#[test]
+fn test_transcode() {
+    let old = AlphaId::<u64>::builder().pad(4).build();
+    let new = AlphaId::<u64>::builder()
+        .chars(b"0123456789abcdefghijklmnopqrstuvwxyz".to_vec())
+        .pad(6)
+        .pad_mode(PadMode::Fill)
+        .order(DigitOrder::BigEndian)
+        .build();
+    for n in (0..1_000_000).step_by(7919) {
+        let v = old.encode(n).unwrap();
+        let migrated = old.transcode(&new, &v).unwrap();
+        assert_eq!(new.decode(&migrated), Ok(n));
+        assert_eq!(new.transcode(&old, &migrated), Ok(v));
+    }
+    assert_eq!(
+        old.transcode(&new, b"a!"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+}
+
+#[test]
+fn test_decode_prefix() {
+    let alphaid = AlphaId::<u64>::builder()
+        .chars(b"0123456789abcdefghijklmnopqrstuvwxyz".to_vec())
+        .prefix(b"v/")
+        .suffix(b"!")
+        .group(3, b'.')
+        .build();
+    let v = alphaid.encode(123_456_789).unwrap();
+    let mut input = v.clone();
+    input.extend_from_slice(b".jpg");
+    assert_eq!(
+        alphaid.decode_prefix(&input),
+        Ok((123_456_789, &b".jpg"[..]))
+    );
+    assert_eq!(alphaid.decode_prefix(&v), Ok((123_456_789, &b""[..])));
+
+    assert_eq!(
+        alphaid.decode_prefix(b"x/abc!"),
+        Err(AlphaIdError::PrefixMissed)
+    );
+    assert_eq!(
+        alphaid.decode_prefix(b"v/.abc!"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(
+        alphaid.decode_prefix(b"v/abc?"),
+        Err(AlphaIdError::SuffixMissed)
+    );
+
+    let alphaid = AlphaId::<u32>::builder().emoji().build();
+    let mut input = alphaid.encode(1350997667).unwrap();
+    input.extend_from_slice("🙂 rest".as_bytes());
+    assert_eq!(
+        alphaid.decode_prefix(&input),
+        Ok((1350997667, "🙂 rest".as_bytes()))
+    );
+}
+
+#[test]
+fn test_decode_prefix_suffix_of_symbols() {
+    let alphaid = AlphaId::<u32>::builder()
+        .prefix(b"p_")
+        .suffix(b"_s")
+        .group(3, b'.')
+        .build();
+    for n in (0..2000).chain([123_456_789, u32::MAX]) {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(alphaid.decode_prefix(&v), Ok((n, &b""[..])), "{}", n);
+        let mut input = v.clone();
+        input.extend_from_slice(b"/x");
+        assert_eq!(alphaid.decode_prefix(&input), Ok((n, &b"/x"[..])), "{}", n);
+    }
+    assert_eq!(
+        alphaid.decode_prefix(b"p_abc.d"),
+        Err(AlphaIdError::SuffixMissed)
+    );
+}
+
+#[test]
+fn test_decode_lossy() {
+    let alphaid = AlphaId::<u64>::builder()
+        .chars(b"0123456789abcdefghijklmnopqrstuvwxyz".to_vec())
+        .prefix(b"id_")
+        .group(4, b'-')
+        .map_confusables(vec![(b"O".to_vec(), b'0')])
+        .build();
+    let v = alphaid.encode(9_876_543_210).unwrap();
+    let s = String::from_utf8(v.clone()).unwrap();
+    assert_eq!(alphaid.decode_lossy(&v), Ok((9_876_543_210, 1)));
+    let pasted = format!("  {}.\u{a0}", s.replace('0', "O"));
+    assert_eq!(
+        alphaid.decode_lossy(pasted.as_bytes()),
+        Ok((9_876_543_210, 6))
+    );
+    let unprefixed = format!("{}.", &s[3..]);
+    assert_eq!(
+        alphaid.decode_lossy(unprefixed.as_bytes()),
+        Ok((9_876_543_210, 2))
+    );
+    assert_eq!(
+        alphaid.decode(pasted.as_bytes()),
+        Err(AlphaIdError::PrefixMissed)
+    );
+}
+
+#[test]
+fn test_is_valid_canonicalize() {
+    let alphaid = AlphaId::<u16>::builder()
+        .group(2, b'.')
+        .map_confusables(vec![(b"$".to_vec(), b'a')])
+        .build();
+    let v = alphaid.encode(4000).unwrap();
+    assert!(alphaid.is_valid(&v));
+    assert!(!alphaid.is_valid(b"!"));
+    assert!(!alphaid.is_valid(b"____"));
+
+    let mut sloppy: Vec<u8> = v.iter().copied().filter(|&b| b != b'.').collect();
+    sloppy.extend_from_slice(b"$$");
+    assert_eq!(alphaid.canonicalize(&sloppy), Ok(v));
+    assert_eq!(alphaid.canonicalize(b"____"), Err(AlphaIdError::Overflow));
+}
+
+#[test]
+fn test_validate() {
+    use alphaid::Problem;
+
+    let alphaid = AlphaId::<u16>::builder()
+        .prefix(b"<")
+        .suffix(b">")
+        .group(2, b'.')
+        .build();
+    assert!(alphaid.validate(b"<ab.c>").is_valid());
+    assert_eq!(
+        alphaid.validate(b"ab.c").problems(),
+        &[
+            Problem::Error(AlphaIdError::PrefixMissed),
+            Problem::Error(AlphaIdError::SuffixMissed),
+        ]
+    );
+    assert_eq!(
+        alphaid.validate("<a!b.é>".as_bytes()).problems(),
+        &[Problem::UnexpectedChar(2), Problem::UnexpectedChar(5)]
+    );
+    assert_eq!(
+        alphaid.validate(b"<__.__.__>").problems(),
+        &[Problem::Error(AlphaIdError::Overflow)]
+    );
+    assert_eq!(
+        alphaid.validate(b"<ba.a>").problems(),
+        &[Problem::Error(AlphaIdError::NonCanonical)]
+    );
+}
+
+#[test]
+fn test_iter_ext() {
+    use alphaid::AlphaIdIterExt;
+
+    let alphaid = AlphaId::<u32>::new();
+    let ns = [0, 1, 1350997667, u32::MAX];
+    let encode = ns.iter().alpha_encode(&alphaid);
+    assert_eq!(encode.len(), 4);
+    let vs: Vec<Vec<u8>> = encode.collect::<Result<_, _>>().unwrap();
+    assert_eq!(vs, alphaid.encode_batch(&ns).unwrap());
+    let owned: Vec<_> = ns.iter().copied().alpha_encode(&alphaid).rev().collect();
+    assert_eq!(owned[0], alphaid.encode(u32::MAX));
+
+    let decoded: Vec<u32> = vs
+        .iter()
+        .alpha_decode(&alphaid)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(decoded, ns);
+    let mut decode = ["b", "!", "90F7qb"].iter().alpha_decode(&alphaid);
+    assert_eq!(decode.next(), Some(Ok(1)));
+    assert_eq!(decode.next(), Some(Err(AlphaIdError::UnexpectedChar)));
+    assert_eq!(decode.next(), Some(Ok(1350997667)));
+    assert_eq!(decode.next(), None);
+}
+
+#[test]
+fn test_stream_decoder() {
+    let alphaid = AlphaId::<u64>::builder()
+        .prefix(b"id_")
+        .group(3, b'.')
+        .build();
+    let v = alphaid.encode(u64::MAX).unwrap();
+    let mut decoder = alphaid.stream_decoder();
+    for chunk in v.chunks(2) {
+        decoder.push(chunk).unwrap();
+    }
+    assert_eq!(decoder.finish(), Ok(u64::MAX));
+
+    decoder.push(b"id_").unwrap();
+    assert_eq!(decoder.finish(), alphaid.decode(b"id_"));
+
+    decoder.push(&v).unwrap();
+    assert_eq!(decoder.push(b"a"), Err(AlphaIdError::InvalidLength));
+    assert_eq!(decoder.finish(), Ok(u64::MAX));
+
+    let alphaid = AlphaId::<u64>::builder().max_input_len(4).build();
+    let mut decoder = alphaid.stream_decoder();
+    decoder.push(b"abc").unwrap();
+    assert_eq!(decoder.push(b"de"), Err(AlphaIdError::InputTooLong));
+    decoder.reset();
+    decoder.push(b"b").unwrap();
+    assert_eq!(decoder.finish(), Ok(1));
+}
+
+#[test]
+fn test_decode_ct() {
+    let alphaids = [
+        AlphaId::<u32>::new(),
+        AlphaId::<u32>::builder()
+            .prefix(b"id_")
+            .suffix(b"!")
+            .group(2, b'.')
+            .pad(4)
+            .build(),
+        AlphaId::<u32>::builder()
+            .order(DigitOrder::BigEndian)
+            .reject_empty(true)
+            .build(),
+    ];
+    for alphaid in &alphaids {
+        for &n in &[0, 1, 1350997667, u32::MAX] {
+            let v = alphaid.encode(n).unwrap();
+            assert_eq!(alphaid.decode_ct(&v), Ok(n));
+        }
+        for v in [
+            &b""[..],
+            b"id_",
+            b"id_!",
+            b"x_ab!",
+            b"id_ab?",
+            b"id_a$.b!",
+            b"__________",
+        ] {
+            assert_eq!(alphaid.decode_ct(v), alphaid.decode(v), "{:?}", v);
+        }
+    }
+
+    let alphaid = AlphaId::<u32>::builder().emoji().build();
+    assert_eq!(alphaid.decode_ct("🐶"), Err(AlphaIdError::InvalidConfig));
+}
+
+#[test]
+fn test_tag() {
+    let key = *b"0123456789abcdef";
+    let plain = AlphaId::<u64>::new();
+    for alphaid in [
+        AlphaId::<u64>::builder().tag(key, 2).build(),
+        AlphaId::<u64>::builder()
+            .tag(key, 4)
+            .pad(6)
+            .order(DigitOrder::BigEndian)
+            .build(),
+        #[cfg(feature = "rand")]
+        AlphaId::<u64>::builder()
+            .tag(key, 3)
+            .pad_mode(PadMode::Random)
+            .pad(8)
+            .build(),
+    ] {
+        for n in [0, 1, 1350997667, u64::MAX >> 24] {
+            let v = alphaid.encode(n).unwrap();
+            assert_eq!(alphaid.decode(&v), Ok(n));
+            assert_eq!(alphaid.decode_strict(&v), Ok(n));
+        }
+    }
+
+    let alphaid = AlphaId::<u64>::builder().tag(key, 2).build();
+    assert_eq!(alphaid.encode(u64::MAX), Err(AlphaIdError::Overflow));
+    let v = alphaid.encode(1350997667).unwrap();
+    assert_eq!(v.len(), plain.encode(1350997667).unwrap().len() + 2);
+    let mismatches = (0..64 * 64)
+        .filter(|t| {
+            alphaid
+                .decode(plain.encode(1350997667 * 64 * 64 + t).unwrap())
+                .is_err()
+        })
+        .count();
+    assert_eq!(mismatches, 64 * 64 - 1);
+    let other = AlphaId::<u64>::builder()
+        .tag(*b"fedcba9876543210", 2)
+        .build();
+    assert_ne!(other.encode(1350997667).unwrap(), v);
+
+    // The tags are the SipHash-2-4 of the number, so the encodings are
+    // stable across versions.
+    let alphaid = AlphaId::<u64>::builder().tag(key, 4).build();
+    assert_eq!(alphaid.encode(1350997667), Ok(b"UZ_890F7qb".to_vec()));
+}
+
+#[test]
+#[should_panic(expected = "tag must fit into the number type")]
+fn test_tag_too_long() {
+    AlphaId::<u16>::builder().tag([0; 16], 3).build();
+}
+
+#[test]
+fn test_old_tag_keys() {
+    let (k1, k2, k3) = ([1; 16], [2; 16], [3; 16]);
+    let v1 = AlphaId::<u64>::builder()
+        .tag(k1, 2)
+        .build()
+        .encode(7)
+        .unwrap();
+    let v2 = AlphaId::<u64>::builder()
+        .tag(k2, 2)
+        .build()
+        .encode(7)
+        .unwrap();
+
+    let alphaid = AlphaId::<u64>::builder()
+        .tag(k3, 2)
+        .old_tag_keys(&[k2, k1])
+        .build();
+    assert_eq!(alphaid.decode(&v1), Ok(7));
+    assert_eq!(alphaid.decode(&v2), Ok(7));
+    assert_eq!(alphaid.decode(alphaid.encode(7).unwrap()), Ok(7));
+
+    let alphaid = AlphaId::<u64>::builder()
+        .tag(k3, 2)
+        .old_tag_keys(&[k2])
+        .build();
+    assert_eq!(alphaid.decode(&v1), Err(AlphaIdError::TagMismatch));
+    assert_eq!(alphaid.to_config().old_tag_keys, [k2]);
+}
+
+#[test]
+fn test_tag_key_id() {
+    let (k1, k2) = ([1; 16], [2; 16]);
+    let old = AlphaId::<u64>::builder().tag(k1, 2).tag_key_id().build();
+    let alphaid = AlphaId::<u64>::builder()
+        .tag(k2, 2)
+        .old_tag_keys(&[k1])
+        .tag_key_id()
+        .build();
+    for n in [0, 7, 1 << 40] {
+        let v = old.encode(n).unwrap();
+        assert_eq!(alphaid.decode(&v), Ok(n));
+        // The key ID follows the 2 tag symbols.
+        let mut other = v.clone();
+        other[2] = alphaid.encode(n).unwrap()[2];
+        assert_ne!(other, v);
+        assert_eq!(alphaid.decode(&other), Err(AlphaIdError::TagMismatch));
+    }
+    assert_eq!(
+        AlphaId::<u64>::builder()
+            .tag(k1, 2)
+            .build()
+            .decode(old.encode(7).unwrap()),
+        Err(AlphaIdError::TagMismatch)
+    );
+    assert_eq!(AlphaId::from_config(alphaid.to_config()), alphaid);
+}
+
+#[test]
+#[should_panic(expected = "tag_key_id needs a tag")]
+fn test_tag_key_id_without_tag() {
+    AlphaId::<u64>::builder().tag_key_id().build();
+}
+
+#[test]
+fn test_proquint() {
+    let alphaid = AlphaId::<u64>::builder().prefix(b"<").suffix(b">").build();
+    for (n, v) in [
+        (0, &b"<babab>"[..]),
+        (0xFFFF, b"<zuzuz>"),
+        (0x7F00_0001, b"<lusab-babad>"),
+        (0x3F54_DCC1, b"<gutih-tugad>"),
+        (u64::MAX, b"<zuzuz-zuzuz-zuzuz-zuzuz>"),
+    ] {
+        assert_eq!(alphaid.encode_proquint(n).unwrap(), v);
+        assert_eq!(alphaid.decode_proquint(v), Ok(n));
+    }
+    assert_eq!(
+        alphaid.decode_proquint(b"<lusab-bab>"),
+        Err(AlphaIdError::InvalidLength)
+    );
+    assert_eq!(
+        alphaid.decode_proquint(b"<lusab-babaa>"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(
+        alphaid.decode_proquint(b"<babad-zuzuz-zuzuz-zuzuz-zuzuz>"),
+        Err(AlphaIdError::Overflow)
+    );
+
+    let alphaid = AlphaId::<u8>::new();
+    assert_eq!(alphaid.encode_proquint(255), Ok(b"baguz".to_vec()));
+    assert_eq!(alphaid.decode_proquint(b"baguz"), Ok(255));
+    assert_eq!(
+        alphaid.decode_proquint(b"bahab"),
+        Err(AlphaIdError::Overflow)
+    );
+}
+
+#[test]
+fn test_pronounceable() {
+    let alphaid = AlphaId::<u64>::builder().pronounceable().pad(4).build();
+    for n in [0, 1, 79, 80, 1350997667, u64::MAX] {
+        let v = alphaid.encode_string(n).unwrap();
+        assert!(v.len() >= 8 && v.len().is_multiple_of(2));
+        for (i, c) in v.chars().enumerate() {
+            assert_eq!("aeiou".contains(c), i % 2 == 1, "{}", v);
+        }
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+    assert_eq!(alphaid.decode(b"bab"), Err(AlphaIdError::UnexpectedChar));
+}
+
+#[test]
+fn test_url_select_safe() {
+    use alphaid::ALNUM62;
+
+    let alphaid = AlphaId::<u64>::builder()
+        .chars_str(ALNUM62)
+        .url_select_safe(true)
+        .build();
+    let v = alphaid.encode(u64::MAX).unwrap();
+    assert!(v.iter().all(u8::is_ascii_alphanumeric));
+    assert_eq!(alphaid.decode(&v), Ok(u64::MAX));
+
+    AlphaId::<u64>::builder()
+        .chars_str("абвгдежзийклмнопрстуфхцчшщъыьэюя")
+        .url_select_safe(true)
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "chars must be letters or digits to be selectable")]
+fn test_url_select_safe_separator() {
+    AlphaId::<u64>::builder()
+        .chars_str(alphaid::ALNUM62)
+        .group(4, b'-')
+        .url_select_safe(true)
+        .build();
+}
+
+#[test]
+fn test_qr_alphanumeric() {
+    use alphaid::{qr_savings, QR_ALPHANUMERIC};
+
+    let alphaid = AlphaId::<u128>::builder().qr_alphanumeric().build();
+    assert_eq!(alphaid.base(), 44);
+    let v = alphaid.encode(u128::MAX).unwrap();
+    assert!(v.iter().all(|b| QR_ALPHANUMERIC.as_bytes().contains(b)));
+    assert_eq!(alphaid.decode(&v), Ok(u128::MAX));
+
+    assert_eq!(qr_savings(b""), Some(0));
+    assert_eq!(qr_savings(b"A"), Some(2));
+    assert_eq!(qr_savings(b"AB"), Some(5));
+    assert_eq!(qr_savings(b"AB C"), Some(10));
+    assert_eq!(qr_savings(b"ab"), None);
+}
+
+#[test]
+fn test_dns_label() {
+    use alphaid::{decode_dns_label, encode_dns_label};
+
+    for n in [0, 1, 25, 26, 1350997667, u128::MAX] {
+        let v = encode_dns_label(n);
+        assert!(v.len() <= 63 && v.as_bytes()[0].is_ascii_lowercase());
+        assert!(v
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit()));
+        assert_eq!(decode_dns_label(&v), Ok(n));
+        assert_eq!(decode_dns_label(v.to_ascii_uppercase()), Ok(n));
+    }
+    assert_eq!(encode_dns_label(u128::MAX), "vkzgj9row2ddex6g8y22r6n49");
+    assert_eq!(decode_dns_label("9a"), Err(AlphaIdError::UnexpectedChar));
+    assert_eq!(decode_dns_label("a-b"), Err(AlphaIdError::UnexpectedChar));
+    assert_eq!(decode_dns_label("a01"), Err(AlphaIdError::NonCanonical));
+    assert_eq!(decode_dns_label(""), Err(AlphaIdError::EmptyInput));
+    assert_eq!(
+        decode_dns_label("a".repeat(64)),
+        Err(AlphaIdError::InvalidLength)
+    );
+    assert_eq!(
+        decode_dns_label("zzzzzzzzzzzzzzzzzzzzzzzzzz"),
+        Err(AlphaIdError::Overflow)
+    );
+}
+
+#[test]
+#[should_panic(expected = "chars must be printable characters")]
+fn test_chars_whitespace() {
+    AlphaId::<u32>::builder()
+        .chars(b"abcdefghijklmnop q".to_vec())
+        .build();
+}
+
+#[test]
+fn test_allow_raw_bytes() {
+    let mut chars = b"abcdefghijklmnop".to_vec();
+    chars.extend_from_slice(b"\t\n\xff");
+    let alphaid = AlphaId::<u32>::builder()
+        .chars(chars)
+        .allow_raw_bytes()
+        .build();
+    let v = alphaid.encode(18).unwrap();
+    assert_eq!(v, b"\xff");
+    assert_eq!(alphaid.decode(&v), Ok(18));
+    assert!(alphaid.to_config().allow_raw_bytes);
+    assert_eq!(
+        AlphaId::<u32>::from_config(alphaid.to_config()).encode(18),
+        Ok(v)
+    );
+
+    assert_eq!(
+        Builder::<u32>::parse("chars=abcdefghijklmnop\tq").err(),
+        Some(AlphaIdError::InvalidConfig)
+    );
+}
+
+#[test]
+fn test_small_alphabet() {
+    fn check<T>(max: T)
+    where
+        T: alphaid::UnsignedInteger + std::fmt::Debug + Into<u128>,
+    {
+        for base in 2..=16 {
+            let chars = DEFAULT_CHARS.as_bytes()[..base].to_vec();
+            for &mode in &[
+                PadMode::Shift,
+                #[cfg(feature = "rand")]
+                PadMode::Random,
+                PadMode::Fill,
+            ] {
+                // Random padding needs a terminator besides two digits.
+                if base == 2 && mode != PadMode::Shift && mode != PadMode::Fill {
+                    continue;
+                }
+                for &pad in &[1, 5] {
+                    let alphaid = AlphaId::<T>::builder()
+                        .chars(chars.clone())
+                        .allow_small_alphabet()
+                        .pad(pad)
+                        .pad_mode(mode)
+                        .build();
+                    for &n in &[T::zero(), T::one(), max] {
+                        let v = alphaid.encode(n).unwrap();
+                        assert!(v.len() <= alphaid.max_encoded_len());
+                        assert_eq!(alphaid.decode(&v), Ok(n), "base {} {:?}", base, mode);
+                    }
+                }
+            }
+        }
+    }
+
+    check(u8::MAX);
+    check(u32::MAX);
+    check(u128::MAX);
+
+    let alphaid = AlphaId::<u32>::builder()
+        .chars(b"01".to_vec())
+        .allow_small_alphabet()
+        .build();
+    assert_eq!(alphaid.encode(6), Ok(b"011".to_vec()));
+    assert_eq!(alphaid.max_encoded_len(), 32);
+
+    let alphaid = Builder::<u32>::parse("chars=01234567;small_alphabet=true")
+        .unwrap()
+        .build();
+    assert_eq!(alphaid.encode(8), Ok(b"01".to_vec()));
+    assert!(alphaid.to_config().allow_small_alphabet);
+    assert_eq!(
+        Builder::<u32>::parse("chars=01234567").err(),
+        Some(AlphaIdError::InvalidConfig)
+    );
+}
+
+#[test]
+#[should_panic(expected = "chars size must large than 16")]
+fn test_small_alphabet_not_allowed() {
+    AlphaId::<u32>::builder().chars(b"ACGT".to_vec()).build();
+}
+
+#[test]
+fn test_symbols() {
+    let symbols: Vec<String> = [
+        "a", "ab", "abc", "x", "y", "z", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    let alphaid = AlphaId::<u64>::builder().symbols(symbols).build();
+    assert_eq!(alphaid.decode(b"abcab"), Ok(2 + 17));
+    assert_eq!(alphaid.decode(b"abca"), Ok(2));
+    for n in 0..1000 {
+        let v = alphaid.encode_string(n).unwrap();
+        assert_eq!(alphaid.decode_str(&v), Ok(n));
+    }
+}
+
+#[test]
+#[should_panic(expected = "symbols must not be empty")]
+fn test_symbols_empty() {
+    AlphaId::<u64>::builder().symbols(vec![String::new(); 17]);
+}
+
+#[test]
+fn test_encode_fixed() {
+    let alphaid = AlphaId::<u32>::new();
+    let mut seen = std::collections::HashSet::new();
+    for digit_count in 0..=3 {
+        for n in 0..10u32.pow(digit_count) {
+            let v = alphaid.encode_fixed(n, digit_count).unwrap();
+            assert_eq!(alphaid.decode_fixed(&v), Ok((n, digit_count)));
+            assert!(seen.insert(v));
+        }
+    }
+    assert_eq!(alphaid.encode_fixed(0, 0), alphaid.encode(0));
+    assert_eq!(alphaid.encode_fixed(1000, 3), Err(AlphaIdError::Overflow));
+    assert_eq!(
+        alphaid.encode_fixed(4_000_000_000, 10),
+        Err(AlphaIdError::Overflow)
+    );
+    let v = alphaid.encode_fixed(42, 9).unwrap();
+    assert_eq!(alphaid.decode_fixed(&v), Ok((42, 9)));
+
+    let v = alphaid.encode_fixed_radix(0xff, 4, 16).unwrap();
+    assert_eq!(alphaid.decode_fixed_radix(&v, 16), Ok((0xff, 4)));
+    let v = alphaid.encode(u32::MAX).unwrap();
+    assert_eq!(alphaid.decode_fixed_radix(&v, 2), Ok((0, 32)));
+}
+
+#[test]
+fn test_dec_str() {
+    let alphaid = AlphaId::<u8>::new();
+    let n = "123456789012345678901234567890123456789012345678901234567890";
+    let v = alphaid.encode_dec_str(n).unwrap();
+    assert_eq!(alphaid.decode_dec_str(&v), Ok(n.to_string()));
+    assert_eq!(
+        alphaid.encode_dec_str(&u128::MAX.to_string()),
+        Ok(alphaid::encode(u128::MAX))
+    );
+    assert_eq!(alphaid.encode_dec_str("007"), alphaid.encode_dec_str("7"));
+    assert_eq!(alphaid.decode_dec_str("a"), Ok("0".to_string()));
+    assert_eq!(alphaid.encode_dec_str(""), Err(AlphaIdError::EmptyInput));
+    assert_eq!(
+        alphaid.encode_dec_str("12a"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(
+        alphaid.decode_dec_str("ab+"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+
+    let alphaid = AlphaId::<u32>::builder()
+        .prefix(b"big_")
+        .group(4, b'.')
+        .order(DigitOrder::BigEndian)
+        .emoji()
+        .build();
+    let v = alphaid.encode_dec_str(n).unwrap();
+    assert!(v.starts_with("big_"));
+    assert_eq!(alphaid.decode_dec_str(&v), Ok(n.to_string()));
+}
+
+#[test]
+fn test_signed() {
+    let alphaid = AlphaId::<u64>::builder()
+        .chars(b"0123456789abcdefghijklmnopqrstuvwxyz".to_vec())
+        .order(DigitOrder::BigEndian)
+        .prefix(b"n_")
+        .sign(b'~')
+        .build();
+    assert_eq!(alphaid.encode_signed(-42), Ok(b"n_~16".to_vec()));
+    assert_eq!(alphaid.encode_signed(42), Ok(b"n_16".to_vec()));
+    for &n in &[0, 1, -1, i64::MIN, i64::MAX] {
+        let v = alphaid.encode_signed(n).unwrap();
+        assert_eq!(alphaid.decode_signed::<i64>(&v), Ok(n));
+    }
+    assert_eq!(
+        alphaid.encode_signed(i128::MIN),
+        Err(AlphaIdError::Overflow)
+    );
+    assert_eq!(
+        alphaid.decode_signed::<i64>(b"n_~0"),
+        Err(AlphaIdError::NonCanonical)
+    );
+    assert_eq!(
+        alphaid.decode_signed::<i64>(b"n_1~6"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(
+        alphaid.decode_signed::<i64>(b"~n_16"),
+        Err(AlphaIdError::PrefixMissed)
+    );
+    assert_eq!(
+        alphaid.decode_signed::<i8>(b"n_~zz"),
+        Err(AlphaIdError::Overflow)
+    );
+
+    let unsigned = AlphaId::<u64>::new();
+    assert_eq!(unsigned.encode_signed(-1), Err(AlphaIdError::InvalidNumber));
+    assert_eq!(unsigned.decode_signed::<i64>(b"b"), Ok(1));
+}
+
+#[test]
+#[should_panic(expected = "sign must not be in chars or the separator")]
+fn test_sign_in_chars() {
+    AlphaId::<u64>::builder().sign(b'-').build();
+}
+
+#[test]
+fn test_nonzero() {
+    use std::num::{NonZeroU128, NonZeroU8};
+
+    let alphaid = AlphaId::<u8>::builder().pad(3).build();
+    let n = NonZeroU8::new(255).unwrap();
+    let v = alphaid.encode_nonzero(n).unwrap();
+    assert_eq!(Ok(v.clone()), alphaid.encode(255));
+    assert_eq!(alphaid.decode_nonzero(&v), Ok(n));
+    let zero = alphaid.encode(0).unwrap();
+    assert_eq!(
+        alphaid.decode_nonzero::<NonZeroU8>(&zero),
+        Err(AlphaIdError::Zero)
+    );
+
+    let alphaid = AlphaId::<u128>::new();
+    let n = NonZeroU128::MAX;
+    let v = alphaid.encode_nonzero(n).unwrap();
+    assert_eq!(alphaid.decode_nonzero(&v), Ok(n));
+    assert_eq!(AlphaIdError::Zero.to_string(), "zero is not allowed");
+}
+
+#[test]
+fn test_opt() {
+    let alphaid = AlphaId::<u64>::builder()
+        .prefix(b"p_")
+        .none_symbol(b'~')
+        .build();
+    assert_eq!(alphaid.encode_opt(None), Ok(b"p_~".to_vec()));
+    assert_eq!(alphaid.decode_opt(b"p_~"), Ok(None));
+    for &n in &[0, 1, u64::MAX] {
+        let v = alphaid.encode_opt(Some(n)).unwrap();
+        assert_eq!(v, alphaid.encode(n).unwrap());
+        assert_eq!(alphaid.decode_opt(&v), Ok(Some(n)));
+    }
+    assert_eq!(
+        alphaid.decode_opt(b"p_~~"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(alphaid.decode_opt(b"~"), Err(AlphaIdError::PrefixMissed));
+
+    let alphaid = AlphaId::<u64>::new();
+    assert_eq!(alphaid.encode_opt(None), Err(AlphaIdError::InvalidConfig));
+}
+
+#[test]
+#[should_panic(expected = "none symbol must not be in chars, the separator or the sign")]
+fn test_none_symbol_is_sign() {
+    AlphaId::<u64>::builder()
+        .sign(b'~')
+        .none_symbol(b'~')
+        .build();
+}
+
+#[test]
+fn test_lint() {
+    let lints = AlphaId::<u32>::builder().lint();
+    assert!(lints.contains(&Lint::Confusable('0', 'O')));
+    assert!(lints.contains(&Lint::Confusable('I', 'l')));
+    assert!(lints.contains(&Lint::MixedCase('a')));
+    assert!(lints.contains(&Lint::Vowels));
+    assert!(!lints.iter().any(|l| matches!(l, Lint::NeedsUrlEncoding(_))));
+
+    let lints = AlphaId::<u32>::builder()
+        .chars(b"bcdfghjkmnpqrstwxyz23456789".to_vec())
+        .group(4, b' ')
+        .lint();
+    assert_eq!(lints, [Lint::NeedsUrlEncoding(' ')]);
+
+    let lints = AlphaId::<u32>::builder()
+        .chars_str("абвгдежзийклмнопрстуфхцчшщъыьэюя")
+        .block_words(&["xyz"])
+        .lint();
+    assert_eq!(lints.len(), 32);
+    assert_eq!(lints[0], Lint::NeedsUrlEncoding('а'));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_stats() {
+    let alphaid = AlphaId::<u8>::builder()
+        .chars(b"0123456789abcdefghijklmnop".to_vec())
+        .build();
+    let stats = alphaid.stats(0..=255);
+    assert_eq!(stats.lengths.iter().map(|l| l.ids).sum::<u128>(), 256);
+    assert_eq!(
+        stats
+            .lengths
+            .iter()
+            .map(|l| (l.len, l.ids))
+            .collect::<Vec<_>>(),
+        [(1, 26), (2, 256 - 26)]
+    );
+    let stats = alphaid.stats(20..=30);
+    assert_eq!(
+        stats.lengths.iter().map(|l| l.in_range).collect::<Vec<_>>(),
+        [6, 5]
+    );
+
+    let alphaid = AlphaId::<u128>::builder().sortable(22).build();
+    let stats = alphaid.stats(0..=u128::MAX);
+    assert_eq!(stats.lengths.len(), 1);
+    assert_eq!(stats.lengths[0].len, 22);
+    assert_eq!(stats.lengths[0].ids, u128::MAX);
+}
+
+#[test]
+fn test_shuffle_seed() {
+    let alphaid = AlphaId::<u32>::builder().shuffle_seed(42).build();
+    assert_eq!(alphaid.encode(1350997667), Ok(b"hL7la-".to_vec()));
+    let mut chars = alphaid.alphabet().to_vec();
+    chars.sort_unstable();
+    let mut default = DEFAULT_CHARS.as_bytes().to_vec();
+    default.sort_unstable();
+    assert_eq!(chars, default);
+
+    let same = Builder::<u32>::parse("shuffle_seed=42").unwrap().build();
+    assert_eq!(same, alphaid);
+    let copy = AlphaId::<u32>::from_config(alphaid.to_config());
+    assert_eq!(copy.encode(1350997667), alphaid.encode(1350997667));
+
+    let alphaid = AlphaId::<u32>::builder()
+        .shuffle_seed(7)
+        .pad_char(b'_')
+        .pad(4)
+        .build();
+    assert_eq!(alphaid.alphabet()[0], b'_');
+    assert_eq!(
+        Builder::<u32>::parse("shuffle_seed=1;sortable=4").err(),
+        Some(AlphaIdError::InvalidConfig)
+    );
+}
+
+#[test]
+fn test_decode_with_suggestions() {
+    let alphaid = AlphaId::<u64>::new();
+    assert_eq!(alphaid.decode_with_suggestions(b"90F7qb"), Ok(1350997667));
+
+    let err = alphaid.decode_with_suggestions(b"9|F7qb").unwrap_err();
+    assert_eq!(err.error, AlphaIdError::UnexpectedChar);
+    let inputs: Vec<&[u8]> = err.suggestions.iter().map(|(v, _)| &v[..]).collect();
+    assert_eq!(inputs, [&b"91F7qb"[..], b"9lF7qb", b"9IF7qb", b"9iF7qb"]);
+    for (v, n) in &err.suggestions {
+        assert_eq!(alphaid.decode(v), Ok(*n));
+    }
+
+    let tagged = AlphaId::<u64>::builder()
+        .tag(*b"0123456789abcdef", 2)
+        .build();
+    assert_eq!(tagged.encode(2), Ok(b"I5c".to_vec()));
+    let err = tagged.decode_with_suggestions(b"l5c").unwrap_err();
+    assert_eq!(err.error, AlphaIdError::TagMismatch);
+    assert!(err.suggestions.contains(&(b"I5c".to_vec(), 2)));
+
+    let err = alphaid.decode_with_suggestions(b"+++++").unwrap_err();
+    assert!(err.suggestions.is_empty());
+}
+
+#[test]
+fn test_fast_codec() {
+    let chars = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz".to_vec();
+    let fast = alphaid::AlphaId64::new(&chars);
+    let generic = AlphaId::<u64>::builder().chars(chars.clone()).build();
+    let mut n = 1u64;
+    for _ in 0..200 {
+        let v = fast.encode(n);
+        assert_eq!(Ok(v.clone()), generic.encode(n));
+        assert_eq!(fast.decode(&v), Ok(n));
+        n = n
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+    }
+    assert_eq!(fast.decode(fast.encode(u64::MAX)), Ok(u64::MAX));
+    assert_eq!(fast.decode(b"zzzzzzzzzzzz"), Err(AlphaIdError::Overflow));
+    assert_eq!(fast.decode(b"a+"), Err(AlphaIdError::UnexpectedChar));
+    assert_eq!(fast.decode(b""), Ok(0));
+
+    let fast = alphaid::AlphaId128::new(&chars[..17]);
+    assert_eq!(fast.encode(u128::MAX).len(), 32);
+    assert_eq!(fast.decode(fast.encode(u128::MAX)), Ok(u128::MAX));
+    let mut buf = [0; 4];
+    assert_eq!(
+        fast.encode_to_slice(u128::MAX, &mut buf),
+        Err(AlphaIdError::BufferTooSmall)
+    );
+}
+
+#[test]
+fn test_shard_for() {
+    let alphaid = AlphaId::<u64>::new();
+    assert_eq!(alphaid.shard_for(1, 10), 6);
+    assert_eq!(alphaid.shard_for(42, 10), 2);
+    assert_eq!(alphaid.shard_for(1350997667, 100), 22);
+    assert_eq!(alphaid.shard_for(u64::MAX, 1000), 313);
+
+    for n in 0..1000u64 {
+        let before = alphaid.shard_for(n, 10);
+        let after = alphaid.shard_for(n, 11);
+        assert!(before < 10);
+        assert!(after == before || after == 10);
+    }
+
+    let wide = AlphaId::<u128>::new();
+    assert_eq!(wide.shard_for(42, 10), 2);
+    assert_eq!(
+        alphaid.shard_for_encoded(b"+", 10),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+}
+
+#[test]
+fn test_obfuscator() {
+    // Multiplication by an odd number is a permutation of the `u64`s.
+    struct Multiply;
+
+    impl Obfuscator<u64> for Multiply {
+        fn forward(&self, n: u64) -> u64 {
+            n.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        }
+
+        fn backward(&self, n: u64) -> u64 {
+            n.wrapping_mul(0xF1DE_83E1_9937_733D)
+        }
+    }
+
+    let alphaid = AlphaId::<u64>::builder()
+        .obfuscator(Multiply)
+        .pad(4)
+        .build();
+    let plain = AlphaId::<u64>::new();
+    assert_ne!(alphaid.encode(1), plain.encode(1));
+    for n in (0..100).chain([u64::MAX]) {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(alphaid.decode(&v), Ok(n));
+        assert_eq!(alphaid.decode_strict(&v), Ok(n));
+    }
+
+    let alphaid = AlphaId::<u64>::builder()
+        .obfuscator(Multiply)
+        .emoji()
+        .build();
+    let v = alphaid.encode_string(42).unwrap();
+    assert_eq!(alphaid.decode_str(&v), Ok(42));
+    assert_eq!(alphaid.clone(), alphaid);
+    assert_ne!(
+        AlphaId::<u64>::builder().obfuscator(Multiply).build(),
+        plain
+    );
+
+    // The lengths do not grow with the values.
+    let alphaid = AlphaId::<u64>::builder().obfuscator(Multiply).build();
+    assert!((1..1000).any(|n| alphaid.len_for(n) > alphaid.len_for(n + 1)));
+    assert_eq!(alphaid.max_value_for_len(10), None);
+    #[cfg(feature = "std")]
+    assert!(alphaid.stats(0..=1000).lengths.is_empty());
+    #[cfg(feature = "rand")]
+    assert_eq!(alphaid.random(10), Err(AlphaIdError::InvalidLength));
+}
+
+#[test]
+fn test_obfuscator_stages() {
+    struct Add(u32);
+
+    impl Obfuscator<u32> for Add {
+        fn forward(&self, n: u32) -> u32 {
+            n.wrapping_add(self.0)
+        }
+
+        fn backward(&self, n: u32) -> u32 {
+            n.wrapping_sub(self.0)
+        }
+    }
+
+    struct Rotate;
+
+    impl Obfuscator<u32> for Rotate {
+        fn forward(&self, n: u32) -> u32 {
+            n.rotate_left(1)
+        }
+
+        fn backward(&self, n: u32) -> u32 {
+            n.rotate_right(1)
+        }
+    }
+
+    let alphaid = AlphaId::<u32>::builder()
+        .obfuscator(Add(3))
+        .obfuscator(Rotate)
+        .tag(*b"0123456789abcdef", 1)
+        .build();
+    let tagged = AlphaId::<u32>::builder()
+        .tag(*b"0123456789abcdef", 1)
+        .build();
+    // (1 + 3) rotated, not 1 rotated plus 3.
+    assert_eq!(alphaid.encode(1), tagged.encode(8));
+    assert_eq!(alphaid.decode(alphaid.encode(1).unwrap()), Ok(1));
+}
+
+#[test]
+fn test_codec() {
+    fn check(codec: &dyn alphaid::Codec<u64>) {
+        for &n in &[0, 1, 1350997667, u64::MAX] {
+            let v = codec.encode(n).unwrap();
+            assert!(v.len() <= codec.max_len());
+            assert_eq!(codec.decode(&v), Ok(n));
+        }
+        assert!(codec.decode(b"+").is_err());
+    }
+
+    check(&AlphaId::<u64>::builder().pad(20).prefix(b"x_").build());
+    check(&alphaid::AlphaIdConst::<u64, 64>::DEFAULT);
+    check(&alphaid::AlphaId64::default());
+    #[cfg(feature = "php")]
+    check(&alphaid::PhpAlphaId::new());
+
+    let codec: &dyn alphaid::Codec<u128> = &alphaid::AlphaId128::default();
+    assert_eq!(codec.max_len(), 22);
+}
+
+#[test]
+fn test_encode_list() {
+    let alphaid = AlphaId::<u64>::builder().prefix(b"id_").pad(3).build();
+    let items = [0, 1, 1350997667, u64::MAX];
+    let v = alphaid.encode_list(&items, b',').unwrap();
+    assert_eq!(alphaid.decode_list(&v, b','), Ok(items.to_vec()));
+    for (item, &n) in v.split(|&b| b == b',').zip(&items) {
+        assert_eq!(alphaid.decode(item), Ok(n));
+    }
+
+    assert_eq!(alphaid.encode_list(&[], b','), Ok(vec![]));
+    assert_eq!(alphaid.decode_list(b"", b','), Ok(vec![]));
+    assert_eq!(
+        alphaid.encode_list(&[1], b'-'),
+        Err(AlphaIdError::InvalidConfig)
+    );
+    assert_eq!(
+        alphaid.encode_list(&[1], b'_'),
+        Err(AlphaIdError::InvalidConfig)
+    );
+    assert_eq!(
+        alphaid.decode_list(b"id_aab,,id_aac", b','),
+        Err(AlphaIdError::PrefixMissed)
+    );
+}
+
+#[test]
+fn test_migrator() {
+    let old = AlphaId::<u64>::builder().prefix(b"id_").build();
+    let new = AlphaId::<u64>::builder()
+        .chars_str("0123456789abcdefghijklmnopqrstuv")
+        .build();
+    let migrator = Migrator::<u64>::from_configs(old.to_config(), new.to_config());
+
+    let ns = [0, 1, 1350997667, u64::MAX];
+    let vs = old.encode_batch(&ns).unwrap();
+    let migrated = migrator.migrate_batch(&vs);
+    for (n, v) in ns.iter().zip(migrated) {
+        assert_eq!(new.decode(v.unwrap()), Ok(*n));
+    }
+
+    let results: Vec<_> = migrator
+        .migrate_iter(vec![&b"id_b"[..], b"b", b"id_?"])
+        .map(|(_, new)| new)
+        .collect();
+    assert_eq!(
+        results,
+        vec![
+            Ok(b"1".to_vec()),
+            Err(AlphaIdError::PrefixMissed),
+            Err(AlphaIdError::UnexpectedChar)
+        ]
+    );
+}
+
+#[test]
+fn test_decode_unchecked() {
+    let configs = vec![
+        AlphaId::<u64>::new(),
+        AlphaId::<u64>::builder()
+            .pad(4)
+            .pad_mode(PadMode::Fill)
+            .build(),
+        AlphaId::<u64>::builder()
+            .order(DigitOrder::BigEndian)
+            .group(3, b'.')
+            .prefix(b"id_")
+            .build(),
+        AlphaId::<u64>::builder().pad(4).build(),
+        AlphaId::<u64>::builder().sortable(11).build(),
+    ];
+    for alphaid in configs {
+        for n in [0, 1, 63, 64, 1350997667, u64::MAX] {
+            let v = alphaid.encode(n).unwrap();
+            assert_eq!(alphaid.decode_unchecked(&v), Ok(n));
+        }
+        assert!(alphaid.decode_unchecked(b"?").is_err());
+    }
+}
+
+#[test]
+fn test_mixed_radix() {
+    let base62 = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let codec = alphaid::MixedRadix::<u64>::new(&[
+        "abcdefghijklmnopqrstuvwxyz",
+        base62,
+        base62,
+        base62,
+        base62,
+    ]);
+    assert_eq!(codec.encoded_len(), 5);
+    assert_eq!(codec.encode(1350997667), Err(AlphaIdError::Overflow));
+    let max = 26 * 62u64.pow(4) - 1;
+    assert_eq!(codec.encode(max), Ok(b"zZZZZ".to_vec()));
+    for n in [0, 1, 61, 62, 1_000_000, max] {
+        let v = codec.encode(n).unwrap();
+        assert_eq!(codec.decode(&v), Ok(n));
+    }
+    assert_eq!(codec.decode(b"0aaaa"), Err(AlphaIdError::UnexpectedChar));
+    assert_eq!(codec.decode(b"aaaa"), Err(AlphaIdError::InvalidLength));
+}
+
+#[test]
+#[should_panic(expected = "chars size must fit into the number type")]
+fn test_mixed_radix_too_large() {
+    let chars: Vec<u8> = (0..=255).collect();
+    alphaid::MixedRadix::<u8>::new(&[chars]);
+}
+
+#[test]
+fn test_permute_digits() {
+    fn check<T>(alphaid: AlphaId<T>, ns: impl Iterator<Item = T>)
+    where
+        T: alphaid::UnsignedInteger + std::fmt::Debug,
+    {
+        let mut config = alphaid.to_config();
+        config.digit_seed = None;
+        let plain = AlphaId::<T>::from_config(config);
+        for n in ns {
+            let v = alphaid.encode(n).unwrap();
+            assert_eq!(v.len(), plain.encode(n).unwrap().len());
+            assert_eq!(alphaid.decode(&v), Ok(n));
+        }
+    }
+
+    check(
+        AlphaId::<u8>::builder().permute_digits(1).build(),
+        0..=u8::MAX,
+    );
+    check(
+        AlphaId::<u16>::builder()
+            .chars(b"01".to_vec())
+            .allow_small_alphabet()
+            .permute_digits(2)
+            .build(),
+        0..=u16::MAX,
+    );
+    check(
+        AlphaId::<u16>::builder().pad(3).permute_digits(3).build(),
+        0..=u16::MAX,
+    );
+    check(
+        AlphaId::<u64>::builder().permute_digits(4).build(),
+        (0..10_000).chain(u64::MAX - 10_000..=u64::MAX),
+    );
+
+    let alphaid = AlphaId::<u64>::builder().permute_digits(5).build();
+    let a = alphaid.encode(1_000_000).unwrap();
+    let b = alphaid.encode(1_000_001).unwrap();
+    assert_ne!(a[1..], b[1..]);
+    assert_eq!(
+        Builder::<u64>::parse("permute_digits=5").unwrap().build(),
+        alphaid
+    );
+}
+
+#[test]
+#[should_panic(expected = "permute_digits can not be combined with sortable mode")]
+fn test_permute_digits_sortable() {
+    AlphaId::<u64>::builder()
+        .permute_digits(1)
+        .sortable(11)
+        .build();
+}
+
+#[test]
+fn test_never_numeric() {
+    let alphaid = AlphaId::<u32>::builder().never_numeric().build();
+    for n in 0..100_000 {
+        let v = alphaid.encode(n).unwrap();
+        assert!(!v.iter().all(u8::is_ascii_digit), "{}", n);
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+    assert!(alphaid.to_config().never_numeric);
+    assert_eq!(
+        Builder::<u32>::parse("never_numeric=true").unwrap().build(),
+        alphaid
+    );
+    assert!(matches!(
+        Builder::<u32>::parse("chars=0123456789;small_alphabet=true;never_numeric=true"),
+        Err(AlphaIdError::InvalidConfig)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "never_numeric needs a symbol which is not an ASCII digit")]
+fn test_never_numeric_digits() {
+    AlphaId::<u32>::builder()
+        .chars(b"0123456789".to_vec())
+        .allow_small_alphabet()
+        .never_numeric()
+        .build();
+}
+
+#[test]
+fn test_first_char() {
+    let alphaid = AlphaId::<u32>::builder()
+        .first_char(CharClass::NoneOf(b"-_".to_vec()))
+        .build();
+    for n in 0..100_000 {
+        let v = alphaid.encode(n).unwrap();
+        assert!(v[0] != b'-' && v[0] != b'_', "{}", n);
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+
+    let alphaid = AlphaId::<u64>::builder()
+        .order(DigitOrder::BigEndian)
+        .first_char(CharClass::Letter)
+        .build();
+    for n in (0..10_000).chain([u64::MAX]) {
+        let v = alphaid.encode(n).unwrap();
+        assert!(v[0].is_ascii_alphabetic(), "{}", n);
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+    assert_eq!(
+        Builder::<u64>::parse("order=big_endian;first_char=letter")
+            .unwrap()
+            .build(),
+        alphaid
+    );
+    assert!(matches!(
+        Builder::<u64>::parse("first_char=digit"),
+        Err(AlphaIdError::InvalidConfig)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "first_char needs the zero symbol in its class in big endian order")]
+fn test_first_char_big_endian_zero() {
+    AlphaId::<u64>::builder()
+        .chars(b"0123456789abcdefghij".to_vec())
+        .order(DigitOrder::BigEndian)
+        .first_char(CharClass::Letter)
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "first_char needs the zero symbol in its class in big endian order")]
+fn test_first_char_big_endian_pad_char() {
+    AlphaId::<u64>::builder()
+        .pad_char(b'0')
+        .order(DigitOrder::BigEndian)
+        .first_char(CharClass::Letter)
+        .build();
+}
+
+#[test]
+fn test_parse_first_char_after_reordering() {
+    assert!(matches!(
+        Builder::<u32>::parse("first_char=letter;pad_char=0;order=big_endian"),
+        Err(AlphaIdError::InvalidConfig)
+    ));
+    let mut rejected = 0;
+    for seed in 0..50 {
+        let config = format!("first_char=letter;order=big_endian;shuffle_seed={}", seed);
+        match Builder::<u32>::parse(&config) {
+            Ok(builder) => assert!(builder.build().alphabet()[0].is_ascii_alphabetic()),
+            Err(_) => rejected += 1,
+        }
+    }
+    assert!(rejected > 0);
+}
+
+#[test]
+fn test_chars_static() {
+    static CHARS: &[u8] = b"0123456789abcdefghij";
+    let alphaid = AlphaId::<u32>::builder().chars_static(CHARS).build();
+    assert_eq!(alphaid.alphabet().as_ptr(), CHARS.as_ptr());
+    assert_eq!(
+        alphaid,
+        AlphaId::<u32>::builder().chars(CHARS.to_vec()).build()
+    );
+
+    let alphaid = AlphaId::<u32>::builder()
+        .chars_static(CHARS)
+        .pad_char(b'5')
+        .build();
+    assert_eq!(alphaid.alphabet()[0], b'5');
+    assert_eq!(&CHARS[..2], b"01");
+}
+
+#[test]
+fn test_pad_override() {
+    let alphaid = AlphaId::<u64>::builder().pad(2).build();
+    for pad in 1..12 {
+        let padded = AlphaId::<u64>::builder().pad(pad).build();
+        for n in [0, 1, 63, 64, 1350997667, u64::MAX] {
+            let v = alphaid.encode_with_pad(n, pad).unwrap();
+            assert_eq!(v, padded.encode(n).unwrap());
+            assert_eq!(alphaid.decode_with_pad(&v, pad), Ok(n));
+        }
+    }
+    assert_eq!(
+        alphaid.encode_with_pad(1, 0),
+        Err(AlphaIdError::InvalidConfig)
+    );
+
+    let bounded = AlphaId::<u64>::builder().max_len::<11>().build();
+    assert_eq!(
+        bounded.encode_with_pad(1, 12),
+        Err(AlphaIdError::InvalidLength)
+    );
+}
+
+#[test]
+fn test_dyn_alphaid() {
+    let config = AlphaId::<u64>::builder()
+        .permute_digits(9)
+        .max_len::<11>()
+        .build()
+        .to_config();
+    let alphaid = alphaid::DynAlphaId::from_config(config.clone()).unwrap();
+    let v = AlphaId::<u64>::from_config(config.clone())
+        .encode(1350997667)
+        .unwrap();
+    assert_eq!(alphaid.decode_as::<u64>(&v), Ok(1350997667));
+    let v = AlphaId::<u32>::from_config(config).encode(42).unwrap();
+    assert_eq!(alphaid.decode_as::<u32>(&v), Ok(42));
+    assert!(alphaid.get::<u128>().is_none());
+    assert_eq!(alphaid.encode_u128(1), Err(AlphaIdError::InvalidConfig));
+
+    let mut config = Config::default();
+    config.chars = b"abc".to_vec();
+    assert!(matches!(
+        alphaid::DynAlphaId::from_config(config),
+        Err(AlphaIdError::InvalidConfig)
+    ));
+}
+
+#[test]
+fn test_decode_concat() {
+    let alphaid = AlphaId::<u64>::builder().sortable(11).prefix(b"#").build();
+    let ns = [0, 1, 1350997667, u64::MAX];
+    let v: Vec<u8> = ns
+        .iter()
+        .flat_map(|&n| alphaid.encode(n).unwrap())
+        .collect();
+    let width = alphaid.max_encoded_len();
+    assert_eq!(width, 12);
+    assert_eq!(alphaid.decode_concat(&v, width), Ok(ns.to_vec()));
+    assert_eq!(alphaid.decode_concat(b"", width), Ok(vec![]));
+
+    let err = alphaid.decode_concat(&v[..v.len() - 1], width).unwrap_err();
+    assert_eq!(err.error, AlphaIdError::InvalidLength);
+    assert_eq!(err.position, Some(36));
+
+    let mut corrupt = v.clone();
+    corrupt[24] = b'x';
+    let err = alphaid.decode_concat(&corrupt, width).unwrap_err();
+    assert_eq!(err.error, AlphaIdError::PrefixMissed);
+    assert_eq!(err.position, Some(24));
+    assert_eq!(
+        alphaid.decode_concat(&v, 0).unwrap_err().error,
+        AlphaIdError::InvalidLength
+    );
+}
+
+#[test]
+fn test_find_vanity() {
+    let alphaid = AlphaId::<u64>::builder().prefix(b"go/").build();
+    let target = 5_000_000;
+    let (n, v) = alphaid
+        .find_vanity(target - 1_000_000..=target + 1_000_000, b"xyz")
+        .unwrap();
+    assert!(v.windows(3).any(|w| w == b"xyz"));
+    assert_eq!(alphaid.decode(&v), Ok(n));
+    assert!(alphaid.find_vanity(0..=1000, b"zzzz").is_none());
+    assert_eq!(
+        alphaid.find_vanity_by(u64::MAX - 1..=u64::MAX, |v| v.starts_with(b"go/_")),
+        Some((u64::MAX, alphaid.encode(u64::MAX).unwrap()))
+    );
+    assert_eq!(alphaid.find_vanity_by(u64::MAX..=u64::MAX, |_| false), None);
+}
+
+#[test]
+fn test_max_value() {
+    let alphaid = AlphaId::<u64>::builder()
+        .max_value(alphaid::JS_SAFE)
+        .permute_digits(3)
+        .build();
+    for n in [0, 1, alphaid::JS_SAFE - 1, alphaid::JS_SAFE] {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+    assert_eq!(
+        alphaid.encode(alphaid::JS_SAFE + 1),
+        Err(AlphaIdError::Overflow)
+    );
+    let unbounded = AlphaId::<u64>::builder().permute_digits(3).build();
+    let v = unbounded.encode(u64::MAX).unwrap();
+    assert_eq!(alphaid.decode(&v), Err(AlphaIdError::Overflow));
+    assert_eq!(
+        Builder::<u64>::parse("max_value=9007199254740991;permute_digits=3")
+            .unwrap()
+            .build(),
+        alphaid
+    );
+    assert_eq!(
+        Builder::<u8>::parse("max_value=9007199254740991").err(),
+        Some(AlphaIdError::InvalidConfig)
+    );
+    assert_eq!(
+        Builder::<u8>::parse("max_value=255").unwrap().build(),
+        AlphaId::<u8>::builder().max_value(u8::MAX).build()
+    );
+}
+
+#[test]
+fn test_offset() {
+    let alphaid = AlphaId::<u32>::builder()
+        .offset(1_000_000)
+        .max_value(u32::MAX - 1_000_000)
+        .build();
+    assert_eq!(alphaid.encode(0).unwrap().len(), 4);
+    for n in [0, 1, 63, u32::MAX - 1_000_000] {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(alphaid.decode(&v), Ok(n));
+    }
+    assert_eq!(
+        alphaid.encode(u32::MAX - 999_999),
+        Err(AlphaIdError::Overflow)
+    );
+    assert_eq!(alphaid.decode(b"a"), Err(AlphaIdError::Overflow));
+
+    let alphaid = AlphaId::<u128>::builder().offset(u128::MAX).build();
+    assert_eq!(alphaid.decode(alphaid.encode(0).unwrap()), Ok(0));
+    assert_eq!(alphaid.encode(1), Err(AlphaIdError::Overflow));
+
+    assert_eq!(
+        Builder::<u32>::parse("offset=1000000")
+            .unwrap()
+            .build()
+            .encode(1),
+        Ok(b"bjQd".to_vec())
+    );
+    assert!(Builder::<u8>::parse("offset=256").is_err());
+}
+
+macro_rules! check_boundaries {
+    ($t:ty) => {{
+        let alphabets: Vec<(Vec<u8>, bool)> = vec![
+            (b"ACGT".to_vec(), false),
+            (b"0123456789abcdefg".to_vec(), false),
+            (Vec::new(), false),
+            ((0x20..0xf0).collect(), true),
+        ];
+        let modes = [
+            PadMode::Shift,
+            PadMode::Fill,
+            #[cfg(feature = "rand")]
+            PadMode::Random,
+        ];
+        for (chars, raw) in &alphabets {
+            for mode in modes {
+                for pad in [1, 3] {
+                    for blocked in [false, true] {
+                        // Random padding, or fill padding longer than 2.
+                        if blocked && mode != PadMode::Shift && (mode != PadMode::Fill || pad > 2) {
+                            continue;
+                        }
+                        let mut builder = AlphaId::<$t>::builder().pad(pad).pad_mode(mode);
+                        if !chars.is_empty() {
+                            builder = builder.chars(chars.clone()).allow_small_alphabet();
+                        }
+                        if *raw {
+                            builder = builder.allow_raw_bytes();
+                        }
+                        if blocked {
+                            builder = builder.block_words(&["CA", "ab"]);
+                        }
+                        let alphaid = builder.build();
+
+                        for n in [0, 1, <$t>::MAX / 2, <$t>::MAX - 1, <$t>::MAX] {
+                            let encoded = alphaid.encode(n).unwrap();
+                            assert_eq!(
+                                alphaid.decode(&encoded),
+                                Ok(n),
+                                "{:?} {:?} {} {}",
+                                encoded,
+                                mode,
+                                pad,
+                                blocked
+                            );
+                        }
+
+                        let longest = alphaid.max_encoded_len();
+                        let mut garbage: Vec<Vec<u8>> =
+                            (0..=255u8).map(|b| vec![b; longest + 2]).collect();
+                        garbage.push(Vec::new());
+                        if let [zero, one, .., last] = alphaid.alphabet() {
+                            // Reads as a digit rotated by one for blocked words.
+                            garbage.push(vec![*last, *one, *zero]);
+                        }
+                        garbage.push(alphaid.encode(<$t>::MAX).unwrap().repeat(2));
+                        for v in garbage {
+                            let _ = alphaid.decode(&v);
+                            let _ = alphaid.decode_strict(&v);
+                            let _ = alphaid.decode_lossy(&v);
+                            let _ = alphaid.decode_unchecked(&v);
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[test]
+fn test_boundaries_never_panic() {
+    check_boundaries!(u8);
+    check_boundaries!(u16);
+    check_boundaries!(u32);
+    check_boundaries!(u64);
+    check_boundaries!(u128);
+}
+
+#[test]
+#[should_panic(expected = "fill padding longer than 2 can not be combined with blocked words")]
+fn test_fill_pad_with_blocked_words() {
+    AlphaId::<u32>::builder()
+        .pad(3)
+        .pad_mode(PadMode::Fill)
+        .block_words(&["cat"])
+        .build();
+}
+
+#[test]
+fn test_encode_set() {
+    let alphaid = AlphaId::<u64>::builder()
+        .prefix(b"s_")
+        .group(4, b'.')
+        .build();
+    let mut ids: Vec<u64> = (0..48).map(|i| 1_350_997_667 + i * i * 7).collect();
+    ids.reverse();
+    let v = alphaid.encode_set(&mut ids).unwrap();
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(alphaid.decode_set(&v), Ok(ids.clone()));
+    assert!(v.len() < alphaid.encode_list(&ids, b'~').unwrap().len() / 2);
+
+    assert_eq!(alphaid.encode_set(&mut []), Ok(b"s_".to_vec()));
+    assert_eq!(alphaid.decode_set(b"s_"), Ok(vec![]));
+    let mut bounds = [u64::MAX, 0, u64::MAX - 1, 0];
+    let v = alphaid.encode_set(&mut bounds).unwrap();
+    assert_eq!(alphaid.decode_set(&v), Ok(vec![0, u64::MAX - 1, u64::MAX]));
+
+    // `F` continues a number, `Fa` pads it with a needless zero.
+    assert_eq!(
+        alphaid.decode_set(b"s_bF"),
+        Err(AlphaIdError::InvalidLength)
+    );
+    assert_eq!(
+        alphaid.decode_set(b"s_Fa"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(
+        alphaid.decode_set(b"s_a?"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(
+        alphaid.decode_set(b"s_____.____.____._d"),
+        Err(AlphaIdError::Overflow)
+    );
+
+    let small = AlphaId::<u8>::builder()
+        .chars(b"ACG".to_vec())
+        .allow_small_alphabet()
+        .build();
+    assert_eq!(small.encode_set(&mut [1]), Err(AlphaIdError::InvalidConfig));
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "arbitrary")]
+
+use alphaid::AlphaId;
+
+#[test]
+fn test_arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use rand_chacha::rand_core::{RngCore, SeedableRng};
+
+    let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(646);
+    let mut data = vec![0; 512];
+    let (mut built, mut decoded) = (0, 0);
+    for _ in 0..2000 {
+        rng.fill_bytes(&mut data);
+        let mut u = Unstructured::new(&data);
+        let alphaid = match AlphaId::<u64>::arbitrary(&mut u) {
+            Ok(alphaid) => alphaid,
+            Err(_) => continue,
+        };
+        built += 1;
+        let input = alphaid.arbitrary_input(&mut u).unwrap();
+        assert!(input.starts_with(&alphaid.to_config().prefix));
+        if let Ok(n) = alphaid.decode(&input) {
+            decoded += 1;
+            // Every representation of some numbers may be blocked.
+            if let Ok(v) = alphaid.encode(n) {
+                assert_eq!(alphaid.decode(&v), Ok(n));
+            }
+        }
+        let _ = alphaid.decode_strict(&input);
+    }
+    assert!(built > 1000, "{}", built);
+    assert!(decoded > 100, "{}", decoded);
+}
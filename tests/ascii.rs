@@ -0,0 +1,20 @@
+#![cfg(feature = "ascii")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_encode_ascii() {
+    let alphaid = AlphaId::<u64>::builder()
+        .prefix(b"id_")
+        .group(3, b'.')
+        .build();
+    let id = alphaid.encode_ascii(1350997667).unwrap();
+    assert_eq!(id.as_str(), "id_90F.7qb");
+    assert_eq!(id.to_string(), "id_90F.7qb");
+    assert_eq!(id.len(), 10);
+    assert_eq!(alphaid.decode(&id), Ok(1350997667));
+    assert_eq!(String::from(id), "id_90F.7qb");
+
+    let emoji = AlphaId::<u64>::builder().emoji().build();
+    assert_eq!(emoji.encode_ascii(1), Err(AlphaIdError::InvalidConfig));
+}
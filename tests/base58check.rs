@@ -0,0 +1,34 @@
+#![cfg(feature = "base58check")]
+
+use alphaid::AlphaIdError;
+
+#[test]
+fn test_base58check() {
+    use alphaid::{decode_base58check, encode_base58check};
+
+    let wif = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+    let (version, key) = decode_base58check(wif).unwrap();
+    assert_eq!(version, 0x80);
+    assert_eq!(key[..4], [0x0c, 0x28, 0xfc, 0xa3]);
+    assert_eq!(encode_base58check(version, &key), wif);
+
+    let vectors = [
+        (0, &b"\0\0"[..], "11146EAsf"),
+        (1, &[b'y'; 55][..], "GA1ckn2Ak7rmEzLR19burs6X3RHJJpJnBewgh9SH6zcqZM6GWArLXZWTmVyKz6YSresuSjMQQPMz5NuF5"),
+        (1, &[b'y'; 63][..], "CBwe5EVKPzcYvbEsZWg3j22eEJ48tUWth8nMCTfxySSrsn9hEMDsTFfLnajX9YX91ZNgPN71SZ6pc3r9XjqeJXxV66mE"),
+    ];
+    for (version, payload, s) in vectors {
+        assert_eq!(encode_base58check(version, payload), s);
+        assert_eq!(decode_base58check(s), Ok((version, payload.to_vec())));
+    }
+
+    assert_eq!(
+        decode_base58check("11146EAsg"),
+        Err(AlphaIdError::ChecksumMismatch)
+    );
+    assert_eq!(
+        decode_base58check("1114l"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(decode_base58check("1111"), Err(AlphaIdError::InvalidLength));
+}
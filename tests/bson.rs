@@ -0,0 +1,15 @@
+#![cfg(feature = "bson")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_object_id() {
+    let alphaid = AlphaId::<u128>::new();
+    for oid in [[0; 12], [0xFF; 12], *b"abcdefghijkl"] {
+        let v = alphaid.encode_object_id(&oid).unwrap();
+        assert!(v.len() <= 16);
+        assert_eq!(alphaid.decode_object_id(&v), Ok(oid));
+    }
+    let v = alphaid.encode(1 << 96).unwrap();
+    assert_eq!(alphaid.decode_object_id(&v), Err(AlphaIdError::Overflow));
+}
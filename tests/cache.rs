@@ -0,0 +1,20 @@
+#![cfg(feature = "cache")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_cached_alphaid() {
+    let alphaid = AlphaId::<u64>::builder().pad(3).build();
+    let cached = alphaid::CachedAlphaId::new(alphaid.clone(), 2);
+    for n in [0, 1, 2, 0, 1350997667, 1, 0] {
+        let v = cached.encode(n).unwrap();
+        assert_eq!(v, alphaid.encode(n).unwrap());
+        assert_eq!(cached.decode(&v), Ok(n));
+    }
+    assert_eq!(cached.decode(b"?"), Err(AlphaIdError::UnexpectedChar));
+    cached.clear();
+    assert_eq!(cached.decode(b"aab"), Ok(0));
+
+    let uncached = alphaid::CachedAlphaId::new(alphaid, 0);
+    assert_eq!(uncached.encode(0), Ok(b"aab".to_vec()));
+}
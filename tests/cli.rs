@@ -0,0 +1,34 @@
+#![cfg(feature = "cli")]
+
+#[test]
+fn test_cli() {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let cli = || Command::new(env!("CARGO_BIN_EXE_alphaid"));
+    let out = cli().args(["encode", "1350997667", "1"]).output().unwrap();
+    assert!(out.status.success());
+    assert_eq!(out.stdout, b"90F7qb\nb\n");
+
+    let out = cli()
+        .args(["--pad", "3", "--prefix", "id_", "decode", "id_aab", "x!"])
+        .output()
+        .unwrap();
+    assert_eq!(out.status.code(), Some(1));
+    assert_eq!(out.stdout, b"0\n");
+    assert_eq!(out.stderr, b"x!: prefix missed\n");
+
+    let mut child = cli()
+        .args(["--config", "pad=3", "encode"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"0\n\n1\n").unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert!(out.status.success());
+    assert_eq!(out.stdout, b"aab\nbab\n");
+
+    let out = cli().args(["--pad", "0", "encode", "1"]).output().unwrap();
+    assert_eq!(out.status.code(), Some(2));
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "cursor")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_cursor() {
+    use alphaid::{Cursor, CursorError};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Page {
+        offset: u64,
+        sort: Option<String>,
+        filters: u32,
+    }
+
+    let cursor = Cursor::new(AlphaId::<u64>::new());
+    // The zero bytes of the value survive.
+    let page = Page {
+        offset: 0,
+        sort: None,
+        filters: 0,
+    };
+    let v = cursor.encode(&page).unwrap();
+    assert_eq!(cursor.decode::<Page>(&v), Ok(page));
+    assert_eq!(cursor.encode(&0u8), Ok("ae".to_string()));
+    assert_eq!(cursor.decode::<u8>("ae"), Ok(0));
+    assert_eq!(
+        cursor.decode::<u8>("aa!"),
+        Err(CursorError::Id(AlphaIdError::UnexpectedChar))
+    );
+    assert_eq!(
+        cursor.decode::<u8>("ab"),
+        Err(CursorError::Id(AlphaIdError::InvalidNumber))
+    );
+    assert!(matches!(
+        cursor.decode::<Page>("ae"),
+        Err(CursorError::Format(_))
+    ));
+
+    let tagged =
+        Cursor::new(AlphaId::<u64>::builder().group(4, b'.').build()).tag(*b"0123456789abcdef");
+    let page = Page {
+        offset: 1 << 40,
+        sort: Some("created_at desc".to_string()),
+        filters: 0xdead_beef,
+    };
+    let v = tagged.encode(&page).unwrap();
+    assert!(v.contains('.'));
+    assert_eq!(tagged.decode::<Page>(&v), Ok(page));
+    let untagged = Cursor::new(AlphaId::<u64>::builder().group(4, b'.').build());
+    assert!(untagged.decode::<Page>(&v).is_err());
+    assert_eq!(
+        tagged.decode::<u8>("ae"),
+        Err(CursorError::Id(AlphaIdError::TagMismatch))
+    );
+    assert_eq!(
+        tagged.decode::<Page>(&untagged.encode(&0u8).unwrap()),
+        Err(CursorError::Id(AlphaIdError::TagMismatch))
+    );
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "diesel-sqlite")]
+
+use alphaid::AlphaId;
+
+#[test]
+fn test_diesel() {
+    use alphaid::{EncodedId, Encoding};
+    use diesel::prelude::*;
+    use std::sync::OnceLock;
+
+    struct Orders;
+
+    impl Encoding for Orders {
+        type Int = u64;
+
+        fn alphaid() -> &'static AlphaId<u64> {
+            static ALPHAID: OnceLock<AlphaId<u64>> = OnceLock::new();
+            ALPHAID.get_or_init(|| AlphaId::builder().prefix(b"ord_").build())
+        }
+    }
+
+    diesel::table! {
+        orders (id) {
+            id -> BigInt,
+            code -> Text,
+        }
+    }
+
+    #[derive(Queryable, Insertable, Debug, PartialEq)]
+    #[diesel(table_name = orders)]
+    struct Order {
+        id: EncodedId<Orders>,
+        code: EncodedId<Orders>,
+    }
+
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE orders (id BIGINT PRIMARY KEY, code TEXT NOT NULL)")
+        .execute(&mut conn)
+        .unwrap();
+    let id = EncodedId::<Orders>::new(12345).unwrap();
+    let order = Order {
+        id: id.clone(),
+        code: id.clone(),
+    };
+    diesel::insert_into(orders::table)
+        .values(&order)
+        .execute(&mut conn)
+        .unwrap();
+
+    let read: Order = orders::table.first(&mut conn).unwrap();
+    assert_eq!(read, order);
+    let raw: (i64, String) = orders::table.first(&mut conn).unwrap();
+    assert_eq!(raw, (12345, "ord_Vad".to_string()));
+
+    let found = orders::table
+        .filter(orders::id.eq("ord_Vad".parse::<EncodedId<Orders>>().unwrap()))
+        .count()
+        .get_result::<i64>(&mut conn)
+        .unwrap();
+    assert_eq!(found, 1);
+
+    diesel::update(orders::table)
+        .set(orders::code.eq("ord_Vada"))
+        .execute(&mut conn)
+        .unwrap();
+    assert!(orders::table.first::<Order>(&mut conn).is_err());
+    let too_big = diesel::insert_into(orders::table)
+        .values(Order {
+            id: EncodedId::new(u64::MAX).unwrap(),
+            code: id,
+        })
+        .execute(&mut conn);
+    assert!(too_big.is_err());
+}
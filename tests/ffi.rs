@@ -0,0 +1,42 @@
+#![cfg(feature = "ffi")]
+
+use alphaid::AlphaIdError;
+
+#[test]
+fn test_ffi() {
+    use alphaid::ffi::*;
+    use std::ffi::CStr;
+    use std::ptr;
+
+    unsafe {
+        assert!(alphaid_new(b"pad=0\0".as_ptr().cast()).is_null());
+        let alphaid = alphaid_new(b"prefix=id_\0".as_ptr().cast());
+        assert!(!alphaid.is_null());
+
+        let mut buf = [0; 10];
+        assert_eq!(
+            alphaid_encode(alphaid, 1350997667, buf.as_mut_ptr(), buf.len()),
+            0
+        );
+        assert_eq!(CStr::from_ptr(buf.as_ptr()).to_bytes(), b"id_90F7qb");
+        assert_eq!(
+            alphaid_encode(alphaid, 1350997667, buf.as_mut_ptr(), 9),
+            error_code(AlphaIdError::BufferTooSmall)
+        );
+
+        let mut n = 0;
+        assert_eq!(alphaid_decode(alphaid, buf.as_ptr(), &mut n), 0);
+        assert_eq!(n, 1350997667);
+        assert_eq!(
+            alphaid_decode(alphaid, b"90F7qb\0".as_ptr().cast(), &mut n),
+            error_code(AlphaIdError::PrefixMissed)
+        );
+        assert_eq!(
+            alphaid_decode(alphaid, ptr::null(), &mut n),
+            ALPHAID_ERR_ARGUMENT
+        );
+
+        alphaid_free(alphaid);
+        alphaid_free(ptr::null_mut());
+    }
+}
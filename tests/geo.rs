@@ -0,0 +1,41 @@
+#![cfg(feature = "geo")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_latlng() {
+    let geohash = AlphaId::<u64>::builder()
+        .chars(b"0123456789bcdefghjkmnpqrstuvwxyz".to_vec())
+        .build();
+    assert_eq!(geohash.encode_latlng(42.6, -5.6, 5), Ok(b"ezs42".to_vec()));
+    let area = geohash.decode_latlng(b"ezs42").unwrap();
+    let (lat, lng) = area.center();
+    assert!((lat - 42.605).abs() < 0.01 && (lng - -5.603).abs() < 0.01);
+
+    for alphaid in &[
+        AlphaId::<u64>::new(),
+        AlphaId::<u64>::builder()
+            .chars(b"0123456789abcdefghijklmnopqrstuvwxyz".to_vec())
+            .prefix(b"geo:")
+            .build(),
+    ] {
+        let points = [
+            (0.0, 0.0),
+            (-90.0, -180.0),
+            (90.0, 180.0),
+            (48.8584, 2.2945),
+        ];
+        for &(lat, lng) in &points {
+            let v = alphaid.encode_latlng(lat, lng, 8).unwrap();
+            let area = alphaid.decode_latlng(&v).unwrap();
+            assert!(area.contains(lat, lng), "{:?} {:?}", area, (lat, lng));
+            // Shorter prefixes stand for larger areas around the point.
+            let shorter = alphaid.encode_latlng(lat, lng, 4).unwrap();
+            assert!(v.starts_with(&shorter));
+        }
+    }
+    assert_eq!(
+        geohash.encode_latlng(91.0, 0.0, 5),
+        Err(AlphaIdError::InvalidNumber)
+    );
+}
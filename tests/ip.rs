@@ -0,0 +1,32 @@
+#![cfg(feature = "ip")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_ip() {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let alphaid = AlphaId::<u128>::builder().prefix(b"ip_").build();
+    let ips = [
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        IpAddr::V4(Ipv4Addr::BROADCAST),
+        IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+        IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0x0a00, 0x0001)),
+        IpAddr::V6(Ipv6Addr::from(u128::MAX)),
+    ];
+    let encoded: Vec<Vec<u8>> = ips
+        .iter()
+        .map(|&ip| alphaid.encode_ip(ip).unwrap())
+        .collect();
+    for (ip, v) in ips.iter().zip(&encoded) {
+        assert_eq!(alphaid.decode_ip(v), Ok(*ip));
+    }
+    // The same number in both families.
+    assert_ne!(encoded[2], encoded[4]);
+
+    let v = alphaid.encode_pair(5, 1).unwrap();
+    assert_eq!(alphaid.decode_ip(&v), Err(AlphaIdError::InvalidNumber));
+    let v = alphaid.encode_pair(4, 1 << 32).unwrap();
+    assert_eq!(alphaid.decode_ip(&v), Err(AlphaIdError::Overflow));
+}
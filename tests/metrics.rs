@@ -0,0 +1,68 @@
+#![cfg(feature = "metrics")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_metrics() {
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::with_local_recorder(&recorder, || {
+        let alphaid = AlphaId::<u8>::new();
+        assert_eq!(alphaid.encode(1), Ok(b"b".to_vec()));
+        assert_eq!(alphaid.encode_string(200), Ok("id".to_string()));
+        assert_eq!(alphaid.decode(b"id"), Ok(200));
+        assert_eq!(alphaid.decode_strict(b"b"), Ok(1));
+        assert_eq!(alphaid.decode(b"Ep"), Err(AlphaIdError::Overflow));
+        assert_eq!(alphaid.decode_str("b!"), Err(AlphaIdError::UnexpectedChar));
+        assert_eq!(
+            alphaid.decode_strict(b"ba"),
+            Err(AlphaIdError::NonCanonical)
+        );
+    });
+
+    let mut values = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| {
+            let key = key.key();
+            let labels = key
+                .labels()
+                .map(|l| format!("{}={}", l.key(), l.value()))
+                .collect::<Vec<_>>();
+            (format!("{}{:?}", key.name(), labels), value)
+        })
+        .collect::<Vec<_>>();
+    values.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        values,
+        vec![
+            (
+                r#"alphaid_decodes_total["result=non_canonical"]"#.to_string(),
+                DebugValue::Counter(1)
+            ),
+            (
+                r#"alphaid_decodes_total["result=ok"]"#.to_string(),
+                DebugValue::Counter(2)
+            ),
+            (
+                r#"alphaid_decodes_total["result=overflow"]"#.to_string(),
+                DebugValue::Counter(1)
+            ),
+            (
+                r#"alphaid_decodes_total["result=unexpected_char"]"#.to_string(),
+                DebugValue::Counter(1)
+            ),
+            (
+                "alphaid_encoded_len[]".to_string(),
+                DebugValue::Histogram(vec![1.0.into(), 2.0.into()])
+            ),
+            (
+                r#"alphaid_encodes_total["result=ok"]"#.to_string(),
+                DebugValue::Counter(2)
+            ),
+        ]
+    );
+}
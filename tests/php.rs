@@ -0,0 +1,43 @@
+#![cfg(feature = "php")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_php_alphaid() {
+    use alphaid::PhpAlphaId;
+
+    // Computed with a line by line port of the PHP function.
+    let vectors = [
+        (PhpAlphaId::new(), 0, "a"),
+        (PhpAlphaId::new(), 1350997667, "XmE03b"),
+        (PhpAlphaId::new(), 62 * 62 * 62, "aaab"),
+        (PhpAlphaId::new(), 62 * 62 * 62 - 1, "ZZZ"),
+        (PhpAlphaId::new().pad_up(3), 5, "fab"),
+        (PhpAlphaId::new().pad_up(8), 2188847690240, "SpQXn7Cb"),
+        (PhpAlphaId::new().pass_key("x"), 61, "q"),
+        (PhpAlphaId::new().pass_key("secret"), 42, "2"),
+        (
+            PhpAlphaId::new().pass_key("correct horse").pad_up(5),
+            (1 << 53) - 1,
+            "jpzEH61kr",
+        ),
+    ];
+    for (php, n, v) in &vectors {
+        assert_eq!(php.encode(*n).as_deref(), Ok(*v));
+        assert_eq!(php.decode(v), Ok(*n));
+    }
+
+    let php = PhpAlphaId::new().pad_up(4);
+    let alphaid = AlphaId::<u64>::builder()
+        .chars(b"abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ".to_vec())
+        .pad(4)
+        .build();
+    for n in [0, 1, 1350997667, u64::MAX >> 20] {
+        assert_eq!(
+            php.encode(n).unwrap().as_bytes(),
+            &alphaid.encode(n).unwrap()[..]
+        );
+    }
+    assert_eq!(php.decode("ab"), Err(AlphaIdError::PadMissed));
+    assert_eq!(php.decode("a-"), Err(AlphaIdError::UnexpectedChar));
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "primitive-types")]
+
+use alphaid::{AlphaId, AlphaIdError, DigitOrder};
+
+#[test]
+fn test_u256() {
+    use primitive_types::{U256, U512};
+
+    let n = "123456789012345678901234567890123456789012345678901234567890";
+    for alphaid in [
+        AlphaId::<u8>::new(),
+        AlphaId::<u8>::builder()
+            .prefix(b"big_")
+            .group(4, b'.')
+            .order(DigitOrder::BigEndian)
+            .emoji()
+            .build(),
+    ] {
+        for x in [U256::zero(), U256::from(1350997667u64), U256::MAX] {
+            let v = alphaid.encode_u256(x).unwrap();
+            assert_eq!(alphaid.decode_u256(&v), Ok(x));
+            assert_eq!(alphaid.encode_dec_str(&x.to_string()), Ok(v));
+        }
+        let v = alphaid.encode_dec_str(n).unwrap();
+        assert_eq!(alphaid.decode_u256(&v), Ok(U256::from_dec_str(n).unwrap()));
+
+        let max = U512::from(U256::MAX);
+        let v = alphaid.encode_u512(max).unwrap();
+        assert_eq!(alphaid.decode_u512(&v), Ok(max));
+        assert_eq!(alphaid.decode_u256(&v), Ok(U256::MAX));
+        let v = alphaid.encode_u512(max + 1).unwrap();
+        assert_eq!(alphaid.decode_u256(&v), Err(AlphaIdError::Overflow));
+        let v = alphaid.encode_u512(U512::MAX).unwrap();
+        assert_eq!(alphaid.decode_u512(&v), Ok(U512::MAX));
+    }
+
+    let alphaid = AlphaId::<u64>::new();
+    assert_eq!(
+        alphaid.encode_u256(U256::from(1350997667u64)),
+        Ok("90F7qb".to_string())
+    );
+    assert_eq!(
+        alphaid.decode_u256("ab+"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(alphaid.decode_u256(""), Err(AlphaIdError::EmptyInput));
+}
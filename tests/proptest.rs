@@ -0,0 +1,35 @@
+#![cfg(feature = "proptest")]
+
+use alphaid::AlphaId;
+
+#[test]
+fn test_proptest() {
+    use alphaid::proptest::{any_alphaid, valid_encoding_for};
+    use proptest::strategy::Strategy;
+    use proptest::test_runner::TestRunner;
+
+    let mut runner = TestRunner::deterministic();
+    runner
+        .run(&(any_alphaid::<u64>(), 0..u64::MAX), |(alphaid, n)| {
+            if let Ok(v) = alphaid.encode(n) {
+                assert_eq!(alphaid.decode(&v), Ok(n));
+            }
+            Ok(())
+        })
+        .unwrap();
+    runner
+        .run(&any_alphaid::<u8>(), |alphaid| {
+            assert!(alphaid.to_config().chars.len() >= 17);
+            Ok(())
+        })
+        .unwrap();
+
+    let alphaid = AlphaId::<u8>::new();
+    let strategy = valid_encoding_for(&alphaid).prop_map(String::from_utf8);
+    runner
+        .run(&strategy, |v| {
+            assert!(alphaid.decode_strict(v.unwrap()).is_ok());
+            Ok(())
+        })
+        .unwrap();
+}
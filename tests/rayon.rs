@@ -0,0 +1,19 @@
+#![cfg(feature = "rayon")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_par_batch() {
+    let alphaid = AlphaId::<u64>::builder().pad(4).build();
+    let ns: Vec<u64> = (0..100_000).map(|n| n * 104_729).collect();
+    let vs = alphaid.par_encode_batch(&ns).unwrap();
+    assert_eq!(vs, alphaid.encode_batch(&ns).unwrap());
+    assert_eq!(alphaid.par_decode_batch(&vs), Ok(ns));
+
+    let mut vs = vs;
+    vs[77_777] = b"+".to_vec();
+    assert_eq!(
+        alphaid.par_decode_batch(&vs),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "rocket")]
+
+use alphaid::{AlphaId, AlphaIdError, EncodedId, Encoding};
+use rocket::get;
+
+struct Users;
+
+impl Encoding for Users {
+    type Int = u8;
+
+    fn alphaid() -> &'static AlphaId<u8> {
+        static ALPHAID: std::sync::OnceLock<AlphaId<u8>> = std::sync::OnceLock::new();
+        ALPHAID.get_or_init(AlphaId::new)
+    }
+}
+
+#[get("/users/<id>")]
+fn user(id: EncodedId<Users>) -> String {
+    id.value().to_string()
+}
+
+#[get("/posts/<id>")]
+fn post(id: Result<EncodedId<Users>, AlphaIdError>) -> String {
+    match id {
+        Ok(id) => id.value().to_string(),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[get("/search?<author>")]
+fn search(author: EncodedId<Users>) -> String {
+    author.value().to_string()
+}
+
+#[test]
+fn test_rocket() {
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+    use rocket::routes;
+
+    let client =
+        Client::untracked(rocket::build().mount("/", routes![user, post, search])).unwrap();
+    let get = |uri: &str| {
+        let res = client.get(uri.to_string()).dispatch();
+        (res.status(), res.into_string().unwrap_or_default())
+    };
+
+    assert_eq!(get("/users/id"), (Status::Ok, "200".to_string()));
+    assert_eq!(get("/users/Ep").0, Status::UnprocessableEntity);
+    assert_eq!(
+        get("/posts/Ep"),
+        (Status::Ok, "number overflow".to_string())
+    );
+    assert_eq!(get("/search?author=id"), (Status::Ok, "200".to_string()));
+    assert_eq!(get("/search?author=E!").0, Status::UnprocessableEntity);
+}
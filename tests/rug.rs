@@ -0,0 +1,54 @@
+#![cfg(feature = "rug")]
+
+use alphaid::{AlphaId, AlphaIdError, DigitOrder};
+use rug::Integer;
+
+#[test]
+fn test_rug_integer() {
+    let mut numbers = vec![
+        Integer::new(),
+        Integer::from(1),
+        Integer::from(1350997667),
+        Integer::from(u64::MAX),
+        Integer::from(u128::MAX) + 1,
+    ];
+    // Thousands of bits, with many zero and non-zero digits.
+    numbers.push(Integer::from(1) << 4096);
+    numbers.push((Integer::from(1) << 4096) - 1);
+    numbers.push(Integer::from(Integer::u_pow_u(3, 2500)));
+
+    for alphaid in [
+        AlphaId::<u8>::new(),
+        AlphaId::<u8>::builder()
+            .chars(b"0123456789abcdefghijklmnopqrstuvwxyz".to_vec())
+            .order(DigitOrder::BigEndian)
+            .build(),
+        AlphaId::<u8>::builder()
+            .prefix(b"big_")
+            .group(4, b'.')
+            .emoji()
+            .build(),
+    ] {
+        for n in &numbers {
+            let v = alphaid.encode_integer(n).unwrap();
+            assert_eq!(alphaid.encode_dec_str(&n.to_string()), Ok(v.clone()));
+            assert_eq!(alphaid.decode_integer(&v).as_ref(), Ok(n));
+            assert_eq!(alphaid.decode_dec_str(&v), Ok(n.to_string()));
+        }
+    }
+
+    let alphaid = AlphaId::<u64>::new();
+    assert_eq!(
+        alphaid.encode_integer(&Integer::from(1350997667)),
+        Ok("90F7qb".to_string())
+    );
+    assert_eq!(
+        alphaid.encode_integer(&Integer::from(-1)),
+        Err(AlphaIdError::InvalidNumber)
+    );
+    assert_eq!(
+        alphaid.decode_integer("ab+"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+    assert_eq!(alphaid.decode_integer(""), Err(AlphaIdError::EmptyInput));
+}
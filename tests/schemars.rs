@@ -0,0 +1,43 @@
+#![cfg(feature = "schemars")]
+
+use alphaid::AlphaId;
+
+#[test]
+fn test_json_schema() {
+    use alphaid::{EncodedId, Encoding};
+
+    struct Orders;
+
+    impl Encoding for Orders {
+        type Int = u64;
+
+        fn alphaid() -> &'static AlphaId<u64> {
+            static ALPHAID: std::sync::OnceLock<AlphaId<u64>> = std::sync::OnceLock::new();
+            ALPHAID.get_or_init(|| {
+                AlphaId::builder()
+                    .chars(b"0123456789-.abcdefghijklmnopqrstuvwxyz".to_vec())
+                    .group(4, b'_')
+                    .prefix(b"ord.")
+                    .pad(6)
+                    .build()
+            })
+        }
+    }
+
+    let schema = schemars::schema_for!(EncodedId<Orders>);
+    assert_eq!(schema.get("title").unwrap(), "Orders");
+    assert_eq!(schema.get("type").unwrap(), "string");
+    assert_eq!(
+        schema.get("pattern").unwrap(),
+        r"^ord\.[0123456789\-\.abcdefghijklmnopqrstuvwxyz_]+$"
+    );
+    let len = |key| schema.get(key).unwrap().as_u64().unwrap() as usize;
+    for n in [0, 1, 1 << 32, u64::MAX] {
+        let id = EncodedId::<Orders>::new(n).unwrap();
+        assert!((len("minLength")..=len("maxLength")).contains(&id.as_str().len()));
+    }
+    assert_eq!(
+        len("minLength"),
+        EncodedId::<Orders>::new(0).unwrap().as_str().len()
+    );
+}
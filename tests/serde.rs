@@ -0,0 +1,125 @@
+#![cfg(feature = "serde")]
+
+use alphaid::{AlphaId, CharClass, Config, DigitOrder, PadMode};
+
+#[test]
+fn test_serde_helpers() {
+    use alphaid::{EncodedId, Encoding};
+    use serde::{Deserialize, Serialize};
+    use std::sync::OnceLock;
+
+    struct Orders;
+
+    impl Encoding for Orders {
+        type Int = u32;
+
+        fn alphaid() -> &'static AlphaId<u32> {
+            static ALPHAID: OnceLock<AlphaId<u32>> = OnceLock::new();
+            ALPHAID.get_or_init(|| AlphaId::builder().prefix(b"ord_").build())
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: EncodedId<Orders>,
+        #[serde(with = "alphaid::serde::u64")]
+        user: u64,
+        #[serde(with = "alphaid::serde::u8")]
+        kind: u8,
+    }
+
+    let order = Order {
+        id: EncodedId::new(12345).unwrap(),
+        user: 1350997667,
+        kind: 0,
+    };
+    let json = serde_json::to_string(&order).unwrap();
+    assert_eq!(json, r#"{"id":"ord_Vad","user":"90F7qb","kind":"a"}"#);
+    assert_eq!(serde_json::from_str::<Order>(&json).unwrap(), order);
+
+    for json in [
+        r#"{"id":"ord_Vada","user":"90F7qb","kind":"a"}"#,
+        r#"{"id":"ord_Vad","user":"90F7q!","kind":"a"}"#,
+        r#"{"id":"ord_Vad","user":"90F7qb","kind":"ae"}"#,
+        r#"{"id":"ord_Vad","user":1350997667,"kind":"a"}"#,
+    ] {
+        assert!(serde_json::from_str::<Order>(json).is_err(), "{}", json);
+    }
+}
+
+#[test]
+fn test_serde_lenient() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        #[serde(with = "alphaid::serde::lenient")]
+        user: u64,
+        #[serde(with = "alphaid::serde::lenient")]
+        kind: u8,
+    }
+
+    let order = Order {
+        user: 1350997667,
+        kind: 200,
+    };
+    for json in [
+        r#"{"user":"90F7qb","kind":"id"}"#,
+        r#"{"user":1350997667,"kind":200}"#,
+        r#"{"user":"90F7qb","kind":200}"#,
+    ] {
+        assert_eq!(serde_json::from_str::<Order>(json).unwrap(), order);
+    }
+    assert_eq!(
+        serde_json::to_string(&order).unwrap(),
+        r#"{"user":"90F7qb","kind":"id"}"#
+    );
+
+    for json in [
+        r#"{"user":"90F7q!","kind":"id"}"#,
+        r#"{"user":-1,"kind":"id"}"#,
+        r#"{"user":1.5,"kind":"id"}"#,
+        r#"{"user":"90F7qb","kind":256}"#,
+        r#"{"user":"90F7qb","kind":"Ep"}"#,
+    ] {
+        assert!(serde_json::from_str::<Order>(json).is_err(), "{}", json);
+    }
+}
+
+#[test]
+fn test_config_serde() {
+    let alphaid = AlphaId::<u64>::builder()
+        .pad(4)
+        .group(2, b'.')
+        .block_words(&["cat"])
+        .first_char(CharClass::Letter)
+        .order(DigitOrder::BigEndian)
+        .tag(*b"0123456789abcdef", 2)
+        .build();
+    let json = serde_json::to_string(&alphaid.to_config()).unwrap();
+    assert!(json.contains(r#""order":"big_endian""#));
+    assert!(json.contains(r#""first_char":"letter""#));
+    let config: Config = serde_json::from_str(&json).unwrap();
+    assert_eq!(config, alphaid.to_config());
+
+    let copy: AlphaId<u64> = serde_json::from_str(&json).unwrap();
+    for n in (0..u64::MAX >> 16).step_by(1_000_000_000_000_007) {
+        assert_eq!(copy.encode(n), alphaid.encode(n));
+    }
+    assert_eq!(serde_json::to_string(&copy).unwrap(), json);
+
+    let config: Config = serde_json::from_str(r#"{"pad":3,"pad_mode":"fill"}"#).unwrap();
+    assert_eq!(config.pad_mode, PadMode::Fill);
+    assert_eq!(config.chars, Config::default().chars);
+    for json in [
+        r#"{"pad":0}"#,
+        r#"{"chars":[97,98,97]}"#,
+        r#"{"pad_mode":"zigzag"}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<AlphaId<u64>>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+}
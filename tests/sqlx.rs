@@ -0,0 +1,58 @@
+#![cfg(feature = "sqlx")]
+
+use alphaid::AlphaId;
+
+#[tokio::test]
+async fn test_sqlx() {
+    use alphaid::{EncodedId, Encoding};
+    use sqlx::{Connection, SqliteConnection};
+    use std::sync::OnceLock;
+
+    struct Orders;
+
+    impl Encoding for Orders {
+        type Int = u64;
+
+        fn alphaid() -> &'static AlphaId<u64> {
+            static ALPHAID: OnceLock<AlphaId<u64>> = OnceLock::new();
+            ALPHAID.get_or_init(|| AlphaId::builder().prefix(b"ord_").build())
+        }
+    }
+
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE orders (id BIGINT PRIMARY KEY)")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    let id = EncodedId::<Orders>::new(12345).unwrap();
+    sqlx::query("INSERT INTO orders (id) VALUES (?)")
+        .bind(&id)
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let (read, raw): (EncodedId<Orders>, i64) = sqlx::query_as("SELECT id, id FROM orders")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(read, id);
+    assert_eq!(read.as_str(), "ord_Vad");
+    assert_eq!(raw, 12345);
+
+    let found: Option<(i64,)> = sqlx::query_as("SELECT id FROM orders WHERE id = ?")
+        .bind("ord_Vad".parse::<EncodedId<Orders>>().unwrap())
+        .fetch_optional(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(found, Some((12345,)));
+
+    let negative = sqlx::query_as::<_, (EncodedId<Orders>,)>("SELECT -1")
+        .fetch_one(&mut conn)
+        .await;
+    assert!(negative.is_err());
+    let too_big = sqlx::query("SELECT ?")
+        .bind(EncodedId::<Orders>::new(u64::MAX).unwrap())
+        .execute(&mut conn)
+        .await;
+    assert!(too_big.is_err());
+}
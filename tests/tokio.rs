@@ -0,0 +1,46 @@
+#![cfg(feature = "tokio")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_id_service() {
+    use alphaid::{IdService, SequentialGen, ServiceError, SourceError, SourceGen};
+    use std::collections::HashSet;
+    use std::sync::atomic::AtomicU64;
+
+    let alphaid = AlphaId::<u64>::new();
+    let gen = SequentialGen::new(alphaid.clone());
+    let service = IdService::spawn(move || gen.next_id());
+    let tasks: Vec<_> = (0..8)
+        .map(|_| {
+            let handle = service.clone();
+            tokio::spawn(async move {
+                let mut ids = Vec::new();
+                for _ in 0..100 {
+                    ids.push(handle.next().await.unwrap());
+                }
+                ids
+            })
+        })
+        .collect();
+    let mut ids = HashSet::new();
+    for task in tasks {
+        ids.extend(task.await.unwrap());
+    }
+    assert_eq!(ids.len(), 800);
+    assert!(ids.iter().all(|v| alphaid.decode(v).unwrap() < 800));
+
+    let gen = SourceGen::new(AtomicU64::new(u64::from(u16::MAX)), AlphaId::<u16>::new());
+    let service = IdService::spawn_blocking(move || gen.next_id());
+    assert!(service.next().await.is_ok());
+    assert_eq!(
+        service.next().await,
+        Err(ServiceError::Generator(SourceError::Encode(
+            AlphaIdError::Overflow
+        )))
+    );
+
+    let service = IdService::<AlphaIdError>::spawn(|| panic!("generator failed"));
+    assert_eq!(service.next().await, Err(ServiceError::Stopped));
+    assert_eq!(service.next().await, Err(ServiceError::Stopped));
+}
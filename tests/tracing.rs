@@ -0,0 +1,80 @@
+#![cfg(feature = "tracing")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_tracing() {
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Records the spans and events as `name field=value ...`.
+    #[derive(Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    struct Fields<'a>(&'a mut String);
+
+    impl Visit for Fields<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            if field.name() != "message" {
+                self.0.push_str(&format!(" {}={:?}", field.name(), value));
+            }
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "alphaid"
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut line = span.metadata().name().to_string();
+            span.record(&mut Fields(&mut line));
+            self.0.lock().unwrap().push(line);
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut line = event.metadata().name().to_string();
+            event.record(&mut Fields(&mut line));
+            self.0.lock().unwrap().push(line);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    let recorder = Recorder::default();
+    let lines = recorder.0.clone();
+    let alphaid = AlphaId::<u8>::builder().prefix(b"id-").build();
+    tracing::subscriber::with_default(recorder, || {
+        assert_eq!(alphaid.decode("id-id"), Ok(200));
+        assert_eq!(alphaid.decode("id-i!"), Err(AlphaIdError::UnexpectedChar));
+        assert_eq!(alphaid.decode("id-Ep"), Err(AlphaIdError::Overflow));
+        assert_eq!(
+            alphaid.decode_strict("id-ba"),
+            Err(AlphaIdError::NonCanonical)
+        );
+        assert_eq!(
+            alphaid.decode_batch(&["id-a", "x"]),
+            Err(AlphaIdError::PrefixMissed)
+        );
+    });
+    assert_eq!(
+        *lines.lock().unwrap(),
+        [
+            "alphaid::decode_failed error=\"unexpected_char\" input_len=5 offset=4",
+            "alphaid::decode_failed error=\"overflow\" input_len=5",
+            "alphaid::decode_failed error=\"non_canonical\" input_len=5",
+            "decode_batch len=2",
+            "alphaid::decode_failed error=\"prefix_missed\" input_len=1",
+        ]
+    );
+}
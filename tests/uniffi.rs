@@ -0,0 +1,56 @@
+#![cfg(feature = "uniffi")]
+
+use alphaid::{AlphaId, AlphaIdError, DigitOrder};
+
+#[test]
+fn test_uniffi() {
+    use alphaid::uniffi::{AlphaId as MobileAlphaId, AlphaIdBuilder};
+    use std::sync::Arc;
+
+    let backend = AlphaId::<u64>::builder()
+        .pad(5)
+        .prefix(b"usr_")
+        .order(DigitOrder::BigEndian)
+        .build();
+    let alphaid = Arc::new(AlphaIdBuilder::new())
+        .pad(5)
+        .prefix("usr_")
+        .order(DigitOrder::BigEndian)
+        .build()
+        .unwrap();
+    for n in [0, 1, 1350997667, u64::MAX] {
+        let v = alphaid.encode(n).unwrap();
+        assert_eq!(v.as_bytes(), backend.encode(n).unwrap());
+        assert_eq!(alphaid.decode(&v), Ok(n));
+        assert_eq!(alphaid.decode_strict(&v), Ok(n));
+    }
+    assert_eq!(alphaid.decode("usr_a!"), Err(AlphaIdError::UnexpectedChar));
+
+    let alphaid = MobileAlphaId::parse("pad=3;prefix=usr_").unwrap();
+    assert_eq!(alphaid.encode(0), Ok("usr_aab".to_string()));
+    assert_eq!(
+        MobileAlphaId::new().encode(1350997667),
+        Ok("90F7qb".to_string())
+    );
+
+    // The setters modify the settings in place.
+    let builder = || Arc::new(AlphaIdBuilder::new());
+    assert_eq!(
+        builder().tag(vec![0; 15], 2).err(),
+        Some(AlphaIdError::InvalidConfig)
+    );
+    for builder in [
+        builder().pad(0),
+        builder().group(0, b'.'),
+        builder().group(4, b'a'),
+        builder().chars("abc"),
+        builder().prefix("ü"),
+        builder().block_words(vec![String::new()]),
+    ] {
+        assert_eq!(builder.build().err(), Some(AlphaIdError::InvalidConfig));
+    }
+    assert!(
+        MobileAlphaId::parse("pad=0").is_err(),
+        "invalid settings are an error"
+    );
+}
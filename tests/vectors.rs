@@ -0,0 +1,19 @@
+#![cfg(feature = "vectors")]
+
+use alphaid::Builder;
+
+#[test]
+fn test_vectors() {
+    let vectors = alphaid::vectors::vectors();
+    for config in alphaid::vectors::CONFIGS {
+        assert!(vectors.iter().any(|v| v.config == *config));
+    }
+    for v in &vectors {
+        let alphaid = Builder::<u128>::parse(v.config).unwrap().build();
+        assert_eq!(alphaid.decode(&v.encoded), Ok(v.number), "{:?}", v);
+    }
+    let json = alphaid::vectors::to_json();
+    assert_eq!(json.lines().count(), vectors.len() + 2);
+    assert!(json
+        .contains(r#"{"config": "prefix=usr_;suffix=.v1", "number": "0", "encoded": "usr_a.v1"}"#));
+}
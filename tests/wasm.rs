@@ -0,0 +1,15 @@
+#![cfg(feature = "wasm")]
+
+// Errors are JavaScript objects, which only exist on wasm targets.
+#[test]
+fn test_wasm() {
+    use alphaid::wasm::WasmAlphaId;
+
+    let alphaid = WasmAlphaId::new(Some("prefix=id_".into())).unwrap();
+    assert_eq!(alphaid.encode(1350997667).unwrap(), "id_90F7qb");
+    assert_eq!(alphaid.decode("id_90F7qb").unwrap(), 1350997667);
+    assert_eq!(alphaid.encode(u64::MAX).unwrap(), "id___________p");
+
+    let alphaid = WasmAlphaId::new(None).unwrap();
+    assert_eq!(alphaid.decode("90F7qb").unwrap(), 1350997667);
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "web")]
+
+use alphaid::AlphaId;
+
+#[tokio::test]
+async fn test_axum() {
+    use alphaid::{EncodedId, Encoding};
+    use axum::body::Body;
+    use axum::extract::Path;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::get;
+    use axum::Router;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    struct Users;
+
+    impl Encoding for Users {
+        type Int = u8;
+
+        fn alphaid() -> &'static AlphaId<u8> {
+            static ALPHAID: std::sync::OnceLock<AlphaId<u8>> = std::sync::OnceLock::new();
+            ALPHAID.get_or_init(AlphaId::new)
+        }
+    }
+
+    let app = Router::new()
+        .route(
+            "/users/{id}",
+            get(|id: EncodedId<Users>| async move { id.value().to_string() }),
+        )
+        .route(
+            "/posts/{id}",
+            get(|Path(id): Path<EncodedId<Users>>| async move { id.value().to_string() }),
+        );
+    let get = |uri: &'static str| {
+        let app = app.clone();
+        async move {
+            let res = app
+                .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let status = res.status();
+            let body = res.into_body().collect().await.unwrap().to_bytes();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        }
+    };
+
+    assert_eq!(get("/users/id").await, (StatusCode::OK, "200".to_string()));
+    assert_eq!(
+        get("/users/Ep").await,
+        (
+            StatusCode::BAD_REQUEST,
+            "invalid ID: number overflow".to_string()
+        )
+    );
+    assert_eq!(get("/users/E!").await.0, StatusCode::BAD_REQUEST);
+    assert_eq!(get("/posts/id").await, (StatusCode::OK, "200".to_string()));
+    assert_eq!(get("/posts/E!").await.0, StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn test_actix() {
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web, App};
+    use alphaid::{EncodedId, Encoding};
+
+    struct Users;
+
+    impl Encoding for Users {
+        type Int = u8;
+
+        fn alphaid() -> &'static AlphaId<u8> {
+            static ALPHAID: std::sync::OnceLock<AlphaId<u8>> = std::sync::OnceLock::new();
+            ALPHAID.get_or_init(AlphaId::new)
+        }
+    }
+
+    actix_rt::System::new().block_on(async {
+        let app = test::init_service(App::new().route(
+            "/users/{id}",
+            web::get().to(|id: EncodedId<Users>| async move { id.value().to_string() }),
+        ))
+        .await;
+
+        let req = test::TestRequest::get().uri("/users/id").to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(test::read_body(res).await, "200");
+
+        let req = test::TestRequest::get().uri("/users/Ep").to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(test::read_body(res).await, "invalid ID: number overflow");
+    });
+}
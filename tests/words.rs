@@ -0,0 +1,30 @@
+#![cfg(feature = "words")]
+
+use alphaid::{AlphaId, AlphaIdError};
+
+#[test]
+fn test_words() {
+    let words = [
+        "act", "action", "actor", "bird", "birth", "cat", "catalog", "dog", "echo", "fox", "goat",
+        "hen", "ibis", "jay", "koi", "lark", "mole", "newt",
+    ];
+    let alphaid = AlphaId::<u64>::builder().words(&words, b' ').pad(3).build();
+    for n in [0, 1, 17, 18, 12345, u64::MAX] {
+        let v = alphaid.encode_string(n).unwrap();
+        assert_eq!(alphaid.decode(&v), Ok(n), "{}", v);
+        assert_eq!(alphaid.decode_str(&v), Ok(n), "{}", v);
+    }
+    let n = alphaid.decode(b"act action actor").unwrap();
+    assert_eq!(alphaid.encode_string(n).unwrap(), "act action actor");
+    assert_eq!(
+        alphaid.decode(b"act owl"),
+        Err(AlphaIdError::UnexpectedChar)
+    );
+}
+
+#[test]
+#[should_panic(expected = "words must not be empty or contain the separator")]
+fn test_words_with_separator() {
+    let words: Vec<String> = (0..17).map(|i| format!("w {}", i)).collect();
+    AlphaId::<u64>::builder().words(&words, b' ').build();
+}